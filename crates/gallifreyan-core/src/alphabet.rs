@@ -0,0 +1,544 @@
+mod consonant;
+mod definition;
+mod vocal;
+
+pub use consonant::*;
+pub use definition::*;
+pub use vocal::*;
+
+use crate::layout::{PositionData, ReadingDirection};
+use itertools::Itertools;
+use std::collections::HashSet;
+use strum::IntoEnumIterator;
+
+pub const NESTED_LETTER_TEXT_DELIMITER: &str = "~";
+
+/// Separates the sentences of a sanitized text.
+pub const SENTENCE_SEPARATOR: char = '\n';
+
+/// Characters which end a sentence in the input.
+const SENTENCE_ENDINGS: [char; 4] = ['.', '!', '?', '\n'];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Letter {
+    Vocal(Vocal),
+    Consonant(Consonant),
+    ConsonantWithVocal { consonant: Consonant, vocal: Vocal },
+}
+
+impl Default for Letter {
+    fn default() -> Self {
+        Self::Consonant(Consonant::B)
+    }
+}
+
+impl Letter {
+    pub fn is_cutting(&self, alphabet: &Alphabet) -> bool {
+        match self {
+            Self::Consonant(consonant) | Self::ConsonantWithVocal { consonant, .. } => {
+                match alphabet.consonant_placement(*consonant) {
+                    ConsonantPlacement::DeepCut | ConsonantPlacement::ShallowCut => true,
+                    ConsonantPlacement::OnLine | ConsonantPlacement::Inside => false,
+                }
+            }
+            Self::Vocal(_) => false,
+        }
+    }
+
+    pub fn dots(&self, alphabet: &Alphabet) -> usize {
+        match self {
+            Letter::Vocal(vocal) => alphabet.vocal_decoration(*vocal).dots(),
+            Letter::Consonant(consonant) | Letter::ConsonantWithVocal { consonant, .. } => {
+                alphabet.consonant_decoration(*consonant).dots()
+            }
+        }
+    }
+
+    pub fn lines(&self, alphabet: &Alphabet) -> usize {
+        match self {
+            Letter::Vocal(vocal) => alphabet.vocal_decoration(*vocal).lines(),
+            Letter::Consonant(consonant) | Letter::ConsonantWithVocal { consonant, .. } => {
+                alphabet.consonant_decoration(*consonant).lines()
+            }
+        }
+    }
+
+    pub fn line_points_outside(&self, alphabet: &Alphabet) -> bool {
+        match self {
+            Letter::Vocal(vocal) => {
+                alphabet.vocal_decoration(*vocal) == VocalDecoration::LineOutside
+            }
+            Letter::Consonant(_) | Letter::ConsonantWithVocal { .. } => false,
+        }
+    }
+
+    pub fn radius(&self, word_radius: f32, number_of_letters: usize) -> f32 {
+        match self {
+            Letter::Vocal(vocal) => vocal.radius(word_radius, number_of_letters),
+            Letter::Consonant(consonant) | Letter::ConsonantWithVocal { consonant, .. } => {
+                consonant.radius(word_radius, number_of_letters)
+            }
+        }
+    }
+
+    pub fn position_data(
+        &self,
+        alphabet: &Alphabet,
+        reading_direction: ReadingDirection,
+        word_radius: f32,
+        number_of_letters: usize,
+        index: usize,
+    ) -> PositionData {
+        match self {
+            Letter::Vocal(vocal) => vocal.position_data(
+                alphabet,
+                reading_direction,
+                word_radius,
+                number_of_letters,
+                index,
+            ),
+            Letter::Consonant(consonant) | Letter::ConsonantWithVocal { consonant, .. } => {
+                consonant.position_data(
+                    alphabet,
+                    reading_direction,
+                    word_radius,
+                    number_of_letters,
+                    index,
+                )
+            }
+        }
+    }
+}
+
+/// Decides which vocals get nested into the consonant in front of them.
+pub trait Nesting {
+    fn can_nest(&self, consonant: Consonant, vocal: Vocal) -> bool;
+}
+
+/// Nests either all or no vocals.
+impl Nesting for bool {
+    fn can_nest(&self, _consonant: Consonant, _vocal: Vocal) -> bool {
+        *self
+    }
+}
+
+impl Nesting for HashSet<(Consonant, Vocal)> {
+    fn can_nest(&self, consonant: Consonant, vocal: Vocal) -> bool {
+        self.contains(&(consonant, vocal))
+    }
+}
+
+/// Comma separated rules like `ba`, `*a` or `b*` with `*` matching every consonant or vocal.
+/// The letters are the graphemes of the alphabet.
+pub fn parse_rules_string(
+    rules: &str,
+    alphabet: &Alphabet,
+) -> Result<HashSet<(Consonant, Vocal)>, String> {
+    let mut rules_map = HashSet::new();
+
+    for rule in rules.split(',') {
+        if rule.is_empty() {
+            return Err("Rule can't be empty!".to_string());
+        }
+
+        if rule == "*" {
+            return Err("Missing a consonant or vocal!".to_string());
+        }
+
+        let (consonants, vocals) = if let Some(vocal) = rule.strip_prefix('*') {
+            match alphabet.letter(vocal) {
+                Some(Letter::Vocal(vocal)) => (Consonant::iter().collect(), vec![vocal]),
+                _ => return Err(format!("'{}' is not a valid vocal!", vocal)),
+            }
+        } else if let Some(consonant) = rule.strip_suffix('*') {
+            match alphabet.letter(consonant) {
+                Some(Letter::Consonant(consonant)) => (vec![consonant], Vocal::iter().collect()),
+                _ => return Err(format!("'{}' is not a valid consonant!", consonant)),
+            }
+        } else {
+            let graphemes = alphabet.split_word(rule);
+
+            if graphemes.concat() != rule {
+                return Err(format!("'{}' is not a valid letter!", rule));
+            }
+
+            let letters: Vec<Option<Letter>> =
+                graphemes.iter().map(|it| alphabet.letter(it)).collect();
+
+            match letters[..] {
+                [Some(Letter::Consonant(consonant)), Some(Letter::Vocal(vocal))] => {
+                    (vec![consonant], vec![vocal])
+                }
+                [Some(Letter::Consonant(_))] => {
+                    return Err(format!("'{}' is missing a vocal!", rule));
+                }
+                [Some(Letter::Vocal(_))] => {
+                    return Err(format!("'{}' is missing a consonant!", rule));
+                }
+                [_, _, _, ..] => {
+                    return Err(format!("Too many letters in '{}'!", rule));
+                }
+                _ => {
+                    return Err(format!(
+                        "'{}' is not a consonant followed by a vocal!",
+                        rule
+                    ));
+                }
+            }
+        };
+
+        for consonant in consonants {
+            for vocal in &vocals {
+                rules_map.insert((consonant, *vocal));
+            }
+        }
+    }
+
+    Ok(rules_map)
+}
+
+/// Keeps a [`GRAPHEME_SEPARATOR`] only where it changes how the word is split.
+pub fn sanitize_text_input(text: &str, alphabet: &Alphabet) -> String {
+    text.split_whitespace()
+        .map(|word| alphabet.join_graphemes(&alphabet.split_word(word)))
+        .filter(|word| !word.is_empty())
+        .join(" ")
+}
+
+/// Splits the input at sentence punctuation and newlines into its sentences together with the
+/// index of the first character of each sentence. Consecutive endings don't create empty
+/// sentences.
+pub fn split_sentences(text: &str) -> Vec<(usize, &str)> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut start_position = 0;
+
+    for (position, (index, character)) in text.char_indices().enumerate() {
+        if SENTENCE_ENDINGS.contains(&character) {
+            if index > start {
+                sentences.push((start_position, &text[start..index]));
+            }
+
+            start = index + character.len_utf8();
+            start_position = position + 1;
+        }
+    }
+
+    if start < text.len() {
+        sentences.push((start_position, &text[start..]));
+    }
+
+    sentences
+}
+
+/// Sanitizes every sentence of the input and joins them with [`SENTENCE_SEPARATOR`].
+pub fn sanitize_sentences(text: &str, alphabet: &Alphabet) -> String {
+    split_sentences(text)
+        .into_iter()
+        .map(|(_, sentence)| sanitize_text_input(sentence, alphabet))
+        .filter(|sentence| !sentence.is_empty())
+        .join(&SENTENCE_SEPARATOR.to_string())
+}
+
+/// Nested letters are joined with [`NESTED_LETTER_TEXT_DELIMITER`] in their text.
+pub fn create_letters_from_word(
+    word_text: &str,
+    nesting: &impl Nesting,
+    alphabet: &Alphabet,
+) -> Vec<(String, Letter)> {
+    alphabet
+        .split_word(word_text)
+        .into_iter()
+        .map(|it| {
+            let letter = alphabet.letter(it).unwrap();
+            (it.to_string(), letter)
+        })
+        .fold(Vec::new(), |mut acc, (text, letter)| {
+            match letter {
+                Letter::Vocal(vocal) => {
+                    if let Some((previous_text, previous_letter)) = acc.pop() {
+                        match previous_letter {
+                            Letter::Consonant(consonant) if nesting.can_nest(consonant, vocal) => {
+                                acc.push((
+                                    previous_text + NESTED_LETTER_TEXT_DELIMITER + &text,
+                                    Letter::ConsonantWithVocal { consonant, vocal },
+                                ));
+                            }
+                            _ => {
+                                acc.push((previous_text, previous_letter));
+                                acc.push((text, letter));
+                            }
+                        }
+                    } else {
+                        acc.push((text, letter));
+                    }
+                }
+                Letter::Consonant(_) | Letter::ConsonantWithVocal { .. } => {
+                    acc.push((text, letter));
+                }
+            }
+
+            acc
+        })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_split_lower_case_word() {
+        let result: Vec<&str> =
+            Alphabet::sherman().split_word("aeioubjtthphwhghchkshydlrzcqgnvquhpwxfmsng");
+        let expected = [
+            "a", "e", "i", "o", "u", "b", "j", "t", "th", "ph", "wh", "gh", "ch", "k", "sh", "y",
+            "d", "l", "r", "z", "c", "q", "g", "n", "v", "qu", "h", "p", "w", "x", "f", "m", "s",
+            "ng",
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_split_upper_case_word() {
+        let result: Vec<&str> =
+            Alphabet::sherman().split_word("AEIOUBJTTHPHWHGHCHKSHYDLRZCQGNVQUHPWXFMSNG");
+        let expected = [
+            "A", "E", "I", "O", "U", "B", "J", "T", "TH", "PH", "WH", "GH", "CH", "K", "SH", "Y",
+            "D", "L", "R", "Z", "C", "Q", "G", "N", "V", "QU", "H", "P", "W", "X", "F", "M", "S",
+            "NG",
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_split_mixed_case_double_letters() {
+        let result: Vec<&str> = Alphabet::sherman().split_word("tHThpHPhwHWhgHGhcHChsHShqUQunGNg");
+        let expected = [
+            "tH", "Th", "pH", "Ph", "wH", "Wh", "gH", "Gh", "cH", "Ch", "sH", "Sh", "qU", "Qu",
+            "nG", "Ng",
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_filter_invalid_letters() {
+        let result: Vec<&str> = Alphabet::sherman()
+            .split_word("äöü+*~#'i#-_.:,;<>|@n€^°1!2²\"3§³4$5v%6&7/{a8([9)l]0=i}ßd?\\´`");
+        let expected = ["i", "n", "v", "a", "l", "i", "d"];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_sanitize_text_input() {
+        let result = sanitize_text_input(
+            "äöü+*~#'i#-_.:,;<>|@n€^°1!2²\"3§³4$5v%6&7/{a8([9)l]0=i}ßd?\\´`",
+            &Alphabet::sherman(),
+        );
+        let expected = "invalid";
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_keep_needed_grapheme_separators() {
+        assert_eq!(
+            sanitize_text_input("hot|house", &Alphabet::sherman()),
+            "hot|house"
+        );
+        assert_eq!(
+            sanitize_text_input("ho|th|ouse |a||b|", &Alphabet::sherman()),
+            "hothouse ab"
+        );
+
+        let result: Vec<&str> = Alphabet::sherman().split_word("hot|house");
+        assert_eq!(result, ["h", "o", "t", "h", "o", "u", "s", "e"]);
+    }
+
+    #[test]
+    fn should_split_sentences() {
+        let result = split_sentences("Hi. Wie geht's?\nGut");
+
+        assert_eq!(result, [(0, "Hi"), (3, " Wie geht's"), (16, "Gut")]);
+        assert_eq!(
+            sanitize_sentences("Hi. Wie geht's?!\nGut", &Alphabet::sherman()),
+            "Hi\nWie gehts\nGut"
+        );
+    }
+
+    #[test]
+    fn should_split_and_merge_graphemes() {
+        let alphabet = Alphabet::sherman();
+
+        assert_eq!(alphabet.split_grapheme("hothouse", 2).unwrap(), "hot|house");
+        assert_eq!(
+            alphabet.merge_graphemes("hot|house", 2).unwrap(),
+            "hothouse"
+        );
+        assert_eq!(alphabet.split_grapheme("hothouse", 1), None);
+        assert_eq!(alphabet.merge_graphemes("hothouse", 0), None);
+        assert_eq!(alphabet.split_grapheme("quest", 0).unwrap(), "q|uest");
+    }
+
+    #[test]
+    fn should_nest_all_vocals() {
+        let result = create_letters_from_word("bbabibubebo", &true, &Alphabet::sherman());
+        let expected = [
+            ("b".to_string(), Letter::Consonant(Consonant::B)),
+            (
+                "b~a".to_string(),
+                Letter::ConsonantWithVocal {
+                    consonant: Consonant::B,
+                    vocal: Vocal::A,
+                },
+            ),
+            (
+                "b~i".to_string(),
+                Letter::ConsonantWithVocal {
+                    consonant: Consonant::B,
+                    vocal: Vocal::I,
+                },
+            ),
+            (
+                "b~u".to_string(),
+                Letter::ConsonantWithVocal {
+                    consonant: Consonant::B,
+                    vocal: Vocal::U,
+                },
+            ),
+            (
+                "b~e".to_string(),
+                Letter::ConsonantWithVocal {
+                    consonant: Consonant::B,
+                    vocal: Vocal::E,
+                },
+            ),
+            (
+                "b~o".to_string(),
+                Letter::ConsonantWithVocal {
+                    consonant: Consonant::B,
+                    vocal: Vocal::O,
+                },
+            ),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_nest_no_vocals() {
+        let result = create_letters_from_word("bbabibubebo", &false, &Alphabet::sherman());
+        let expected = [
+            ("b".to_string(), Letter::Consonant(Consonant::B)),
+            ("b".to_string(), Letter::Consonant(Consonant::B)),
+            ("a".to_string(), Letter::Vocal(Vocal::A)),
+            ("b".to_string(), Letter::Consonant(Consonant::B)),
+            ("i".to_string(), Letter::Vocal(Vocal::I)),
+            ("b".to_string(), Letter::Consonant(Consonant::B)),
+            ("u".to_string(), Letter::Vocal(Vocal::U)),
+            ("b".to_string(), Letter::Consonant(Consonant::B)),
+            ("e".to_string(), Letter::Vocal(Vocal::E)),
+            ("b".to_string(), Letter::Consonant(Consonant::B)),
+            ("o".to_string(), Letter::Vocal(Vocal::O)),
+        ];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_parse_rules_string() {
+        let result = parse_rules_string("ba,tho,c*", &Alphabet::sherman()).unwrap();
+
+        assert_eq!(result.len(), 2 + Vocal::iter().count());
+        assert!(result.contains(&(Consonant::B, Vocal::A)));
+        assert!(result.contains(&(Consonant::TH, Vocal::O)));
+        assert!(result.contains(&(Consonant::C, Vocal::U)));
+    }
+
+    #[test]
+    fn should_parse_wildcard_consonant_rule() {
+        let result = parse_rules_string("*e", &Alphabet::sherman()).unwrap();
+
+        assert_eq!(result.len(), Consonant::iter().count());
+        assert!(result.iter().all(|(_, vocal)| *vocal == Vocal::E));
+    }
+
+    #[test]
+    fn should_reject_invalid_rules() {
+        assert_eq!(
+            parse_rules_string("", &Alphabet::sherman()),
+            Err("Rule can't be empty!".to_string())
+        );
+        assert_eq!(
+            parse_rules_string("b", &Alphabet::sherman()),
+            Err("'b' is missing a vocal!".to_string())
+        );
+        assert_eq!(
+            parse_rules_string("a", &Alphabet::sherman()),
+            Err("'a' is missing a consonant!".to_string())
+        );
+        assert_eq!(
+            parse_rules_string("th", &Alphabet::sherman()),
+            Err("'th' is missing a vocal!".to_string())
+        );
+        assert_eq!(
+            parse_rules_string("bbbb", &Alphabet::sherman()),
+            Err("Too many letters in 'bbbb'!".to_string())
+        );
+    }
+
+    #[test]
+    fn should_parse_rules_with_graphemes_of_alphabet() {
+        let alphabet = Alphabet::from_ron(SHERMAN_PHONETIC).unwrap();
+
+        let result = parse_rules_string("ca,k*", &alphabet).unwrap();
+
+        assert_eq!(result.len(), Vocal::iter().count());
+        assert!(result.contains(&(Consonant::K, Vocal::A)));
+        assert_eq!(
+            parse_rules_string("qua", &alphabet),
+            Err("Too many letters in 'qua'!".to_string())
+        );
+        assert_eq!(
+            parse_rules_string("ab", &alphabet),
+            Err("'ab' is not a consonant followed by a vocal!".to_string())
+        );
+    }
+
+    #[test]
+    fn should_nest_custom_vocals() {
+        let rules: HashSet<_> = [(Consonant::B, Vocal::A), (Consonant::B, Vocal::O)]
+            .into_iter()
+            .collect();
+
+        let result = create_letters_from_word("bbabibubebo", &rules, &Alphabet::sherman());
+        let expected = [
+            ("b".to_string(), Letter::Consonant(Consonant::B)),
+            (
+                "b~a".to_string(),
+                Letter::ConsonantWithVocal {
+                    consonant: Consonant::B,
+                    vocal: Vocal::A,
+                },
+            ),
+            ("b".to_string(), Letter::Consonant(Consonant::B)),
+            ("i".to_string(), Letter::Vocal(Vocal::I)),
+            ("b".to_string(), Letter::Consonant(Consonant::B)),
+            ("u".to_string(), Letter::Vocal(Vocal::U)),
+            ("b".to_string(), Letter::Consonant(Consonant::B)),
+            ("e".to_string(), Letter::Vocal(Vocal::E)),
+            (
+                "b~o".to_string(),
+                Letter::ConsonantWithVocal {
+                    consonant: Consonant::B,
+                    vocal: Vocal::O,
+                },
+            ),
+        ];
+
+        assert_eq!(result, expected);
+    }
+}
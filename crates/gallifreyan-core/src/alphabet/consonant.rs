@@ -0,0 +1,97 @@
+use super::Alphabet;
+use crate::layout::{AnglePlacement, PositionData, ReadingDirection};
+use serde::Deserialize;
+use strum_macros::{EnumIter, EnumString};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter, EnumString, Deserialize)]
+pub enum Consonant {
+    B,
+    J,
+    T,
+    TH,
+    PH,
+    WH,
+    GH,
+    CH,
+    K,
+    SH,
+    Y,
+    D,
+    L,
+    R,
+    Z,
+    C,
+    Q,
+    G,
+    N,
+    V,
+    QU,
+    H,
+    P,
+    W,
+    X,
+    F,
+    M,
+    S,
+    NG,
+}
+
+impl Consonant {
+    pub fn radius(&self, word_radius: f32, number_of_letters: usize) -> f32 {
+        (word_radius * 0.75) / (1.0 + number_of_letters as f32 / 2.0)
+    }
+
+    pub fn position_data(
+        &self,
+        alphabet: &Alphabet,
+        reading_direction: ReadingDirection,
+        word_radius: f32,
+        number_of_letters: usize,
+        index: usize,
+    ) -> PositionData {
+        let distance = match alphabet.consonant_placement(*self) {
+            ConsonantPlacement::DeepCut => {
+                word_radius - self.radius(word_radius, number_of_letters) * 0.75
+            }
+            ConsonantPlacement::Inside => {
+                if number_of_letters > 1 {
+                    word_radius - self.radius(word_radius, number_of_letters) * 1.5
+                } else {
+                    0.0
+                }
+            }
+            ConsonantPlacement::ShallowCut => word_radius,
+            ConsonantPlacement::OnLine => word_radius,
+        };
+
+        PositionData {
+            distance,
+            angle: reading_direction.angle(number_of_letters, index),
+            angle_placement: AnglePlacement::Relative,
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+pub enum ConsonantPlacement {
+    DeepCut,
+    Inside,
+    ShallowCut,
+    OnLine,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ConsonantDecoration {
+    pub dots: usize,
+    pub lines: usize,
+}
+
+impl ConsonantDecoration {
+    pub fn dots(&self) -> usize {
+        self.dots
+    }
+
+    pub fn lines(&self) -> usize {
+        self.lines
+    }
+}
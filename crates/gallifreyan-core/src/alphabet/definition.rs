@@ -0,0 +1,332 @@
+use super::{
+    Consonant, ConsonantDecoration, ConsonantPlacement, Letter, Vocal, VocalDecoration,
+    VocalPlacement, NESTED_LETTER_TEXT_DELIMITER,
+};
+use lazy_static::lazy_static;
+use regex::{Regex, RegexBuilder};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::ops::Range;
+use std::sync::Arc;
+
+pub const SHERMAN: &str = include_str!("../../alphabets/sherman.ron");
+pub const SHERMAN_PHONETIC: &str = include_str!("../../alphabets/sherman_phonetic.ron");
+
+/// Separates graphemes in the input which would otherwise be read as one, e.g. `t|h`.
+pub const GRAPHEME_SEPARATOR: char = '|';
+
+lazy_static! {
+    static ref SHERMAN_ALPHABET: Arc<Alphabet> = Arc::new(Alphabet::from_ron(SHERMAN).unwrap());
+}
+
+/// The content of an alphabet `.ron` file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AlphabetDefinition {
+    pub name: String,
+    pub consonants: Vec<ConsonantDefinition>,
+    pub vocals: Vec<VocalDefinition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct ConsonantDefinition {
+    pub consonant: Consonant,
+    /// Every grapheme is written with the circle of this consonant, e.g. `["k", "c"]`.
+    pub graphemes: Vec<String>,
+    pub placement: ConsonantPlacement,
+    pub dots: usize,
+    pub lines: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct VocalDefinition {
+    pub vocal: Vocal,
+    pub graphemes: Vec<String>,
+    pub placement: VocalPlacement,
+    pub decoration: VocalDecoration,
+}
+
+/// Maps graphemes to letters and defines how each letter is drawn.
+///
+/// Letters which are missing in an alphabet fall back to [`Alphabet::sherman`], so that letters
+/// of an imported svg can always be drawn.
+#[derive(Debug, Clone)]
+pub struct Alphabet {
+    name: String,
+    tokenizer: Regex,
+    graphemes: HashMap<String, Letter>,
+    consonants: HashMap<Consonant, ConsonantDefinition>,
+    vocals: HashMap<Vocal, VocalDefinition>,
+}
+
+impl Alphabet {
+    pub fn from_ron(ron: &str) -> Result<Self, String> {
+        let definition: AlphabetDefinition =
+            ron::from_str(ron).map_err(|error| format!("Invalid alphabet: {}", error))?;
+
+        Self::new(definition)
+    }
+
+    pub fn new(definition: AlphabetDefinition) -> Result<Self, String> {
+        let mut graphemes = HashMap::new();
+
+        let letters = definition
+            .consonants
+            .iter()
+            .flat_map(|it| {
+                it.graphemes
+                    .iter()
+                    .map(|g| (g, Letter::Consonant(it.consonant)))
+            })
+            .chain(
+                definition
+                    .vocals
+                    .iter()
+                    .flat_map(|it| it.graphemes.iter().map(|g| (g, Letter::Vocal(it.vocal)))),
+            );
+
+        for (grapheme, letter) in letters {
+            if grapheme.is_empty()
+                || grapheme.contains(char::is_whitespace)
+                || grapheme.contains(NESTED_LETTER_TEXT_DELIMITER)
+            {
+                return Err(format!("'{}' is not a valid grapheme!", grapheme));
+            }
+
+            if graphemes.insert(grapheme.to_lowercase(), letter).is_some() {
+                return Err(format!("Grapheme '{}' is defined twice!", grapheme));
+            }
+        }
+
+        if graphemes.is_empty() {
+            return Err(format!("Alphabet '{}' has no graphemes!", definition.name));
+        }
+
+        let mut patterns: Vec<&String> = graphemes.keys().collect();
+        // the regex takes the first alternative which matches, so longer graphemes like `th`
+        // have to come before `t`
+        patterns.sort_by(|a, b| b.chars().count().cmp(&a.chars().count()).then(a.cmp(b)));
+
+        let pattern = patterns
+            .into_iter()
+            .map(|it| regex::escape(it))
+            .collect::<Vec<_>>()
+            .join("|");
+
+        let tokenizer = RegexBuilder::new(&pattern)
+            .case_insensitive(true)
+            .build()
+            .map_err(|error| error.to_string())?;
+
+        Ok(Self {
+            name: definition.name,
+            tokenizer,
+            graphemes,
+            consonants: definition
+                .consonants
+                .into_iter()
+                .map(|it| (it.consonant, it))
+                .collect(),
+            vocals: definition
+                .vocals
+                .into_iter()
+                .map(|it| (it.vocal, it))
+                .collect(),
+        })
+    }
+
+    /// The alphabet of the original Sherman's Circular Gallifreyan.
+    pub fn sherman() -> Arc<Self> {
+        Arc::clone(&SHERMAN_ALPHABET)
+    }
+
+    /// All alphabets which are shipped with the crate, starting with [`Alphabet::sherman`].
+    pub fn builtin() -> Vec<Arc<Self>> {
+        vec![
+            Self::sherman(),
+            Arc::new(Self::from_ron(SHERMAN_PHONETIC).unwrap()),
+        ]
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Splits a word into the graphemes of this alphabet and skips everything else.
+    pub fn split_word<'w>(&self, word: &'w str) -> Vec<&'w str> {
+        self.tokenizer
+            .find_iter(word)
+            .map(|matched| matched.as_str())
+            .collect()
+    }
+
+    /// Byte ranges of the graphemes of [`Alphabet::split_word`].
+    pub fn grapheme_ranges(&self, word: &str) -> Vec<Range<usize>> {
+        self.tokenizer
+            .find_iter(word)
+            .map(|matched| matched.range())
+            .collect()
+    }
+
+    /// Joins the graphemes into a word with [`GRAPHEME_SEPARATOR`] between graphemes which would
+    /// be split differently without it.
+    pub fn join_graphemes(&self, graphemes: &[&str]) -> String {
+        let mut word = String::new();
+
+        for (index, grapheme) in graphemes.iter().enumerate() {
+            if let Some(previous) = index.checked_sub(1).map(|it| graphemes[it]) {
+                let joined = format!("{}{}", previous, grapheme);
+
+                if self.split_word(&joined) != [previous, *grapheme] {
+                    word.push(GRAPHEME_SEPARATOR);
+                }
+            }
+
+            word += grapheme;
+        }
+
+        word
+    }
+
+    /// Splits the grapheme at the index into single character graphemes, e.g. `th` into `t|h`.
+    ///
+    /// `None` if the grapheme can't be split.
+    pub fn split_grapheme(&self, word: &str, index: usize) -> Option<String> {
+        let graphemes = self.split_word(word);
+        let grapheme = graphemes.get(index)?;
+
+        let characters: Vec<&str> = grapheme
+            .char_indices()
+            .map(|(start, character)| &grapheme[start..start + character.len_utf8()])
+            .collect();
+
+        if characters.len() < 2 || characters.iter().any(|it| self.letter(it).is_none()) {
+            return None;
+        }
+
+        let mut new_graphemes = graphemes[..index].to_vec();
+        new_graphemes.extend(characters);
+        new_graphemes.extend(&graphemes[index + 1..]);
+
+        Some(self.join_graphemes(&new_graphemes))
+    }
+
+    /// Merges the grapheme at the index with the next one, e.g. `t|h` into `th`.
+    ///
+    /// `None` if both together aren't a grapheme.
+    pub fn merge_graphemes(&self, word: &str, index: usize) -> Option<String> {
+        let graphemes = self.split_word(word);
+        let merged = format!("{}{}", graphemes.get(index)?, graphemes.get(index + 1)?);
+
+        self.letter(&merged)?;
+
+        let mut new_graphemes = graphemes[..index].to_vec();
+        new_graphemes.push(&merged);
+        new_graphemes.extend(&graphemes[index + 2..]);
+
+        Some(self.join_graphemes(&new_graphemes))
+    }
+
+    pub fn letter(&self, grapheme: &str) -> Option<Letter> {
+        self.graphemes.get(&grapheme.to_lowercase()).copied()
+    }
+
+    pub fn consonant_placement(&self, consonant: Consonant) -> ConsonantPlacement {
+        self.consonant_definition(consonant, |it| it.placement)
+    }
+
+    pub fn consonant_decoration(&self, consonant: Consonant) -> ConsonantDecoration {
+        self.consonant_definition(consonant, |it| ConsonantDecoration {
+            dots: it.dots,
+            lines: it.lines,
+        })
+    }
+
+    pub fn vocal_placement(&self, vocal: Vocal) -> VocalPlacement {
+        self.vocal_definition(vocal, |it| it.placement)
+    }
+
+    pub fn vocal_decoration(&self, vocal: Vocal) -> VocalDecoration {
+        self.vocal_definition(vocal, |it| it.decoration)
+    }
+
+    fn consonant_definition<T>(
+        &self,
+        consonant: Consonant,
+        map: impl Fn(&ConsonantDefinition) -> T,
+    ) -> T {
+        match self.consonants.get(&consonant) {
+            Some(definition) => map(definition),
+            None => map(&SHERMAN_ALPHABET.consonants[&consonant]),
+        }
+    }
+
+    fn vocal_definition<T>(&self, vocal: Vocal, map: impl Fn(&VocalDefinition) -> T) -> T {
+        match self.vocals.get(&vocal) {
+            Some(definition) => map(definition),
+            None => map(&SHERMAN_ALPHABET.vocals[&vocal]),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn should_define_every_letter_in_sherman() {
+        let sherman = Alphabet::sherman();
+
+        assert_eq!(sherman.name(), "Sherman");
+        assert!(Consonant::iter().all(|it| sherman.consonants.contains_key(&it)));
+        assert!(Vocal::iter().all(|it| sherman.vocals.contains_key(&it)));
+        assert_eq!(
+            sherman.consonant_placement(Consonant::TH),
+            ConsonantPlacement::OnLine
+        );
+        assert_eq!(
+            sherman.consonant_decoration(Consonant::C),
+            ConsonantDecoration { dots: 4, lines: 0 }
+        );
+        assert_eq!(
+            sherman.vocal_decoration(Vocal::U),
+            VocalDecoration::LineOutside
+        );
+    }
+
+    #[test]
+    fn should_derive_tokenizer_from_graphemes() {
+        let alphabet = Alphabet::from_ron(SHERMAN_PHONETIC).unwrap();
+
+        let result = alphabet.split_word("Quick");
+
+        assert_eq!(result, ["Q", "u", "i", "c", "k"]);
+        assert_eq!(alphabet.letter("C"), Some(Letter::Consonant(Consonant::K)));
+        assert_eq!(alphabet.letter("qu"), None);
+        // falls back to sherman
+        assert_eq!(
+            alphabet.consonant_decoration(Consonant::QU),
+            ConsonantDecoration { dots: 0, lines: 1 }
+        );
+    }
+
+    #[test]
+    fn should_reject_invalid_alphabets() {
+        let duplicate = r#"(
+            name: "Duplicate",
+            consonants: [(consonant: B, graphemes: ["b"], placement: DeepCut, dots: 0, lines: 0)],
+            vocals: [(vocal: A, graphemes: ["B"], placement: Outside, decoration: None)],
+        )"#;
+        let empty = r#"(name: "Empty", consonants: [], vocals: [])"#;
+
+        assert_eq!(
+            Alphabet::from_ron(duplicate).unwrap_err(),
+            "Grapheme 'B' is defined twice!"
+        );
+        assert_eq!(
+            Alphabet::from_ron(empty).unwrap_err(),
+            "Alphabet 'Empty' has no graphemes!"
+        );
+        assert!(Alphabet::from_ron("(name: 1)").is_err());
+    }
+}
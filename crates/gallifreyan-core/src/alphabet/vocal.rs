@@ -0,0 +1,117 @@
+use super::consonant::ConsonantPlacement;
+use super::Alphabet;
+use crate::layout::{AnglePlacement, PositionData, ReadingDirection};
+use crate::math::angle::Degree;
+use serde::Deserialize;
+use strum_macros::{EnumIter, EnumString};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, EnumIter, EnumString, Deserialize)]
+pub enum Vocal {
+    A,
+    E,
+    I,
+    O,
+    U,
+}
+
+impl Vocal {
+    pub fn radius(&self, word_radius: f32, number_of_letters: usize) -> f32 {
+        (word_radius * 0.75 * 0.4) / (1.0 + number_of_letters as f32 / 2.0)
+    }
+
+    pub fn nested_radius(&self, consonant_radius: f32) -> f32 {
+        consonant_radius * 0.4
+    }
+
+    pub fn position_data(
+        &self,
+        alphabet: &Alphabet,
+        reading_direction: ReadingDirection,
+        word_radius: f32,
+        number_of_letters: usize,
+        index: usize,
+    ) -> PositionData {
+        let distance = match alphabet.vocal_placement(*self) {
+            VocalPlacement::OnLine => word_radius,
+            VocalPlacement::Outside => {
+                word_radius + self.radius(word_radius, number_of_letters) * 1.5
+            }
+            VocalPlacement::Inside => {
+                if number_of_letters > 1 {
+                    word_radius - self.radius(word_radius, number_of_letters) * 1.5
+                } else {
+                    0.0
+                }
+            }
+        };
+
+        PositionData {
+            distance,
+            angle: reading_direction.angle(number_of_letters, index),
+            angle_placement: AnglePlacement::Relative,
+        }
+    }
+
+    pub fn nested_position_data(
+        &self,
+        alphabet: &Alphabet,
+        consonant_placement: ConsonantPlacement,
+        consonant_radius: f32,
+        consonant_distance: f32,
+        word_radius: f32,
+    ) -> PositionData {
+        match alphabet.vocal_placement(*self) {
+            VocalPlacement::Inside => PositionData {
+                angle: Degree::new(180.0),
+                distance: consonant_radius,
+                angle_placement: AnglePlacement::Absolute,
+            },
+            VocalPlacement::Outside => PositionData {
+                angle: Degree::new(0.0),
+                distance: word_radius + self.nested_radius(consonant_radius) * 1.5,
+                angle_placement: AnglePlacement::Absolute,
+            },
+            VocalPlacement::OnLine => match consonant_placement {
+                ConsonantPlacement::ShallowCut => PositionData {
+                    angle: Degree::new(0.0),
+                    distance: word_radius - consonant_distance,
+                    angle_placement: AnglePlacement::Absolute,
+                },
+                ConsonantPlacement::DeepCut
+                | ConsonantPlacement::Inside
+                | ConsonantPlacement::OnLine => PositionData {
+                    angle: Degree::new(0.0),
+                    distance: 0.0,
+                    angle_placement: AnglePlacement::Absolute,
+                },
+            },
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+pub enum VocalPlacement {
+    Inside,
+    OnLine,
+    Outside,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Deserialize)]
+pub enum VocalDecoration {
+    None,
+    LineInside,
+    LineOutside,
+}
+
+impl VocalDecoration {
+    pub fn dots(&self) -> usize {
+        0
+    }
+
+    pub fn lines(&self) -> usize {
+        match self {
+            Self::LineOutside | Self::LineInside => 1,
+            _ => 0,
+        }
+    }
+}
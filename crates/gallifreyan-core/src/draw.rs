@@ -0,0 +1,447 @@
+use crate::alphabet::Letter;
+use crate::layout::{SentenceFrame, OUTER_CIRCLE_SIZE};
+use crate::math;
+use crate::math::angle::{Angle, Degree, Radian};
+use crate::math::{Intersection, IntersectionResult};
+use crate::svg::{Circle, Group, Line, Path, PathElement, SVGElement};
+use glam::Vec2;
+use itertools::Itertools;
+use log::error;
+
+/// A letter of a word with its position relative to the center of the word.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PlacedLetter {
+    pub letter: Letter,
+    /// See [`Letter::is_cutting`].
+    pub is_cutting: bool,
+    pub radius: f32,
+    pub angle: Degree,
+    pub position: Vec2,
+}
+
+pub fn sentence(radius: f32) -> SVGElement {
+    let mut group = Group::new();
+
+    group.push(Circle::new(radius + OUTER_CIRCLE_SIZE));
+    group.push(Circle::new(radius));
+
+    group.into()
+}
+
+/// Like [`sentence`] with the circles of the frame which are cut where they cross the circles of
+/// words which are placed outside or across the sentence.
+pub fn sentence_and_words(radius: f32, frame: SentenceFrame, words: &[math::Circle]) -> SVGElement {
+    let mut group = Group::new();
+
+    if frame == SentenceFrame::Double {
+        group.push(cut_circle(radius + OUTER_CIRCLE_SIZE, words));
+    }
+
+    if frame != SentenceFrame::None {
+        group.push(cut_circle(radius, words));
+    }
+
+    group.into()
+}
+
+/// Cutting letters split the word circle into arcs so both are drawn together.
+pub fn word_and_letters(
+    word_radius: f32,
+    letters: &[PlacedLetter],
+) -> (SVGElement, Vec<SVGElement>) {
+    let word_circle = math::Circle {
+        radius: word_radius,
+        position: Vec2::ZERO,
+    };
+
+    let mut word_cut_outs: Vec<[Vec2; 2]> = Vec::new();
+
+    let letter_elements = letters
+        .iter()
+        .map(|placed_letter| {
+            if !placed_letter.is_cutting {
+                return Circle::new(placed_letter.radius).into();
+            }
+
+            let letter_circle = math::Circle {
+                radius: placed_letter.radius,
+                position: placed_letter.position,
+            };
+
+            if let IntersectionResult::Two(a, b) = word_circle.intersection(&letter_circle) {
+                let sorted_intersections =
+                    sort_intersections_by_angle(word_circle, letter_circle, a, b);
+
+                word_cut_outs.push(sorted_intersections);
+
+                let letter_intersections = sorted_intersections
+                    .map(|pos| pos - letter_circle.position)
+                    .map(|pos| {
+                        Vec2::from_angle(-placed_letter.angle.to_radians().inner()).rotate(pos)
+                    });
+
+                generate_letter_path(placed_letter.radius, letter_intersections).into()
+            } else {
+                error!(
+                    "{:?} should intersect with word but it doesn't!",
+                    placed_letter.letter
+                );
+                Circle::new(placed_letter.radius).into()
+            }
+        })
+        .collect();
+
+    (
+        generate_cut_circle(word_radius, word_cut_outs),
+        letter_elements,
+    )
+}
+
+/// A tick across the circles of the sentence where the first word is placed.
+pub fn start_marker(sentence_radius: f32, angle: Degree) -> SVGElement {
+    let direction = Vec2::from_angle(angle.to_radians().inner()).rotate(Vec2::NEG_Y);
+
+    Line::new(
+        direction * sentence_radius,
+        direction * (sentence_radius + OUTER_CIRCLE_SIZE),
+    )
+    .into()
+}
+
+pub fn line_slot(position: Vec2) -> SVGElement {
+    Line::new(Vec2::ZERO, position.normalize_or_zero() * 10.0).into()
+}
+
+fn sort_intersections_by_angle(c1: math::Circle, c2: math::Circle, a: Vec2, b: Vec2) -> [Vec2; 2] {
+    let angle_a = Radian::angle_from_vec(a).to_degrees().normalize();
+    let angle_b = Radian::angle_from_vec(b).to_degrees().normalize();
+
+    let angle_origin = c1.position + Vec2::NEG_Y * c1.radius;
+    let distance = c2.position.distance(angle_origin) - c2.radius;
+    let is_angle_origin_inside_letter = distance <= 0.0;
+
+    if is_angle_origin_inside_letter ^ (angle_a <= angle_b) {
+        [a, b]
+    } else {
+        [b, a]
+    }
+}
+
+fn generate_arc_path(radius: f32, [start, end]: [Vec2; 2]) -> Path {
+    let start_angle = Radian::angle_from_vec(start).to_degrees().normalize();
+    let end_angle = Radian::angle_from_vec(end).to_degrees().normalize();
+
+    let is_large_arc = (end_angle - start_angle).inner().abs() > 180.0;
+    let large_arc_flag = !(is_large_arc ^ (start_angle < end_angle));
+
+    let mut path = Path::new();
+
+    path.push(PathElement::MoveTo(Vec2::new(start.x, -start.y)));
+    path.push(PathElement::Arc {
+        radius,
+        large_arc: large_arc_flag,
+        end: Vec2::new(end.x, -end.y),
+    });
+
+    path
+}
+
+/// The intersections are pairs of the start and end of the cut-outs in the order of their angles.
+fn generate_circle_path(radius: f32, intersections: Vec<Vec2>) -> Path {
+    intersections
+        .into_iter()
+        .circular_tuple_windows::<(_, _)>()
+        .skip(1)
+        .step_by(2)
+        .flat_map(|(start, end)| generate_arc_path(radius, [start, end]).elements)
+        .collect::<Vec<_>>()
+        .into()
+}
+
+fn cut_circle(radius: f32, circles: &[math::Circle]) -> SVGElement {
+    let circle = math::Circle {
+        radius,
+        position: Vec2::ZERO,
+    };
+
+    let cut_outs = circles
+        .iter()
+        .filter_map(|other| match circle.intersection(other) {
+            IntersectionResult::Two(a, b) => {
+                Some(sort_intersections_by_angle(circle, *other, a, b))
+            }
+            _ => None,
+        })
+        .collect();
+
+    generate_cut_circle(radius, cut_outs)
+}
+
+/// The cut-outs are ordered by their angle starting with the first one, so letters which are
+/// placed clockwise are drawn the same as counter-clockwise ones.
+fn generate_cut_circle(radius: f32, mut cut_outs: Vec<[Vec2; 2]>) -> SVGElement {
+    let Some([first, _]) = cut_outs.first().copied() else {
+        return Circle::new(radius).into();
+    };
+
+    let angle = |position: Vec2| Radian::angle_from_vec(position).to_degrees().inner();
+    let first_angle = angle(first);
+    cut_outs.sort_by(|[a, _], [b, _]| {
+        let angle_a = (angle(*a) - first_angle).rem_euclid(360.0);
+        let angle_b = (angle(*b) - first_angle).rem_euclid(360.0);
+        angle_a.total_cmp(&angle_b)
+    });
+
+    generate_circle_path(radius, cut_outs.into_iter().flatten().collect()).into()
+}
+
+fn generate_letter_path(letter_radius: f32, [end, start]: [Vec2; 2]) -> Path {
+    generate_arc_path(letter_radius, [start, end])
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::alphabet::Consonant;
+    use crate::svg::PathElement;
+
+    #[test]
+    fn should_not_cut_sentence_with_words_inside() {
+        let words = [math::Circle {
+            radius: 20.0,
+            position: Vec2::new(0.0, -50.0),
+        }];
+
+        let SVGElement::Group(group) = sentence_and_words(100.0, SentenceFrame::Double, &words)
+        else {
+            panic!("Wasn't a group!");
+        };
+
+        assert!(group
+            .elements
+            .iter()
+            .all(|it| matches!(it, SVGElement::Circle(_))));
+    }
+
+    #[test]
+    fn should_cut_sentence_where_words_cross_it() {
+        let words = [
+            math::Circle {
+                radius: 10.0,
+                position: Vec2::new(0.0, -95.0),
+            },
+            math::Circle {
+                radius: 20.0,
+                position: Vec2::new(105.0, 0.0),
+            },
+        ];
+
+        let SVGElement::Group(group) = sentence_and_words(100.0, SentenceFrame::Double, &words)
+        else {
+            panic!("Wasn't a group!");
+        };
+
+        let arcs = |element: &SVGElement| match element {
+            SVGElement::Path(path) => path
+                .elements
+                .iter()
+                .filter(|it| matches!(it, PathElement::Arc { .. }))
+                .count(),
+            _ => 0,
+        };
+
+        // only the second word crosses the outer circle
+        assert_eq!(arcs(&group.elements[0]), 1);
+        assert_eq!(arcs(&group.elements[1]), 2);
+    }
+
+    #[test]
+    fn should_cut_word_the_same_for_both_reading_directions() {
+        let placed_letter = |angle: f32| {
+            let angle = Degree::new(angle);
+            PlacedLetter {
+                letter: Letter::Consonant(Consonant::T),
+                is_cutting: true,
+                radius: 20.0,
+                angle,
+                position: Vec2::from_angle(angle.to_radians().inner()).rotate(Vec2::NEG_Y) * 100.0,
+            }
+        };
+
+        let letters = [
+            placed_letter(0.0),
+            placed_letter(120.0),
+            placed_letter(240.0),
+        ];
+        let mut reversed = letters;
+        reversed.reverse();
+
+        let arcs = |letters: &[PlacedLetter]| {
+            let SVGElement::Path(path) = word_and_letters(100.0, letters).0 else {
+                panic!("Wasn't a path!");
+            };
+
+            path.elements
+                .into_iter()
+                .tuples()
+                .map(|(start, end)| format!("{:?} {:?}", start, end))
+                .sorted()
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(arcs(&letters).len(), 3);
+        assert_eq!(arcs(&letters), arcs(&reversed));
+    }
+
+    #[test]
+    fn should_draw_sentence_frame() {
+        let circles = |frame| match sentence_and_words(100.0, frame, &[]) {
+            SVGElement::Group(group) => group.elements.len(),
+            _ => panic!("Wasn't a group!"),
+        };
+
+        assert_eq!(circles(SentenceFrame::Double), 2);
+        assert_eq!(circles(SentenceFrame::Single), 1);
+        assert_eq!(circles(SentenceFrame::None), 0);
+    }
+
+    #[test]
+    fn should_not_swap_intersections_for_non_overlapping_origin() {
+        let c1 = math::Circle {
+            radius: 10.0,
+            position: Default::default(),
+        };
+
+        let c2 = math::Circle {
+            radius: 5.0,
+            position: Vec2::new(c1.radius, 0.0),
+        };
+
+        let a = Vec2::new(8.75, -4.8412285);
+        let b = Vec2::new(8.75, 4.8412285);
+
+        let result = sort_intersections_by_angle(c1, c2, a, b);
+
+        assert_eq!(result, [a, b]);
+    }
+
+    #[test]
+    fn should_swap_intersections_for_non_overlapping_origin() {
+        let c1 = math::Circle {
+            radius: 10.0,
+            position: Default::default(),
+        };
+
+        let c2 = math::Circle {
+            radius: 5.0,
+            position: Vec2::new(c1.radius, 0.0),
+        };
+
+        let a = Vec2::new(8.75, 4.8412285);
+        let b = Vec2::new(8.75, -4.8412285);
+
+        let result = sort_intersections_by_angle(c1, c2, a, b);
+
+        assert_eq!(result, [b, a]);
+    }
+
+    #[test]
+    fn should_not_swap_intersections_for_overlapping_origin() {
+        let c1 = math::Circle {
+            radius: 10.0,
+            position: Default::default(),
+        };
+
+        let c2 = math::Circle {
+            radius: 5.0,
+            position: Vec2::new(0.0, -c1.radius),
+        };
+
+        let a = Vec2::new(-4.8412285, -8.75);
+        let b = Vec2::new(4.8412285, -8.75);
+
+        let result = sort_intersections_by_angle(c1, c2, a, b);
+
+        assert_eq!(result, [a, b]);
+    }
+
+    #[test]
+    fn should_swap_intersections_for_overlapping_origin() {
+        let c1 = math::Circle {
+            radius: 10.0,
+            position: Default::default(),
+        };
+
+        let c2 = math::Circle {
+            radius: 5.0,
+            position: Vec2::new(0.0, -c1.radius),
+        };
+
+        let a = Vec2::new(4.8412285, -8.75);
+        let b = Vec2::new(-4.8412285, -8.75);
+
+        let result = sort_intersections_by_angle(c1, c2, a, b);
+
+        assert_eq!(result, [b, a]);
+    }
+
+    #[test]
+    fn should_not_set_large_arc_flag_to_zero_for_non_overlapping_origin() {
+        let r = 5.0;
+        let a = Vec2::new(r, 0.0);
+        let b = Vec2::new(0.0, r);
+
+        let result = generate_arc_path(r, [a, b]).elements[1];
+
+        if let PathElement::Arc { large_arc, .. } = result {
+            assert!(!large_arc);
+        } else {
+            panic!("Wasn't an arc!");
+        }
+    }
+
+    #[test]
+    fn should_set_large_arc_flag_to_zero_for_non_overlapping_origin() {
+        let r = 5.0;
+        let a = Vec2::new(r, 0.0);
+        let b = Vec2::new(-r, -0.5);
+
+        let result = generate_arc_path(r, [a, b]).elements[1];
+
+        if let PathElement::Arc { large_arc, .. } = result {
+            assert!(large_arc);
+        } else {
+            panic!("Wasn't an arc!");
+        }
+    }
+
+    #[test]
+    fn should_not_set_large_arc_flag_to_zero_for_overlapping_origin() {
+        let r = 5.0;
+        let a = Vec2::new(-r, -0.5);
+        let b = Vec2::new(r, -0.5);
+
+        let result = generate_arc_path(r, [a, b]).elements[1];
+
+        if let PathElement::Arc { large_arc, .. } = result {
+            assert!(!large_arc);
+        } else {
+            panic!("Wasn't an arc!");
+        }
+    }
+
+    #[test]
+    fn should_set_large_arc_flag_to_zero_for_overlapping_origin() {
+        let r = 5.0;
+        let a = Vec2::new(-r, 0.0);
+        let b = Vec2::new(0.0, r);
+
+        let result = generate_arc_path(r, [a, b]).elements[1];
+
+        if let PathElement::Arc { large_arc, .. } = result {
+            assert!(large_arc);
+        } else {
+            panic!("Wasn't an arc!");
+        }
+    }
+}
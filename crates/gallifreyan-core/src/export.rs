@@ -0,0 +1,129 @@
+use crate::layout::{OUTER_CIRCLE_SIZE, SVG_SIZE};
+use crate::model;
+use crate::svg::{CSSRule, Group, Rgb, Selector, StrokeLineCap, Style, StyleRule, Title, SVG};
+use glam::{Affine2, Mat2, Vec2};
+
+pub const FILL_CLASS: &str = "fill";
+pub const STROKE_CLASS: &str = "stroke";
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ExportOptions {
+    pub color: Rgb,
+    pub stroke_width: f32,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            color: Rgb::BLACK,
+            stroke_width: 1.0,
+        }
+    }
+}
+
+fn create_style(options: &ExportOptions) -> Style {
+    let mut style = Style::new();
+
+    let mut stroke_rule = StyleRule::new();
+    stroke_rule
+        .selectors
+        .push(Selector::Class(STROKE_CLASS.to_string()));
+    stroke_rule.rules.push(CSSRule::Stroke(Some(options.color)));
+    stroke_rule.rules.push(CSSRule::Fill(None));
+    stroke_rule
+        .rules
+        .push(CSSRule::StrokeWidth(options.stroke_width));
+    stroke_rule
+        .rules
+        .push(CSSRule::StrokeLineCap(StrokeLineCap::Round));
+
+    style.push(stroke_rule);
+
+    let mut fill_rule = StyleRule::new();
+    fill_rule
+        .selectors
+        .push(Selector::Class(FILL_CLASS.to_string()));
+    fill_rule.rules.push(CSSRule::Fill(Some(options.color)));
+    fill_rule.rules.push(CSSRule::Stroke(None));
+
+    style.push(fill_rule);
+
+    style
+}
+
+/// The group all elements of the sentence are placed in.
+pub fn create_root_group() -> Group {
+    let mut group = Group::new();
+
+    // mirror along y-axis because svg uses a mirrored y-axis
+    group.affine2 = Affine2 {
+        translation: Vec2::ZERO,
+        matrix2: Mat2::from_cols(Vec2::X, Vec2::NEG_Y),
+    };
+
+    group
+}
+
+pub fn create_document(title: &str, root_group: Group, options: &ExportOptions) -> SVG {
+    let mut svg = SVG::new(SVG_SIZE);
+
+    svg.push(Title(title.to_string()));
+    svg.push(create_style(options));
+    svg.push(root_group);
+
+    svg
+}
+
+/// Like [`create_document`] but centers the document on the content between `min` and `max` of
+/// the root group and scales it so that the content has the same margin as a single sentence.
+pub fn create_fitted_document(
+    title: &str,
+    mut root_group: Group,
+    min: Vec2,
+    max: Vec2,
+    options: &ExportOptions,
+) -> SVG {
+    let center = (min + max) / 2.0;
+    let sentence_size = (model::Sentence::radius() + OUTER_CIRCLE_SIZE) * 2.0;
+    let size = (max - min).max_element() * SVG_SIZE / sentence_size;
+
+    // the y-axis of the root group is mirrored, subtracting avoids writing `-0`
+    root_group.affine2.translation = Vec2::new(0.0 - center.x, center.y);
+
+    let mut svg = create_document(title, root_group, options);
+    svg.size = size;
+
+    svg
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_fit_document_to_content() {
+        let extent = Vec2::splat(model::Sentence::radius() + OUTER_CIRCLE_SIZE);
+
+        let single = create_fitted_document(
+            "",
+            create_root_group(),
+            -extent,
+            extent,
+            &ExportOptions::default(),
+        );
+        let row = create_fitted_document(
+            "",
+            create_root_group(),
+            -extent,
+            extent + Vec2::new(extent.x * 4.0, 0.0),
+            &ExportOptions::default(),
+        );
+
+        assert_eq!(single.size, SVG_SIZE);
+        assert_eq!(row.size, SVG_SIZE * 3.0);
+        assert!(row.to_string().contains(&format!(
+            "transform=\"matrix(1 0 0 -1 {} 0)\"",
+            -extent.x * 2.0
+        )));
+    }
+}
@@ -0,0 +1,558 @@
+use crate::alphabet::{Alphabet, Letter, VocalPlacement};
+use crate::math::angle::{Angle, Degree, Radian};
+use glam::{Affine2, Affine3A, Mat2, Quat, Vec2, Vec3};
+use std::f32::consts::{PI, TAU};
+
+pub const SVG_SIZE: f32 = 1000.0;
+pub const OUTER_CIRCLE_SIZE: f32 = 10.0;
+
+/// Where the first word of a sentence and the first letter of a word is placed and in which
+/// direction the others follow.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct ReadingDirection {
+    /// `0°` is at 6 o'clock.
+    pub start_angle: Degree,
+    pub clockwise: bool,
+}
+
+impl ReadingDirection {
+    /// Angle of the element at the index when the elements are spread evenly around a circle.
+    pub fn angle(&self, number_of_elements: usize, index: usize) -> Degree {
+        let step = index as f32 * (360.0 / number_of_elements as f32);
+
+        if self.clockwise {
+            Degree::new(self.start_angle.inner() - step)
+        } else {
+            Degree::new(self.start_angle.inner() + step)
+        }
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct PositionData {
+    pub angle: Degree,
+    pub distance: f32,
+    pub angle_placement: AnglePlacement,
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum AnglePlacement {
+    #[default]
+    Absolute,
+    Relative,
+}
+
+impl PositionData {
+    /// Rotation and translation relative to the parent. The `z` coordinate is passed through.
+    pub fn rotation_translation(&self, z: f32) -> (Quat, Vec3) {
+        let translation = Vec3::new(0.0, -self.distance, z);
+        let rotation = Quat::from_rotation_z(self.angle.to_radians().inner());
+
+        match self.angle_placement {
+            AnglePlacement::Absolute => (Quat::IDENTITY, rotation * translation),
+            AnglePlacement::Relative => (rotation, rotation * translation),
+        }
+    }
+
+    pub fn affine2(&self, z: f32) -> Affine2 {
+        let (rotation, translation) = self.rotation_translation(z);
+        let affine3 = Affine3A::from_scale_rotation_translation(Vec3::ONE, rotation, translation);
+
+        Affine2 {
+            matrix2: Mat2::from_cols(affine3.x_axis.truncate(), affine3.y_axis.truncate()),
+            translation: affine3.translation.truncate(),
+        }
+    }
+}
+
+impl PositionData {
+    /// Absolute position data for a translation relative to the parent.
+    pub fn from_translation(translation: Vec2) -> Self {
+        let distance = translation.length();
+
+        Self {
+            angle: if distance == 0.0 {
+                Degree::new(0.0)
+            } else {
+                Radian::angle_from_vec(translation).to_degrees().normalize()
+            },
+            distance,
+            angle_placement: AnglePlacement::Absolute,
+        }
+    }
+}
+
+/// Part of the letter radius which the dots can be moved inwards at most.
+const MAX_DOT_INSET_RATIO: f32 = 0.9;
+
+/// How dots and lines are spread over the side of a letter.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DecorationLayout {
+    /// Angle between two neighboring dots or lines.
+    pub spacing: Degree,
+    /// `180°` is the side of the letter facing the center of the word.
+    pub center_angle: Degree,
+    /// Radius of a dot relative to the radius of the letter.
+    pub dot_size: f32,
+    /// Distance of the dots from the letter line in dot radii.
+    pub inset: f32,
+    /// Spreads the decorations over the part of the letter which is neither cut off by the word
+    /// nor covered by a nested vocal.
+    pub auto_fit: bool,
+}
+
+impl Default for DecorationLayout {
+    fn default() -> Self {
+        Self {
+            spacing: Degree::new(45.0),
+            center_angle: Degree::new(180.0),
+            dot_size: 0.1,
+            inset: 1.5,
+            auto_fit: false,
+        }
+    }
+}
+
+/// The part of a letter which is free for decorations, as half angles around the center angle.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DecorationSpace {
+    /// Outside of it the letter is cut off by the word.
+    pub available: Degree,
+    /// Covered by a nested vocal.
+    pub blocked: Degree,
+}
+
+impl Default for DecorationSpace {
+    fn default() -> Self {
+        Self {
+            available: Degree::new(180.0),
+            blocked: Degree::new(0.0),
+        }
+    }
+}
+
+impl DecorationLayout {
+    pub fn dot_radius(&self, letter_radius: f32) -> f32 {
+        letter_radius * self.dot_size
+    }
+
+    /// Larger insets would move the dots across the center of the letter.
+    pub fn max_inset(&self) -> f32 {
+        MAX_DOT_INSET_RATIO / self.dot_size
+    }
+
+    fn dot_distance(&self, letter_radius: f32) -> f32 {
+        letter_radius - self.dot_radius(letter_radius) * self.inset.min(self.max_inset())
+    }
+
+    /// Only used with [`DecorationLayout::auto_fit`].
+    pub fn space(
+        &self,
+        alphabet: &Alphabet,
+        letter: Letter,
+        letter_radius: f32,
+        letter_distance: f32,
+        word_radius: f32,
+    ) -> DecorationSpace {
+        let dot_distance = self.dot_distance(letter_radius);
+        if dot_distance <= 0.0 {
+            return DecorationSpace::default();
+        }
+
+        let margin = (self.dot_radius(letter_radius) / dot_distance).to_degrees();
+
+        // law of cosines with the angle at the center of the letter
+        let half_angle = |distance: f32, radius: f32, other_radius: f32| {
+            let cos = (distance * distance + radius * radius - other_radius * other_radius)
+                / (2.0 * distance * radius);
+            cos.clamp(-1.0, 1.0).acos().to_degrees()
+        };
+
+        let available = if letter.is_cutting(alphabet) && letter_distance > 0.0 {
+            half_angle(letter_distance, letter_radius, word_radius) - margin
+        } else {
+            180.0
+        };
+
+        let blocked = match letter {
+            Letter::ConsonantWithVocal { vocal, .. }
+                if alphabet.vocal_placement(vocal) == VocalPlacement::Inside =>
+            {
+                let vocal_radius = vocal.nested_radius(letter_radius);
+                half_angle(letter_radius, dot_distance, vocal_radius) + margin
+            }
+            _ => 0.0,
+        };
+
+        DecorationSpace {
+            available: Degree::new(available),
+            blocked: Degree::new(blocked),
+        }
+    }
+
+    pub fn dot_position_data(
+        &self,
+        letter_radius: f32,
+        number_of_dots: usize,
+        index: usize,
+        space: &DecorationSpace,
+    ) -> PositionData {
+        PositionData {
+            distance: self.dot_distance(letter_radius),
+            angle: Degree::new(
+                self.center_angle.inner() + self.offset(number_of_dots, index, space),
+            ),
+            angle_placement: AnglePlacement::Absolute,
+        }
+    }
+
+    /// Lines which point outside start on the opposite side of the letter.
+    pub fn line_slot_position_data(
+        &self,
+        letter_radius: f32,
+        number_of_lines: usize,
+        index: usize,
+        point_outside: bool,
+        space: &DecorationSpace,
+    ) -> PositionData {
+        let (center_angle, space) = if point_outside {
+            (
+                self.center_angle.inner() - 180.0,
+                DecorationSpace::default(),
+            )
+        } else {
+            (self.center_angle.inner(), *space)
+        };
+
+        PositionData {
+            distance: letter_radius,
+            angle: Degree::new(center_angle + self.offset(number_of_lines, index, &space)),
+            angle_placement: AnglePlacement::Absolute,
+        }
+    }
+
+    /// Angle of the decoration relative to the center angle.
+    fn offset(&self, number: usize, index: usize, space: &DecorationSpace) -> f32 {
+        let centered = |spacing: f32, number: usize, index: usize| {
+            index as f32 * spacing - ((number - 1) as f32 * spacing) / 2.0
+        };
+
+        let spacing = self.spacing.inner();
+
+        if !self.auto_fit {
+            return centered(spacing, number, index);
+        }
+
+        let available = space.available.inner();
+        let blocked = space.blocked.inner();
+
+        if blocked <= 0.0 || available <= blocked {
+            return centered(spacing.min(2.0 * available / number as f32), number, index);
+        }
+
+        // the decorations are split between both sides of the nested vocal
+        let before = number / 2;
+        let side_width = available - blocked;
+        let side_center = blocked + side_width / 2.0;
+
+        if index < before {
+            let spacing = spacing.min(side_width / before as f32);
+            -side_center + centered(spacing, before, index)
+        } else {
+            let after = number - before;
+            let spacing = spacing.min(side_width / after as f32);
+            side_center + centered(spacing, after, index - before)
+        }
+    }
+}
+
+/// The circles around the words of a sentence.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum SentenceFrame {
+    #[default]
+    Double,
+    Single,
+    /// Only the words are drawn, e.g. for logos with a single word.
+    None,
+}
+
+impl SentenceFrame {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SentenceFrame::Double => "double",
+            SentenceFrame::Single => "single",
+            SentenceFrame::None => "none",
+        }
+    }
+}
+
+impl TryFrom<&str> for SentenceFrame {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "double" => Ok(SentenceFrame::Double),
+            "single" => Ok(SentenceFrame::Single),
+            "none" => Ok(SentenceFrame::None),
+            _ => Err(format!("'{}' is not a valid sentence frame!", value)),
+        }
+    }
+}
+
+/// How the sentences of a text with multiple sentences are placed next to each other.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum SentenceArrangement {
+    #[default]
+    Row,
+    Grid,
+    /// Around a larger paragraph circle, clockwise from the top.
+    Paragraph,
+    /// Keeps the positions of existing sentences, new sentences start in a row.
+    Free,
+}
+
+impl SentenceArrangement {
+    /// Distance between the centers of two neighboring sentences.
+    fn spacing(sentence_radius: f32) -> f32 {
+        (sentence_radius + OUTER_CIRCLE_SIZE) * 2.0 * 1.1
+    }
+
+    pub fn translation(
+        &self,
+        sentence_radius: f32,
+        number_of_sentences: usize,
+        index: usize,
+    ) -> Vec2 {
+        let spacing = Self::spacing(sentence_radius);
+        let centered = |index: usize, count: usize| index as f32 - (count - 1) as f32 / 2.0;
+
+        match self {
+            Self::Row | Self::Free => {
+                Vec2::new(centered(index, number_of_sentences) * spacing, 0.0)
+            }
+            Self::Grid => {
+                let columns = (number_of_sentences as f32).sqrt().ceil().max(1.0) as usize;
+                let rows = number_of_sentences.div_ceil(columns).max(1);
+
+                // the first row is on top and the y-axis points up
+                Vec2::new(
+                    centered(index % columns, columns) * spacing,
+                    -centered(index / columns, rows) * spacing,
+                )
+            }
+            Self::Paragraph => {
+                let distance = Self::paragraph_distance(sentence_radius, number_of_sentences);
+                let angle = TAU * index as f32 / number_of_sentences as f32;
+
+                Vec2::new(angle.sin(), angle.cos()) * distance
+            }
+        }
+    }
+
+    pub fn position_data(
+        &self,
+        sentence_radius: f32,
+        number_of_sentences: usize,
+        index: usize,
+    ) -> PositionData {
+        PositionData::from_translation(self.translation(
+            sentence_radius,
+            number_of_sentences,
+            index,
+        ))
+    }
+
+    /// Distance of the sentence centers to the center of the paragraph.
+    fn paragraph_distance(sentence_radius: f32, number_of_sentences: usize) -> f32 {
+        if number_of_sentences < 2 {
+            return 0.0;
+        }
+
+        // neighboring sentences are one spacing apart on the chord
+        Self::spacing(sentence_radius) / (2.0 * (PI / number_of_sentences as f32).sin())
+    }
+
+    /// Radius of the paragraph circle around all sentences.
+    pub fn paragraph_radius(sentence_radius: f32, number_of_sentences: usize) -> f32 {
+        Self::paragraph_distance(sentence_radius, number_of_sentences)
+            + Self::spacing(sentence_radius) / 2.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::alphabet::{Consonant, Vocal};
+
+    const RADIUS: f32 = 100.0;
+
+    #[test]
+    fn should_convert_sentence_frame_names() {
+        for frame in [
+            SentenceFrame::Double,
+            SentenceFrame::Single,
+            SentenceFrame::None,
+        ] {
+            assert_eq!(SentenceFrame::try_from(frame.as_str()), Ok(frame));
+        }
+
+        assert!(SentenceFrame::try_from("triple").is_err());
+    }
+
+    #[test]
+    fn should_follow_reading_direction() {
+        let default = ReadingDirection::default();
+        let clockwise_from_top = ReadingDirection {
+            start_angle: Degree::new(180.0),
+            clockwise: true,
+        };
+
+        assert_eq!(default.angle(4, 0), Degree::new(0.0));
+        assert_eq!(default.angle(4, 1), Degree::new(90.0));
+        assert_eq!(clockwise_from_top.angle(4, 0), Degree::new(180.0));
+        assert_eq!(clockwise_from_top.angle(4, 1), Degree::new(90.0));
+        assert_eq!(clockwise_from_top.angle(4, 3), Degree::new(-90.0));
+    }
+
+    #[test]
+    fn should_keep_default_decoration_layout() {
+        let layout = DecorationLayout::default();
+        let space = DecorationSpace::default();
+
+        let angles: Vec<f32> = (0..3)
+            .map(|index| {
+                layout
+                    .dot_position_data(100.0, 3, index, &space)
+                    .angle
+                    .inner()
+            })
+            .collect();
+
+        assert_eq!(angles, [135.0, 180.0, 225.0]);
+        assert_eq!(layout.dot_position_data(100.0, 1, 0, &space).distance, 85.0);
+        assert_eq!(
+            layout
+                .line_slot_position_data(100.0, 1, 0, true, &space)
+                .angle
+                .inner(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn should_fit_decorations_around_nested_vocal() {
+        let layout = DecorationLayout {
+            auto_fit: true,
+            ..Default::default()
+        };
+        let letter = Letter::ConsonantWithVocal {
+            consonant: Consonant::J,
+            vocal: Vocal::O,
+        };
+
+        let space = layout.space(&Alphabet::sherman(), letter, 100.0, 300.0, 400.0);
+        assert_eq!(space.available.inner(), 180.0);
+        assert!(space.blocked.inner() > 0.0);
+
+        let angles: Vec<f32> = (0..2)
+            .map(|index| {
+                layout
+                    .dot_position_data(100.0, 2, index, &space)
+                    .angle
+                    .inner()
+            })
+            .collect();
+
+        let blocked = 180.0 - space.blocked.inner()..=180.0 + space.blocked.inner();
+        assert!(angles.iter().all(|angle| !blocked.contains(angle)));
+        assert!(angles[0] < 180.0 && angles[1] > 180.0);
+    }
+
+    #[test]
+    fn should_fit_decorations_into_cut_letter() {
+        let layout = DecorationLayout {
+            spacing: Degree::new(90.0),
+            auto_fit: true,
+            ..Default::default()
+        };
+        let letter = Letter::Consonant(Consonant::T);
+
+        let space = layout.space(&Alphabet::sherman(), letter, 50.0, 400.0, 400.0);
+        assert!(space.available.inner() < 90.0);
+
+        let last = layout.dot_position_data(50.0, 3, 2, &space).angle.inner();
+        assert!(last - 180.0 <= space.available.inner());
+    }
+
+    #[test]
+    fn should_keep_dots_inside_of_letter_for_large_insets() {
+        let layout = DecorationLayout {
+            dot_size: 0.5,
+            inset: 5.0,
+            auto_fit: true,
+            ..Default::default()
+        };
+        let letter = Letter::Consonant(Consonant::T);
+
+        let space = layout.space(&Alphabet::sherman(), letter, 50.0, 400.0, 400.0);
+        assert!(space.available.inner().is_finite());
+
+        let position_data = layout.dot_position_data(50.0, 3, 0, &space);
+        assert!(position_data.distance > 0.0);
+        assert!(position_data.angle.inner().is_finite());
+
+        let empty = layout.space(&Alphabet::sherman(), letter, 0.0, 400.0, 400.0);
+        assert_eq!(empty, DecorationSpace::default());
+    }
+
+    #[test]
+    fn should_center_row() {
+        let arrangement = SentenceArrangement::Row;
+
+        let first = arrangement.translation(RADIUS, 3, 0);
+        let second = arrangement.translation(RADIUS, 3, 1);
+        let third = arrangement.translation(RADIUS, 3, 2);
+
+        assert_eq!(second, Vec2::ZERO);
+        assert_eq!(first, -third);
+        assert!(third.x > (RADIUS + OUTER_CIRCLE_SIZE) * 2.0);
+    }
+
+    #[test]
+    fn should_fill_grid_rows_from_the_top() {
+        let arrangement = SentenceArrangement::Grid;
+
+        let translations: Vec<Vec2> = (0..4)
+            .map(|index| arrangement.translation(RADIUS, 4, index))
+            .collect();
+
+        assert_eq!(translations[0].y, translations[1].y);
+        assert!(translations[0].y > translations[2].y);
+        assert!(translations[0].x < translations[1].x);
+        assert_eq!(translations[0].x, translations[2].x);
+    }
+
+    #[test]
+    fn should_place_paragraph_clockwise_from_top() {
+        let arrangement = SentenceArrangement::Paragraph;
+
+        let top = arrangement.translation(RADIUS, 4, 0);
+        let right = arrangement.translation(RADIUS, 4, 1);
+
+        assert!(top.y > 0.0 && top.x.abs() < 0.001);
+        assert!(right.x > 0.0 && right.y.abs() < 0.001);
+        assert!(SentenceArrangement::paragraph_radius(RADIUS, 4) > top.length() + RADIUS);
+        assert_eq!(arrangement.translation(RADIUS, 1, 0), Vec2::ZERO);
+    }
+
+    #[test]
+    fn should_convert_translation_into_position_data() {
+        let translation = Vec2::new(30.0, 40.0);
+
+        let position_data = PositionData::from_translation(translation);
+        let (_, result) = position_data.rotation_translation(0.0);
+
+        assert_eq!(position_data.distance, 50.0);
+        assert!((result.truncate() - translation).length() < 0.001);
+    }
+}
@@ -0,0 +1,12 @@
+//! Bevy independent model of Gallifreyan sentences with their layout and svg generation.
+
+pub mod alphabet;
+pub mod draw;
+pub mod export;
+pub mod layout;
+pub mod math;
+pub mod model;
+pub mod normalization;
+pub mod phonetic;
+pub mod script;
+pub mod svg;
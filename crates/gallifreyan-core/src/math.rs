@@ -1,6 +1,6 @@
 pub mod angle;
 
-use bevy::math::{Quat, Vec2};
+use glam::{Quat, Vec2};
 
 pub trait Intersection<T> {
     fn intersection(&self, other: &T) -> IntersectionResult;
@@ -1,4 +1,4 @@
-use bevy::math::Vec2;
+use glam::Vec2;
 
 pub trait Angle: Copy + PartialEq + PartialOrd {
     fn inner(self) -> f32;
@@ -160,7 +160,6 @@ pub fn clamp_angle(angle: f32, min: f32, max: f32) -> f32 {
 #[cfg(test)]
 mod test {
     use super::*;
-    use bevy_prototype_lyon::prelude::tess::geom::euclid::approxeq::ApproxEq;
 
     #[test]
     fn should_clamp_angles_closer_to_max() {
@@ -242,7 +241,7 @@ mod test {
         let expected = std::f32::consts::FRAC_PI_2;
 
         assert!(
-            result.approx_eq(&expected),
+            (result - expected).abs() < 1e-6,
             "Expected {} but got {}",
             expected,
             result
@@ -255,7 +254,7 @@ mod test {
         let expected = -std::f32::consts::FRAC_PI_2;
 
         assert!(
-            result.approx_eq(&expected),
+            (result - expected).abs() < 1e-6,
             "Expected {} but got {}",
             expected,
             result
@@ -268,7 +267,7 @@ mod test {
         let expected = std::f32::consts::PI;
 
         assert!(
-            result.approx_eq(&expected),
+            (result - expected).abs() < 1e-6,
             "Expected {} but got {}",
             expected,
             result
@@ -0,0 +1,606 @@
+use crate::alphabet;
+use crate::alphabet::{
+    Alphabet, ConsonantPlacement, Nesting, Vocal, VocalPlacement, NESTED_LETTER_TEXT_DELIMITER,
+};
+use crate::draw;
+use crate::draw::PlacedLetter;
+use crate::export;
+use crate::export::{ExportOptions, FILL_CLASS, STROKE_CLASS};
+use crate::layout::{
+    AnglePlacement, DecorationLayout, DecorationSpace, PositionData, ReadingDirection,
+    SentenceFrame, OUTER_CIRCLE_SIZE, SVG_SIZE,
+};
+use crate::math;
+use crate::math::angle::Degree;
+use crate::script::ScriptElement;
+use crate::svg::{Circle, SVGElement, SVG};
+
+/// Depth of the drawn elements which doesn't change the svg but has to match the one of the app.
+const ELEMENT_Z: f32 = 0.1;
+
+pub const SENTENCE_KIND: &str = "sentence";
+pub const WORD_KIND: &str = "word";
+pub const LETTER_KIND: &str = "letter";
+pub const POSITION_CORRECTION_KIND: &str = "position correction";
+pub const DOT_KIND: &str = "dot";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Sentence {
+    pub text: String,
+    pub radius: f32,
+    pub position_data: PositionData,
+    pub words: Vec<Word>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Word {
+    pub text: String,
+    pub radius: f32,
+    pub position_data: PositionData,
+    pub letters: Vec<Letter>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Letter {
+    pub text: String,
+    pub letter: alphabet::Letter,
+    pub radius: f32,
+    pub position_data: PositionData,
+    pub nested: Option<Box<NestedVocal>>,
+    pub dots: Vec<Dot>,
+    pub line_slots: Vec<LineSlot>,
+}
+
+/// Vocals which are placed outside of the word are moved back onto the word line by the position
+/// correction.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NestedVocal {
+    pub position_correction: Option<PositionData>,
+    pub vocal: Letter,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Dot {
+    pub radius: f32,
+    pub position_data: PositionData,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct LineSlot {
+    pub position_data: PositionData,
+}
+
+impl Sentence {
+    pub fn radius() -> f32 {
+        SVG_SIZE * 0.9 / 2.0
+    }
+
+    pub fn position_data() -> PositionData {
+        PositionData {
+            angle: Degree::new(0.0),
+            distance: 0.0,
+            angle_placement: AnglePlacement::Absolute,
+        }
+    }
+
+    pub fn new(
+        text: &str,
+        nesting: &impl Nesting,
+        alphabet: &Alphabet,
+        reading_direction: ReadingDirection,
+    ) -> Self {
+        let radius = Self::radius();
+        let words: Vec<&str> = text.split_whitespace().collect();
+
+        Self {
+            text: text.to_string(),
+            radius,
+            position_data: Self::position_data(),
+            words: words
+                .iter()
+                .enumerate()
+                .map(|(index, word)| {
+                    Word::new(
+                        word,
+                        radius,
+                        words.len(),
+                        index,
+                        nesting,
+                        alphabet,
+                        reading_direction,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// Sanitizes raw user input and nests all vocals without rules, no vocals with empty rules
+    /// and otherwise the ones matching [`alphabet::parse_rules_string`].
+    pub fn from_input(
+        text: &str,
+        nesting_rules: Option<&str>,
+        alphabet: &Alphabet,
+        reading_direction: ReadingDirection,
+    ) -> Result<Self, String> {
+        let text = alphabet::sanitize_text_input(text, alphabet);
+
+        Ok(match nesting_rules {
+            None => Self::new(&text, &true, alphabet, reading_direction),
+            Some("") => Self::new(&text, &false, alphabet, reading_direction),
+            Some(rules) => Self::new(
+                &text,
+                &alphabet::parse_rules_string(rules, alphabet)?,
+                alphabet,
+                reading_direction,
+            ),
+        })
+    }
+
+    /// The alphabet decides which letters cut the word circle.
+    pub fn to_svg(&self, alphabet: &Alphabet) -> SVG {
+        self.to_svg_with_options(alphabet, &ExportOptions::default())
+    }
+
+    pub fn to_svg_with_options(&self, alphabet: &Alphabet, options: &ExportOptions) -> SVG {
+        let mut root_group = export::create_root_group();
+        root_group.push(self.to_element(alphabet).to_group());
+
+        export::create_document(&self.text, root_group, options)
+    }
+
+    pub fn to_element(&self, alphabet: &Alphabet) -> ScriptElement {
+        let word_circles: Vec<math::Circle> = self
+            .words
+            .iter()
+            .map(|word| math::Circle {
+                radius: word.radius,
+                position: word
+                    .position_data
+                    .rotation_translation(ELEMENT_Z)
+                    .1
+                    .truncate(),
+            })
+            .collect();
+
+        let mut element = ScriptElement::new(SENTENCE_KIND, &self.text, self.position_data)
+            .with_shape(
+                draw::sentence_and_words(self.radius, SentenceFrame::Double, &word_circles),
+                STROKE_CLASS,
+            );
+
+        for word in &self.words {
+            element.push(word.to_element(alphabet));
+        }
+
+        element
+    }
+}
+
+impl Word {
+    pub fn radius(sentence_radius: f32, number_of_words: usize) -> f32 {
+        (sentence_radius * 0.75) / (1.0 + number_of_words as f32 / 2.0)
+    }
+
+    /// Without a frame a single word is the root and fills the space of the sentence.
+    pub fn framed_radius(
+        frame: SentenceFrame,
+        sentence_radius: f32,
+        number_of_words: usize,
+    ) -> f32 {
+        if frame == SentenceFrame::None && number_of_words == 1 {
+            sentence_radius + OUTER_CIRCLE_SIZE
+        } else {
+            Self::radius(sentence_radius, number_of_words)
+        }
+    }
+
+    pub fn position_data(
+        reading_direction: ReadingDirection,
+        sentence_radius: f32,
+        number_of_words: usize,
+        index: usize,
+    ) -> PositionData {
+        PositionData {
+            distance: if number_of_words > 1 {
+                sentence_radius - Self::radius(sentence_radius, number_of_words) * 1.5
+            } else {
+                0.0
+            },
+            angle: reading_direction.angle(number_of_words, index),
+            angle_placement: AnglePlacement::Absolute,
+        }
+    }
+
+    pub fn new(
+        text: &str,
+        sentence_radius: f32,
+        number_of_words: usize,
+        index: usize,
+        nesting: &impl Nesting,
+        alphabet: &Alphabet,
+        reading_direction: ReadingDirection,
+    ) -> Self {
+        let radius = Self::radius(sentence_radius, number_of_words);
+        let letters = alphabet::create_letters_from_word(text, nesting, alphabet);
+
+        Self {
+            text: text.to_string(),
+            radius,
+            position_data: Self::position_data(
+                reading_direction,
+                sentence_radius,
+                number_of_words,
+                index,
+            ),
+            letters: letters
+                .iter()
+                .enumerate()
+                .map(|(index, (text, letter))| {
+                    Letter::new(
+                        text,
+                        *letter,
+                        radius,
+                        letters.len(),
+                        index,
+                        alphabet,
+                        reading_direction,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    fn to_element(&self, alphabet: &Alphabet) -> ScriptElement {
+        let placed_letters: Vec<PlacedLetter> = self
+            .letters
+            .iter()
+            .map(|letter| PlacedLetter {
+                letter: letter.letter,
+                is_cutting: letter.letter.is_cutting(alphabet),
+                radius: letter.radius,
+                angle: letter.position_data.angle,
+                position: letter
+                    .position_data
+                    .rotation_translation(ELEMENT_Z)
+                    .1
+                    .truncate(),
+            })
+            .collect();
+
+        let (word_element, letter_elements) = draw::word_and_letters(self.radius, &placed_letters);
+
+        let mut element = ScriptElement::new(WORD_KIND, &self.text, self.position_data)
+            .with_shape(word_element, STROKE_CLASS);
+
+        for (letter, letter_element) in self.letters.iter().zip(letter_elements) {
+            element.push(letter.to_element(letter_element));
+        }
+
+        element
+    }
+}
+
+impl Letter {
+    pub fn new(
+        text: &str,
+        letter: alphabet::Letter,
+        word_radius: f32,
+        number_of_letters: usize,
+        index: usize,
+        alphabet: &Alphabet,
+        reading_direction: ReadingDirection,
+    ) -> Self {
+        let radius = letter.radius(word_radius, number_of_letters);
+        let position_data = letter.position_data(
+            alphabet,
+            reading_direction,
+            word_radius,
+            number_of_letters,
+            index,
+        );
+
+        let nested = match letter {
+            alphabet::Letter::ConsonantWithVocal { consonant, vocal } => {
+                let nested_text = text
+                    .split_once(NESTED_LETTER_TEXT_DELIMITER)
+                    .map_or(text, |(_, nested_text)| nested_text);
+
+                Some(Box::new(NestedVocal::new(
+                    nested_text,
+                    vocal,
+                    alphabet.consonant_placement(consonant),
+                    radius,
+                    position_data.distance,
+                    word_radius,
+                    alphabet,
+                )))
+            }
+            alphabet::Letter::Consonant(_) | alphabet::Letter::Vocal(_) => None,
+        };
+
+        Self {
+            text: text.to_string(),
+            letter,
+            radius,
+            position_data,
+            nested,
+            dots: Self::create_dots(letter, radius, alphabet),
+            line_slots: Self::create_line_slots(letter, radius, alphabet),
+        }
+    }
+
+    fn create_dots(letter: alphabet::Letter, radius: f32, alphabet: &Alphabet) -> Vec<Dot> {
+        let number_of_dots = letter.dots(alphabet);
+
+        (0..number_of_dots)
+            .map(|index| Dot {
+                radius: Dot::radius(radius),
+                position_data: Dot::position_data(radius, number_of_dots, index),
+            })
+            .collect()
+    }
+
+    fn create_line_slots(
+        letter: alphabet::Letter,
+        radius: f32,
+        alphabet: &Alphabet,
+    ) -> Vec<LineSlot> {
+        let number_of_lines = letter.lines(alphabet);
+
+        (0..number_of_lines)
+            .map(|index| LineSlot {
+                position_data: LineSlot::position_data(
+                    radius,
+                    number_of_lines,
+                    index,
+                    letter.line_points_outside(alphabet),
+                ),
+            })
+            .collect()
+    }
+
+    /// Line slots are only helpers for drawing lines and are not part of the svg.
+    fn to_element(&self, shape: SVGElement) -> ScriptElement {
+        let mut element = ScriptElement::new(LETTER_KIND, &self.text, self.position_data)
+            .with_shape(shape, STROKE_CLASS);
+
+        if let Some(nested) = &self.nested {
+            element.push(nested.to_element());
+        }
+
+        for dot in &self.dots {
+            element.push(
+                ScriptElement::new(DOT_KIND, "", dot.position_data)
+                    .with_shape(Circle::new(dot.radius), FILL_CLASS),
+            );
+        }
+
+        element
+    }
+}
+
+impl NestedVocal {
+    pub fn position_correction(consonant_distance: f32) -> PositionData {
+        PositionData {
+            angle: Degree::new(0.0),
+            distance: -consonant_distance,
+            angle_placement: AnglePlacement::Relative,
+        }
+    }
+
+    pub fn new(
+        text: &str,
+        vocal: Vocal,
+        consonant_placement: ConsonantPlacement,
+        consonant_radius: f32,
+        consonant_distance: f32,
+        word_radius: f32,
+        alphabet: &Alphabet,
+    ) -> Self {
+        let letter = alphabet::Letter::Vocal(vocal);
+        let radius = vocal.nested_radius(consonant_radius);
+
+        Self {
+            position_correction: (alphabet.vocal_placement(vocal) == VocalPlacement::Outside)
+                .then(|| Self::position_correction(consonant_distance)),
+            vocal: Letter {
+                text: text.to_string(),
+                letter,
+                radius,
+                position_data: vocal.nested_position_data(
+                    alphabet,
+                    consonant_placement,
+                    consonant_radius,
+                    consonant_distance,
+                    word_radius,
+                ),
+                nested: None,
+                dots: Letter::create_dots(letter, radius, alphabet),
+                line_slots: Letter::create_line_slots(letter, radius, alphabet),
+            },
+        }
+    }
+
+    fn to_element(&self) -> ScriptElement {
+        let vocal_element = self.vocal.to_element(Circle::new(self.vocal.radius).into());
+
+        match &self.position_correction {
+            Some(position_correction) => {
+                let mut element =
+                    ScriptElement::new(POSITION_CORRECTION_KIND, "", *position_correction);
+                element.push(vocal_element);
+                element
+            }
+            None => vocal_element,
+        }
+    }
+}
+
+impl Dot {
+    pub fn radius(consonant_radius: f32) -> f32 {
+        DecorationLayout::default().dot_radius(consonant_radius)
+    }
+
+    pub fn position_data(
+        consonant_radius: f32,
+        number_of_dots: usize,
+        index: usize,
+    ) -> PositionData {
+        DecorationLayout::default().dot_position_data(
+            consonant_radius,
+            number_of_dots,
+            index,
+            &DecorationSpace::default(),
+        )
+    }
+}
+
+impl LineSlot {
+    pub fn position_data(
+        letter_radius: f32,
+        number_of_lines: usize,
+        index: usize,
+        point_outside: bool,
+    ) -> PositionData {
+        DecorationLayout::default().line_slot_position_data(
+            letter_radius,
+            number_of_lines,
+            index,
+            point_outside,
+            &DecorationSpace::default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::angle::Angle;
+
+    #[test]
+    fn should_create_sentence_from_input() {
+        let nested = |sentence: &Sentence| sentence.words[0].letters[0].nested.is_some();
+
+        let all = Sentence::from_input(
+            " ba, be! ",
+            None,
+            &Alphabet::sherman(),
+            ReadingDirection::default(),
+        )
+        .unwrap();
+        let none = Sentence::from_input(
+            "ba be",
+            Some(""),
+            &Alphabet::sherman(),
+            ReadingDirection::default(),
+        )
+        .unwrap();
+        let custom = Sentence::from_input(
+            "ba be",
+            Some("be"),
+            &Alphabet::sherman(),
+            ReadingDirection::default(),
+        )
+        .unwrap();
+
+        assert_eq!(all.text, "ba be");
+        assert!(nested(&all));
+        assert!(!nested(&none));
+        assert!(!nested(&custom));
+        assert!(custom.words[1].letters[0].nested.is_some());
+        assert!(Sentence::from_input(
+            "ba",
+            Some("b"),
+            &Alphabet::sherman(),
+            ReadingDirection::default()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn should_create_sentence_tree() {
+        let sentence = Sentence::new(
+            "ba d",
+            &true,
+            &Alphabet::sherman(),
+            ReadingDirection::default(),
+        );
+
+        assert_eq!(sentence.words.len(), 2);
+
+        let ba = &sentence.words[0].letters[0];
+        assert_eq!(ba.text, "b~a");
+        assert_eq!(ba.nested.as_ref().unwrap().vocal.text, "a");
+        assert!(ba.nested.as_ref().unwrap().position_correction.is_some());
+
+        let d = &sentence.words[1].letters[0];
+        assert_eq!(d.dots.len(), 3);
+        assert!(d.line_slots.is_empty());
+    }
+
+    #[test]
+    fn should_not_nest_vocals() {
+        let sentence = Sentence::new(
+            "ba",
+            &false,
+            &Alphabet::sherman(),
+            ReadingDirection::default(),
+        );
+
+        let letters = &sentence.words[0].letters;
+
+        assert_eq!(letters.len(), 2);
+        assert!(letters.iter().all(|letter| letter.nested.is_none()));
+    }
+
+    #[test]
+    fn should_place_words_in_reading_direction() {
+        let reading_direction = ReadingDirection {
+            start_angle: Degree::new(180.0),
+            clockwise: true,
+        };
+        let sentence = Sentence::new("bed ba d", &true, &Alphabet::sherman(), reading_direction);
+
+        let angles: Vec<f32> = sentence
+            .words
+            .iter()
+            .map(|word| word.position_data.angle.inner())
+            .collect();
+
+        assert_eq!(angles, [180.0, 60.0, -60.0]);
+        assert_eq!(
+            sentence.words[0].letters[1].position_data.angle,
+            Degree::new(0.0)
+        );
+    }
+
+    #[test]
+    fn should_place_single_word_in_center() {
+        let sentence = Sentence::new(
+            "word",
+            &true,
+            &Alphabet::sherman(),
+            ReadingDirection::default(),
+        );
+
+        assert_eq!(sentence.words[0].position_data.distance, 0.0);
+    }
+
+    #[test]
+    fn should_fill_sentence_with_single_word_without_frame() {
+        let radius = Sentence::radius();
+
+        assert_eq!(
+            Word::framed_radius(SentenceFrame::None, radius, 1),
+            radius + OUTER_CIRCLE_SIZE
+        );
+        assert_eq!(
+            Word::framed_radius(SentenceFrame::None, radius, 2),
+            Word::radius(radius, 2)
+        );
+        assert_eq!(
+            Word::framed_radius(SentenceFrame::Single, radius, 1),
+            Word::radius(radius, 1)
+        );
+    }
+}
@@ -0,0 +1,345 @@
+use crate::alphabet::{split_sentences, Alphabet, GRAPHEME_SEPARATOR, SENTENCE_SEPARATOR};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// How characters which aren't part of the alphabet are handled before they get dropped.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub enum NormalizationPolicy {
+    /// Drops them like [`crate::alphabet::sanitize_text_input`].
+    Drop,
+    /// Removes diacritics like `é → e` and replaces ligatures like `ß → ss`.
+    #[default]
+    Transliterate,
+    /// Like [`NormalizationPolicy::Transliterate`] but writes German umlauts like `ä → ae`.
+    ExpandUmlauts,
+}
+
+impl NormalizationPolicy {
+    /// `None` if the character stays the same.
+    pub fn transliterate(&self, character: char) -> Option<String> {
+        if *self == Self::Drop || character.is_ascii() {
+            return None;
+        }
+
+        if *self == Self::ExpandUmlauts {
+            let expanded = match character {
+                'ä' => Some("ae"),
+                'ö' => Some("oe"),
+                'ü' => Some("ue"),
+                'Ä' => Some("Ae"),
+                'Ö' => Some("Oe"),
+                'Ü' => Some("Ue"),
+                _ => None,
+            };
+
+            if let Some(expanded) = expanded {
+                return Some(expanded.to_string());
+            }
+        }
+
+        let replacement = match character {
+            'ß' => "ss",
+            'ẞ' => "SS",
+            'æ' => "ae",
+            'Æ' => "Ae",
+            'œ' => "oe",
+            'Œ' => "Oe",
+            'ø' => "o",
+            'Ø' => "O",
+            'đ' => "d",
+            'Đ' => "D",
+            'ł' => "l",
+            'Ł' => "L",
+            'ð' | 'þ' => "th",
+            'Ð' | 'Þ' => "Th",
+            'ı' => "i",
+            _ => {
+                let decomposed: String = character
+                    .nfd()
+                    .filter(|it| !is_combining_mark(*it))
+                    .collect();
+
+                return (decomposed.chars().ne([character])).then_some(decomposed);
+            }
+        };
+
+        Some(replacement.to_string())
+    }
+
+    /// Transliterates every character of the text and keeps everything else, e.g. to run
+    /// [`crate::phonetic::PhoneticRules`] on latin letters only.
+    pub fn transliterate_text(&self, text: &str) -> String {
+        text.chars()
+            .map(|character| {
+                self.transliterate(character)
+                    .unwrap_or_else(|| character.to_string())
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TextChangeKind {
+    Transformed(String),
+    Dropped,
+}
+
+/// A character of the input which didn't make it unchanged into the text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TextChange {
+    /// Index of the character, not of the byte, in the input.
+    pub position: usize,
+    pub character: char,
+    pub kind: TextChangeKind,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NormalizedText {
+    pub text: String,
+    pub changes: Vec<TextChange>,
+}
+
+/// Sanitizes the input like [`crate::alphabet::sanitize_text_input`] after transliterating it
+/// with the policy and reports every character which was changed or dropped.
+///
+/// Whitespace and [`GRAPHEME_SEPARATOR`]s only separate words and graphemes and aren't reported.
+pub fn normalize_text_input(
+    input: &str,
+    policy: NormalizationPolicy,
+    alphabet: &Alphabet,
+) -> NormalizedText {
+    let mut normalized = NormalizedText::default();
+    let mut word = Vec::new();
+
+    for (position, character) in input.chars().enumerate() {
+        if character.is_whitespace() {
+            normalize_word(&word, &mut normalized, alphabet);
+            word.clear();
+        } else {
+            word.push((position, character, policy.transliterate(character)));
+        }
+    }
+
+    normalize_word(&word, &mut normalized, alphabet);
+
+    normalized
+}
+
+/// Normalizes every sentence of [`split_sentences`] like [`normalize_text_input`] and joins them
+/// with [`SENTENCE_SEPARATOR`]. The sentence endings aren't reported.
+pub fn normalize_sentences(
+    input: &str,
+    policy: NormalizationPolicy,
+    alphabet: &Alphabet,
+) -> NormalizedText {
+    let mut normalized = NormalizedText::default();
+
+    for (offset, sentence) in split_sentences(input) {
+        let sentence = normalize_text_input(sentence, policy, alphabet);
+
+        normalized
+            .changes
+            .extend(sentence.changes.into_iter().map(|change| TextChange {
+                position: change.position + offset,
+                ..change
+            }));
+
+        if sentence.text.is_empty() {
+            continue;
+        }
+
+        if !normalized.text.is_empty() {
+            normalized.text.push(SENTENCE_SEPARATOR);
+        }
+
+        normalized.text += &sentence.text;
+    }
+
+    normalized
+}
+
+fn normalize_word(
+    word: &[(usize, char, Option<String>)],
+    normalized: &mut NormalizedText,
+    alphabet: &Alphabet,
+) {
+    let mut text = String::new();
+    let mut ranges = Vec::new();
+
+    for (_, character, transliterated) in word {
+        let start = text.len();
+
+        match transliterated {
+            Some(transliterated) => text += transliterated,
+            None => text.push(*character),
+        }
+
+        ranges.push(start..text.len());
+    }
+
+    let graphemes = alphabet.grapheme_ranges(&text);
+
+    for ((position, character, transliterated), range) in word.iter().zip(ranges) {
+        if *character == GRAPHEME_SEPARATOR {
+            continue;
+        }
+
+        let is_kept = graphemes
+            .iter()
+            .any(|grapheme| grapheme.start < range.end && range.start < grapheme.end);
+
+        let kind = match (is_kept, transliterated) {
+            (false, _) => TextChangeKind::Dropped,
+            (true, Some(transliterated)) => TextChangeKind::Transformed(transliterated.clone()),
+            (true, None) => continue,
+        };
+
+        normalized.changes.push(TextChange {
+            position: *position,
+            character: *character,
+            kind,
+        });
+    }
+
+    if graphemes.is_empty() {
+        return;
+    }
+
+    if !normalized.text.is_empty() {
+        normalized.text.push(' ');
+    }
+
+    let graphemes: Vec<&str> = graphemes.into_iter().map(|it| &text[it]).collect();
+    normalized.text += &alphabet.join_graphemes(&graphemes);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::alphabet::sanitize_text_input;
+
+    fn transformed(position: usize, character: char, to: &str) -> TextChange {
+        TextChange {
+            position,
+            character,
+            kind: TextChangeKind::Transformed(to.to_string()),
+        }
+    }
+
+    fn dropped(position: usize, character: char) -> TextChange {
+        TextChange {
+            position,
+            character,
+            kind: TextChangeKind::Dropped,
+        }
+    }
+
+    #[test]
+    fn should_transliterate_characters() {
+        let result = normalize_text_input(
+            "Zoë Straße, Ærø",
+            NormalizationPolicy::Transliterate,
+            &Alphabet::sherman(),
+        );
+
+        assert_eq!(result.text, "Zoe Strasse Aero");
+        assert_eq!(
+            result.changes,
+            [
+                transformed(2, 'ë', "e"),
+                transformed(8, 'ß', "ss"),
+                dropped(10, ','),
+                transformed(12, 'Æ', "Ae"),
+                transformed(14, 'ø', "o"),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_expand_umlauts() {
+        let result = normalize_text_input(
+            "Jürgen Müller",
+            NormalizationPolicy::ExpandUmlauts,
+            &Alphabet::sherman(),
+        );
+
+        assert_eq!(result.text, "Juergen Mueller");
+        assert_eq!(result.changes.len(), 2);
+    }
+
+    #[test]
+    fn should_transliterate_text() {
+        let text = "Müller, 漢!";
+
+        assert_eq!(
+            NormalizationPolicy::Transliterate.transliterate_text(text),
+            "Muller, 漢!"
+        );
+        assert_eq!(
+            NormalizationPolicy::ExpandUmlauts.transliterate_text(text),
+            "Mueller, 漢!"
+        );
+        assert_eq!(NormalizationPolicy::Drop.transliterate_text(text), text);
+    }
+
+    #[test]
+    fn should_drop_like_sanitize_text_input() {
+        let input = "äöü+*~#'i#-_.:,;<>|@n€^°1!2²\"3§³4$5v%6&7/{a8([9)l]0=i}ßd?\\´`";
+
+        let result = normalize_text_input(input, NormalizationPolicy::Drop, &Alphabet::sherman());
+
+        assert_eq!(
+            result.text,
+            sanitize_text_input(input, &Alphabet::sherman())
+        );
+        // the letters and the separator aren't reported
+        assert_eq!(result.changes.len(), input.chars().count() - 8);
+        assert!(result
+            .changes
+            .iter()
+            .all(|change| change.kind == TextChangeKind::Dropped));
+    }
+
+    #[test]
+    fn should_keep_grapheme_separators() {
+        let result = normalize_text_input(
+            "hot|höuse a|b",
+            NormalizationPolicy::Transliterate,
+            &Alphabet::sherman(),
+        );
+
+        assert_eq!(result.text, "hot|house ab");
+        assert_eq!(result.changes, [transformed(5, 'ö', "o")]);
+    }
+
+    #[test]
+    fn should_normalize_sentences() {
+        let result = normalize_sentences(
+            "Zoë. ¹! Straße",
+            NormalizationPolicy::Transliterate,
+            &Alphabet::sherman(),
+        );
+
+        assert_eq!(result.text, "Zoe\nStrasse");
+        assert_eq!(
+            result.changes,
+            [
+                transformed(2, 'ë', "e"),
+                dropped(5, '¹'),
+                transformed(12, 'ß', "ss")
+            ]
+        );
+    }
+
+    #[test]
+    fn should_report_dropped_transliterations() {
+        let result = normalize_text_input(
+            "a ¹ 漢",
+            NormalizationPolicy::Transliterate,
+            &Alphabet::sherman(),
+        );
+
+        assert_eq!(result.text, "a");
+        assert_eq!(result.changes, [dropped(2, '¹'), dropped(4, '漢')]);
+    }
+}
@@ -0,0 +1,225 @@
+use crate::alphabet::Alphabet;
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+pub const ENGLISH: &str = include_str!("../phonetic/english.ron");
+
+lazy_static! {
+    static ref ENGLISH_RULES: PhoneticRules = PhoneticRules::from_ron(ENGLISH).unwrap();
+    static ref LETTERS_OR_OTHER: Regex = Regex::new(r"\p{L}+|\P{L}+").unwrap();
+}
+
+/// The content of a phonetic rules `.ron` file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct PhoneticRulesDefinition {
+    /// Whole words which don't follow the rules.
+    pub dictionary: HashMap<String, String>,
+    pub rules: Vec<RuleDefinition>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct RuleDefinition {
+    pub letters: String,
+    /// Regex which has to match the end of the word in front of the letters.
+    pub after: Option<String>,
+    /// Regex which has to match the start of the word behind the letters.
+    pub before: Option<String>,
+    pub to: String,
+}
+
+#[derive(Debug, Clone)]
+struct Rule {
+    letters: String,
+    after: Option<Regex>,
+    before: Option<Regex>,
+    to: String,
+}
+
+impl Rule {
+    fn matches(&self, word: &str, index: usize) -> bool {
+        let end = index + self.letters.len();
+
+        word[index..].starts_with(&self.letters)
+            && self
+                .after
+                .as_ref()
+                .is_none_or(|after| after.is_match(&word[..index]))
+            && self
+                .before
+                .as_ref()
+                .is_none_or(|before| before.is_match(&word[end..]))
+    }
+}
+
+/// Converts spelling into a phonetic spelling which only uses graphemes of Sherman's alphabet,
+/// e.g. `phone` into `fon`.
+#[derive(Debug, Clone)]
+pub struct PhoneticRules {
+    dictionary: HashMap<String, String>,
+    rules: Vec<Rule>,
+}
+
+impl PhoneticRules {
+    pub fn from_ron(ron: &str) -> Result<Self, String> {
+        let definition: PhoneticRulesDefinition =
+            ron::from_str(ron).map_err(|error| format!("Invalid phonetic rules: {}", error))?;
+
+        Self::new(definition)
+    }
+
+    pub fn new(definition: PhoneticRulesDefinition) -> Result<Self, String> {
+        let sherman = Alphabet::sherman();
+
+        let spellings = definition
+            .dictionary
+            .values()
+            .chain(definition.rules.iter().map(|rule| &rule.to));
+
+        for spelling in spellings {
+            if sherman.split_word(spelling).concat() != *spelling {
+                return Err(format!(
+                    "'{}' can't be written with the graphemes of Sherman's alphabet!",
+                    spelling
+                ));
+            }
+        }
+
+        let context = |pattern: &Option<String>, format: fn(&str) -> String| {
+            pattern
+                .as_deref()
+                .map(|pattern| Regex::new(&format(pattern)).map_err(|error| error.to_string()))
+                .transpose()
+        };
+
+        let rules = definition
+            .rules
+            .into_iter()
+            .map(|rule| {
+                if rule.letters.is_empty() {
+                    return Err("Rule letters can't be empty!".to_string());
+                }
+
+                Ok(Rule {
+                    after: context(&rule.after, |it| format!("(?:{})$", it))?,
+                    before: context(&rule.before, |it| format!("^(?:{})", it))?,
+                    letters: rule.letters.to_lowercase(),
+                    to: rule.to,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(Self {
+            dictionary: definition
+                .dictionary
+                .into_iter()
+                .map(|(word, spelling)| (word.to_lowercase(), spelling))
+                .collect(),
+            rules,
+        })
+    }
+
+    pub fn english() -> &'static Self {
+        &ENGLISH_RULES
+    }
+
+    /// Converts every word to lowercase before the rules are applied, everything between the words
+    /// is kept as it is. Letters without a rule, e.g. non-latin ones, are kept as well, so the text
+    /// should be transliterated with [`crate::normalization::NormalizationPolicy`] first.
+    pub fn transliterate(&self, text: &str) -> String {
+        LETTERS_OR_OTHER
+            .find_iter(text)
+            .map(|matched| {
+                let part = matched.as_str();
+
+                if part.starts_with(char::is_alphabetic) {
+                    self.transliterate_word(&part.to_lowercase())
+                } else {
+                    part.to_string()
+                }
+            })
+            .collect()
+    }
+
+    fn transliterate_word(&self, word: &str) -> String {
+        if let Some(spelling) = self.dictionary.get(word) {
+            return spelling.clone();
+        }
+
+        let mut spelling = String::new();
+        let mut index = 0;
+
+        while index < word.len() {
+            match self.rules.iter().find(|rule| rule.matches(word, index)) {
+                Some(rule) => {
+                    spelling += &rule.to;
+                    index += rule.letters.len();
+                }
+                None => {
+                    let letter = word[index..].chars().next().unwrap();
+                    spelling.push(letter);
+                    index += letter.len_utf8();
+                }
+            }
+        }
+
+        spelling
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_transliterate_english() {
+        let rules = PhoneticRules::english();
+
+        let words = [
+            ("phone", "fon"),
+            ("back", "bak"),
+            ("city", "siti"),
+            ("cat", "kat"),
+            ("knight", "nit"),
+            ("quick", "kwik"),
+            ("the", "the"),
+            ("Gallifreyan", "galifreyan"),
+            ("one", "wun"),
+        ];
+
+        for (word, expected) in words {
+            assert_eq!(rules.transliterate(word), expected, "{}", word);
+        }
+    }
+
+    #[test]
+    fn should_keep_everything_but_letters() {
+        let result = PhoneticRules::english().transliterate("Hello, phone 42!");
+
+        assert_eq!(result, "helo, fon 42!");
+    }
+
+    #[test]
+    fn should_keep_non_ascii_letters_in_words() {
+        let rules = PhoneticRules::english();
+
+        // the x isn't at the start of the word
+        assert_eq!(rules.transliterate("Bäxter"), "bäkster");
+        assert_eq!(rules.transliterate("MÜLLER phone"), "müler fon");
+    }
+
+    #[test]
+    fn should_reject_invalid_rules() {
+        let unknown_grapheme = r#"#![enable(implicit_some)]
+            (dictionary: {}, rules: [(letters: "a", to: "ä")])"#;
+        let invalid_context = r#"#![enable(implicit_some)]
+            (dictionary: {}, rules: [(letters: "a", before: "[", to: "a")])"#;
+
+        assert_eq!(
+            PhoneticRules::from_ron(unknown_grapheme).unwrap_err(),
+            "'ä' can't be written with the graphemes of Sherman's alphabet!"
+        );
+        assert!(PhoneticRules::from_ron(invalid_context).is_err());
+    }
+}
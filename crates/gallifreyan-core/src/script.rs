@@ -0,0 +1,183 @@
+mod clockwork;
+mod sherman;
+
+pub use clockwork::Clockwork;
+pub use sherman::Sherman;
+
+use crate::export;
+use crate::export::ExportOptions;
+use crate::layout::{PositionData, ReadingDirection};
+use crate::svg::{Class, Group, SVGElement, SVG};
+use itertools::Itertools;
+use std::sync::Arc;
+
+pub const SHERMAN_ID: &str = "sherman";
+
+/// A circular writing system which decides how text is split into letters, which elements a
+/// sentence consists of and how they are placed and drawn.
+pub trait ScriptSystem: Send + Sync {
+    /// Identifies the system, e.g. in query parameters, so it must not contain whitespace.
+    fn id(&self) -> &'static str;
+
+    fn name(&self) -> &'static str;
+
+    /// The kinds of [`ScriptElement`]s the layout is made of.
+    fn element_kinds(&self) -> &'static [&'static str];
+
+    /// Splits a word into its letters and skips everything else.
+    fn split_word<'w>(&self, word: &'w str) -> Vec<&'w str>;
+
+    fn sanitize_text_input(&self, text: &str) -> String {
+        text.split_whitespace()
+            .map(|word| self.split_word(word).join(""))
+            .filter(|word| !word.is_empty())
+            .join(" ")
+    }
+
+    /// Sanitizes the text and places the elements of its sentence.
+    fn layout(
+        &self,
+        text: &str,
+        reading_direction: ReadingDirection,
+    ) -> Result<ScriptElement, String>;
+
+    fn to_svg(
+        &self,
+        text: &str,
+        reading_direction: ReadingDirection,
+        options: &ExportOptions,
+    ) -> Result<SVG, String> {
+        let sentence = self.layout(text, reading_direction)?;
+
+        let mut root_group = export::create_root_group();
+        root_group.push(sentence.to_group());
+
+        Ok(export::create_document(&sentence.text, root_group, options))
+    }
+}
+
+/// All script systems with their default settings, starting with [`Sherman`].
+pub fn script_systems() -> Vec<Arc<dyn ScriptSystem>> {
+    vec![Arc::new(Sherman::default()), Arc::new(Clockwork)]
+}
+
+pub fn find_script_system(id: &str) -> Result<Arc<dyn ScriptSystem>, String> {
+    script_systems()
+        .into_iter()
+        .find(|system| system.id() == id)
+        .ok_or_else(|| format!("'{}' is not a known script system!", id))
+}
+
+/// Falls back to [`Sherman`] without an id. Nesting rules only apply to [`Sherman`].
+pub fn create_script_system(
+    id: Option<&str>,
+    nesting_rules: Option<&str>,
+) -> Result<Arc<dyn ScriptSystem>, String> {
+    match id {
+        None | Some(SHERMAN_ID) => Ok(Arc::new(Sherman {
+            nesting_rules: nesting_rules.map(ToString::to_string),
+            ..Default::default()
+        })),
+        Some(id) => find_script_system(id),
+    }
+}
+
+/// An element of a laid out sentence, placed relative to its parent.
+#[derive(Debug, Clone)]
+pub struct ScriptElement {
+    pub kind: &'static str,
+    pub text: String,
+    pub position_data: PositionData,
+    pub shape: Option<SVGElement>,
+    /// Either [`export::STROKE_CLASS`] or [`export::FILL_CLASS`].
+    pub class: &'static str,
+    pub children: Vec<ScriptElement>,
+}
+
+impl ScriptElement {
+    pub fn new(kind: &'static str, text: impl Into<String>, position_data: PositionData) -> Self {
+        Self {
+            kind,
+            text: text.into(),
+            position_data,
+            shape: None,
+            class: export::STROKE_CLASS,
+            children: vec![],
+        }
+    }
+
+    pub fn with_shape(mut self, shape: impl Into<SVGElement>, class: &'static str) -> Self {
+        self.shape = Some(shape.into());
+        self.class = class;
+        self
+    }
+
+    pub fn push(&mut self, child: ScriptElement) {
+        self.children.push(child);
+    }
+
+    pub fn to_group(&self) -> Group {
+        let mut group = Group::new();
+        group.affine2 = self.position_data.affine2(0.0);
+
+        if let Some(shape) = &self.shape {
+            let mut shape = shape.clone();
+            shape.set_class(Class(self.class.to_string()));
+            group.push(shape);
+        }
+
+        for child in &self.children {
+            group.push(child.to_group());
+        }
+
+        group
+    }
+
+    /// Number of elements of the kind in the tree, including this one.
+    pub fn count(&self, kind: &str) -> usize {
+        usize::from(self.kind == kind)
+            + self
+                .children
+                .iter()
+                .map(|child| child.count(kind))
+                .sum::<usize>()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_find_script_systems() {
+        assert_eq!(find_script_system("sherman").unwrap().name(), "Sherman");
+        assert_eq!(find_script_system("clockwork").unwrap().name(), "Clockwork");
+        assert_eq!(
+            find_script_system("cot").err(),
+            Some("'cot' is not a known script system!".to_string())
+        );
+    }
+
+    #[test]
+    fn should_create_script_system() {
+        let sherman = create_script_system(None, Some("")).unwrap();
+        let clockwork = create_script_system(Some("clockwork"), Some("")).unwrap();
+
+        assert_eq!(sherman.id(), SHERMAN_ID);
+        assert_eq!(clockwork.id(), "clockwork");
+        assert!(create_script_system(None, Some("b"))
+            .unwrap()
+            .layout("ba", ReadingDirection::default())
+            .is_err());
+    }
+
+    #[test]
+    fn should_have_unique_ids() {
+        let systems = script_systems();
+
+        assert!(systems.iter().map(|system| system.id()).all_unique());
+        assert!(systems
+            .iter()
+            .all(|system| !system.id().contains(char::is_whitespace)));
+    }
+}
@@ -0,0 +1,262 @@
+use super::{ScriptElement, ScriptSystem};
+use crate::draw;
+use crate::export::{FILL_CLASS, STROKE_CLASS};
+use crate::layout::{AnglePlacement, PositionData, ReadingDirection};
+use crate::math::angle::{Angle, Degree};
+use crate::model;
+use crate::svg::{Circle, Group, Line};
+use glam::Vec2;
+use lazy_static::lazy_static;
+use regex::{Regex, RegexBuilder};
+
+lazy_static! {
+    static ref LATIN_LETTER: Regex = RegexBuilder::new("[a-z]")
+        .case_insensitive(true)
+        .build()
+        .unwrap();
+}
+
+pub const LETTER_KIND: &str = "letter";
+pub const HAND_KIND: &str = "hand";
+pub const DOT_KIND: &str = "dot";
+
+/// Number of hand positions of a letter, the second half of the latin alphabet is marked with a
+/// dot in the center of the letter.
+const CLOCK_POSITIONS: usize = 13;
+
+/// A clockwork style system where every latin letter is a clock face inside of the word.
+///
+/// The hand of the clock points to the position of the letter in the alphabet with `a` and `n`
+/// at the tick on top.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Clockwork;
+
+impl Clockwork {
+    pub fn letter_radius(word_radius: f32, number_of_letters: usize) -> f32 {
+        (word_radius * 0.4) / (1.0 + number_of_letters as f32 / 4.0)
+    }
+
+    pub fn letter_position_data(
+        reading_direction: ReadingDirection,
+        word_radius: f32,
+        number_of_letters: usize,
+        index: usize,
+    ) -> PositionData {
+        PositionData {
+            distance: if number_of_letters > 1 {
+                word_radius - Self::letter_radius(word_radius, number_of_letters) * 1.25
+            } else {
+                0.0
+            },
+            angle: reading_direction.angle(number_of_letters, index),
+            angle_placement: AnglePlacement::Absolute,
+        }
+    }
+
+    /// `None` for anything but latin letters.
+    pub fn alphabet_index(letter: &str) -> Option<usize> {
+        match letter.as_bytes() {
+            [letter] if letter.is_ascii_alphabetic() => {
+                Some((letter.to_ascii_lowercase() - b'a') as usize)
+            }
+            _ => None,
+        }
+    }
+
+    fn word(
+        text: &str,
+        reading_direction: ReadingDirection,
+        sentence_radius: f32,
+        number_of_words: usize,
+        index: usize,
+    ) -> ScriptElement {
+        let radius = model::Word::radius(sentence_radius, number_of_words);
+        let letters = Clockwork.split_word(text);
+
+        let mut word = ScriptElement::new(
+            model::WORD_KIND,
+            text,
+            model::Word::position_data(reading_direction, sentence_radius, number_of_words, index),
+        )
+        .with_shape(Circle::new(radius), STROKE_CLASS);
+
+        for (index, letter) in letters.iter().enumerate() {
+            word.push(Self::letter(
+                letter,
+                reading_direction,
+                radius,
+                letters.len(),
+                index,
+            ));
+        }
+
+        word
+    }
+
+    fn letter(
+        text: &str,
+        reading_direction: ReadingDirection,
+        word_radius: f32,
+        number_of_letters: usize,
+        index: usize,
+    ) -> ScriptElement {
+        let radius = Self::letter_radius(word_radius, number_of_letters);
+        let alphabet_index = Self::alphabet_index(text).unwrap_or_default();
+
+        let mut shape = Group::new();
+        shape.push(Circle::new(radius));
+        shape.push(Line::new(Vec2::Y * radius * 0.85, Vec2::Y * radius));
+
+        let mut letter = ScriptElement::new(
+            LETTER_KIND,
+            text,
+            Self::letter_position_data(reading_direction, word_radius, number_of_letters, index),
+        )
+        .with_shape(shape, STROKE_CLASS);
+
+        let hand_angle =
+            Degree::new((alphabet_index % CLOCK_POSITIONS) as f32 * 360.0 / CLOCK_POSITIONS as f32);
+        // clockwise from the top as the y-axis points up
+        let hand_direction = Vec2::new(
+            hand_angle.to_radians().inner().sin(),
+            hand_angle.to_radians().inner().cos(),
+        );
+
+        letter.push(
+            ScriptElement::new(HAND_KIND, "", PositionData::default()).with_shape(
+                Line::new(Vec2::ZERO, hand_direction * radius * 0.7),
+                STROKE_CLASS,
+            ),
+        );
+
+        if alphabet_index >= CLOCK_POSITIONS {
+            letter.push(
+                ScriptElement::new(DOT_KIND, "", PositionData::default())
+                    .with_shape(Circle::new(radius * 0.15), FILL_CLASS),
+            );
+        }
+
+        letter
+    }
+}
+
+impl ScriptSystem for Clockwork {
+    fn id(&self) -> &'static str {
+        "clockwork"
+    }
+
+    fn name(&self) -> &'static str {
+        "Clockwork"
+    }
+
+    fn element_kinds(&self) -> &'static [&'static str] {
+        &[
+            model::SENTENCE_KIND,
+            model::WORD_KIND,
+            LETTER_KIND,
+            HAND_KIND,
+            DOT_KIND,
+        ]
+    }
+
+    fn split_word<'w>(&self, word: &'w str) -> Vec<&'w str> {
+        LATIN_LETTER
+            .find_iter(word)
+            .map(|matched| matched.as_str())
+            .collect()
+    }
+
+    fn layout(
+        &self,
+        text: &str,
+        reading_direction: ReadingDirection,
+    ) -> Result<ScriptElement, String> {
+        let text = self.sanitize_text_input(text);
+        let radius = model::Sentence::radius();
+        let words: Vec<&str> = text.split_whitespace().collect();
+
+        let mut sentence = ScriptElement::new(
+            model::SENTENCE_KIND,
+            &text,
+            model::Sentence::position_data(),
+        )
+        .with_shape(draw::sentence(radius), STROKE_CLASS);
+
+        for (index, word) in words.iter().enumerate() {
+            sentence.push(Self::word(
+                word,
+                reading_direction,
+                radius,
+                words.len(),
+                index,
+            ));
+        }
+
+        Ok(sentence)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::export::ExportOptions;
+
+    #[test]
+    fn should_split_latin_letters() {
+        assert_eq!(Clockwork.split_word("Th-ä1s"), ["T", "h", "s"]);
+        assert_eq!(Clockwork.sanitize_text_input(" ab, ß c "), "ab c");
+    }
+
+    #[test]
+    fn should_place_letters_in_reading_direction() {
+        let clockwise = ReadingDirection {
+            start_angle: Degree::new(90.0),
+            clockwise: true,
+        };
+
+        let sentence = Clockwork.layout("abc", clockwise).unwrap();
+        let angles: Vec<f32> = sentence.children[0]
+            .children
+            .iter()
+            .map(|letter| letter.position_data.angle.inner())
+            .collect();
+
+        assert_eq!(angles, [90.0, -30.0, -150.0]);
+    }
+
+    #[test]
+    fn should_layout_letters_as_clocks() {
+        let sentence = Clockwork
+            .layout("an z", ReadingDirection::default())
+            .unwrap();
+
+        assert_eq!(sentence.count(model::WORD_KIND), 2);
+        assert_eq!(sentence.count(LETTER_KIND), 3);
+        assert_eq!(sentence.count(HAND_KIND), 3);
+        // n and z are in the second half of the alphabet
+        assert_eq!(sentence.count(DOT_KIND), 2);
+
+        let a = &sentence.children[0].children[0];
+        let n = &sentence.children[0].children[1];
+        assert_eq!(a.text, "a");
+        assert_eq!(
+            a.children[0].shape.as_ref().map(ToString::to_string),
+            n.children[0].shape.as_ref().map(ToString::to_string)
+        );
+    }
+
+    #[test]
+    fn should_create_svg() {
+        let svg = Clockwork
+            .to_svg(
+                "clock",
+                ReadingDirection::default(),
+                &ExportOptions::default(),
+            )
+            .unwrap()
+            .to_string();
+
+        assert!(svg.contains("<title>clock</title>"));
+        assert_eq!(svg.matches("<line").count(), 10);
+    }
+}
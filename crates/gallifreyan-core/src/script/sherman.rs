@@ -0,0 +1,106 @@
+use super::{ScriptElement, ScriptSystem, SHERMAN_ID};
+use crate::alphabet::Alphabet;
+use crate::layout::ReadingDirection;
+use crate::model;
+use std::sync::Arc;
+
+/// Sherman's Circular Gallifreyan, written with the graphemes of the alphabet.
+#[derive(Debug, Clone)]
+pub struct Sherman {
+    /// See [`model::Sentence::from_input`].
+    pub nesting_rules: Option<String>,
+    pub alphabet: Arc<Alphabet>,
+}
+
+impl Default for Sherman {
+    fn default() -> Self {
+        Self {
+            nesting_rules: None,
+            alphabet: Alphabet::sherman(),
+        }
+    }
+}
+
+impl ScriptSystem for Sherman {
+    fn id(&self) -> &'static str {
+        SHERMAN_ID
+    }
+
+    fn name(&self) -> &'static str {
+        "Sherman"
+    }
+
+    fn element_kinds(&self) -> &'static [&'static str] {
+        &[
+            model::SENTENCE_KIND,
+            model::WORD_KIND,
+            model::LETTER_KIND,
+            model::POSITION_CORRECTION_KIND,
+            model::DOT_KIND,
+        ]
+    }
+
+    fn split_word<'w>(&self, word: &'w str) -> Vec<&'w str> {
+        self.alphabet.split_word(word)
+    }
+
+    fn layout(
+        &self,
+        text: &str,
+        reading_direction: ReadingDirection,
+    ) -> Result<ScriptElement, String> {
+        model::Sentence::from_input(
+            text,
+            self.nesting_rules.as_deref(),
+            &self.alphabet,
+            reading_direction,
+        )
+        .map(|sentence| sentence.to_element(&self.alphabet))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::export::ExportOptions;
+
+    #[test]
+    fn should_match_model_svg() {
+        let sherman = Sherman {
+            nesting_rules: Some("ba".to_string()),
+            ..Default::default()
+        };
+
+        let result = sherman
+            .to_svg(
+                "bab dot",
+                ReadingDirection::default(),
+                &ExportOptions::default(),
+            )
+            .unwrap()
+            .to_string();
+        let expected = model::Sentence::from_input(
+            "bab dot",
+            Some("ba"),
+            &Alphabet::sherman(),
+            ReadingDirection::default(),
+        )
+        .unwrap()
+        .to_svg(&Alphabet::sherman())
+        .to_string();
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_layout_elements() {
+        let sentence = Sherman::default()
+            .layout("ba d", ReadingDirection::default())
+            .unwrap();
+
+        assert_eq!(sentence.count(model::WORD_KIND), 2);
+        assert_eq!(sentence.count(model::LETTER_KIND), 3);
+        assert_eq!(sentence.count(model::POSITION_CORRECTION_KIND), 1);
+        assert_eq!(sentence.count(model::DOT_KIND), 3);
+    }
+}
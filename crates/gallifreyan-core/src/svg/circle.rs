@@ -1,7 +1,4 @@
-use super::{Class, Indent};
-use bevy_prototype_lyon::prelude::tess::path::path::Builder;
-use bevy_prototype_lyon::prelude::Geometry;
-use bevy_prototype_lyon::shapes;
+use super::{Class, Indent, InlineStyle};
 use itertools::Itertools;
 use std::fmt::{Display, Formatter};
 
@@ -9,6 +6,7 @@ use std::fmt::{Display, Formatter};
 pub struct Circle {
     pub radius: f32,
     pub class: Class,
+    pub style: InlineStyle,
 }
 
 impl Circle {
@@ -16,8 +14,13 @@ impl Circle {
         Self {
             radius,
             class: Class::default(),
+            style: InlineStyle::default(),
         }
     }
+
+    pub fn length(&self) -> f32 {
+        std::f32::consts::TAU * self.radius
+    }
 }
 
 impl Display for Circle {
@@ -27,6 +30,7 @@ impl Display for Circle {
             "cy=\"0\"".to_string(),
             format!("r=\"{}\"", self.radius),
             format!("{}", self.class),
+            format!("{}", self.style),
         ];
 
         write!(
@@ -37,16 +41,6 @@ impl Display for Circle {
     }
 }
 
-impl Geometry for Circle {
-    fn add_geometry(&self, b: &mut Builder) {
-        shapes::Circle {
-            radius: self.radius,
-            center: Default::default(),
-        }
-        .add_geometry(b);
-    }
-}
-
 impl Indent for Circle {}
 
 #[cfg(test)]
@@ -0,0 +1,60 @@
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DataAttributes(pub Vec<(String, String)>);
+
+impl DataAttributes {
+    pub fn push(&mut self, key: &str, value: impl ToString) {
+        self.0.push((key.to_string(), value.to_string()));
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0
+            .iter()
+            .find(|(it, _)| it == key)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+impl Display for DataAttributes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.0
+                .iter()
+                .map(|(key, value)| format!("data-{}=\"{}\"", key, escape(value)))
+                .join(" ")
+        )
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_create_data_attributes() {
+        let mut data = DataAttributes::default();
+        data.push("kind", "word");
+        data.push("text", "a\"b");
+
+        assert_eq!(data.to_string(), r#"data-kind="word" data-text="a&quot;b""#);
+        assert_eq!(data.get("kind"), Some("word"));
+        assert_eq!(data.get("radius"), None);
+    }
+
+    #[test]
+    fn should_create_empty_data_attributes() {
+        assert_eq!(DataAttributes::default().to_string(), "");
+    }
+}
@@ -1,23 +1,29 @@
-use super::{Class, Indent, SVGElement, ToCSSString, DEFAULT_INDENTATION_DEPTH};
-use bevy::math::Affine2;
-use bevy_prototype_lyon::prelude::tess::path::path::Builder;
-use bevy_prototype_lyon::prelude::Geometry;
+use super::{
+    Class, DataAttributes, Indent, InlineStyle, SVGElement, ToCSSString, DEFAULT_INDENTATION_DEPTH,
+};
+use glam::Affine2;
 use itertools::Itertools;
 use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Default, Clone)]
 pub struct Group {
+    pub id: Option<String>,
     pub elements: Vec<SVGElement>,
     pub affine2: Affine2,
     pub class: Class,
+    pub style: InlineStyle,
+    pub data: DataAttributes,
 }
 
 impl Group {
     pub fn new() -> Self {
         Self {
+            id: None,
             elements: Vec::new(),
             affine2: Affine2::IDENTITY,
             class: Class::default(),
+            style: InlineStyle::default(),
+            data: DataAttributes::default(),
         }
     }
 
@@ -29,9 +35,12 @@ impl Group {
 impl From<Vec<SVGElement>> for Group {
     fn from(value: Vec<SVGElement>) -> Self {
         Self {
+            id: None,
             elements: value,
             affine2: Affine2::IDENTITY,
             class: Class::default(),
+            style: InlineStyle::default(),
+            data: DataAttributes::default(),
         }
     }
 }
@@ -39,8 +48,14 @@ impl From<Vec<SVGElement>> for Group {
 impl Display for Group {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         let attributes = [
+            self.id
+                .as_ref()
+                .map(|id| format!("id=\"{}\"", id))
+                .unwrap_or_default(),
             format!("transform=\"{}\"", self.affine2.to_css_string()),
             format!("{}", self.class),
+            format!("{}", self.style),
+            format!("{}", self.data),
         ];
 
         let content = self
@@ -58,14 +73,6 @@ impl Display for Group {
     }
 }
 
-impl Geometry for Group {
-    fn add_geometry(&self, b: &mut Builder) {
-        for element in &self.elements {
-            element.add_geometry(b);
-        }
-    }
-}
-
 impl Indent for Group {}
 
 #[cfg(test)]
@@ -100,6 +107,20 @@ mod test {
     <g transform="matrix(1 0 0 1 0 0)">
     
     </g>
+</g>"#;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_create_group_tag_with_id() {
+        let mut group = Group::new();
+        group.id = Some("e1".to_string());
+
+        let result = format!("{}", group);
+
+        let expected = r#"<g id="e1" transform="matrix(1 0 0 1 0 0)">
+
 </g>"#;
 
         assert_eq!(result, expected);
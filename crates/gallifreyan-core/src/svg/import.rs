@@ -0,0 +1,511 @@
+use super::{
+    Animation, CSSRule, Circle, Class, Group, InlineStyle, Keyframes, Line, Path, PathElement, Rgb,
+    SVGElement, Selector, StrokeLineCap, Style, StyleRule, Title, SVG,
+};
+use glam::{Affine2, Vec2};
+use log::debug;
+use roxmltree::{Document, Node};
+use std::str::FromStr;
+
+impl FromStr for SVG {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let document = Document::parse(text).map_err(|err| err.to_string())?;
+        let root = document.root_element();
+
+        if root.tag_name().name() != "svg" {
+            return Err(format!(
+                "Expected <svg> as root element but found <{}>!",
+                root.tag_name().name()
+            ));
+        }
+
+        let view_box = root
+            .attribute("viewBox")
+            .ok_or_else(|| "Missing viewBox attribute on <svg>!".to_string())?;
+
+        let [min_x, min_y, width, height]: [f32; 4] = parse_numbers(view_box)?
+            .try_into()
+            .map_err(|_| format!("Invalid viewBox '{}'!", view_box))?;
+
+        let size = width.max(height);
+        let elements = parse_children(root)?;
+
+        let mut svg = SVG::new(size);
+
+        // the svg is always centered around the origin so other view boxes need to be shifted
+        let center = Vec2::new(min_x + width / 2.0, min_y + height / 2.0);
+        if center == Vec2::ZERO {
+            svg.elements = elements;
+        } else {
+            let mut group = Group::from(elements);
+            group.affine2 = Affine2::from_translation(-center);
+            svg.push(group);
+        }
+
+        Ok(svg)
+    }
+}
+
+impl FromStr for InlineStyle {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        parse_declarations(text).map(InlineStyle)
+    }
+}
+
+impl FromStr for Style {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut style = Style::new();
+        let mut rest = text.trim();
+
+        while !rest.is_empty() {
+            let (prelude, body, remaining) = split_block(rest)?;
+
+            if let Some(name) = prelude.strip_prefix("@keyframes") {
+                let mut keyframes = Keyframes::new(name.trim());
+                let mut frames = body.trim();
+
+                while !frames.is_empty() {
+                    let (selectors, declarations, remaining) = split_block(frames)?;
+
+                    keyframes.push(StyleRule {
+                        selectors: selectors
+                            .split(',')
+                            .map(|it| Selector::Keyframe(it.trim().to_string()))
+                            .collect(),
+                        rules: parse_declarations(declarations)?,
+                    });
+
+                    frames = remaining.trim();
+                }
+
+                style.push_keyframes(keyframes);
+            } else {
+                style.push(StyleRule {
+                    selectors: prelude.split(',').map(parse_selector).collect(),
+                    rules: parse_declarations(body)?,
+                });
+            }
+
+            rest = remaining.trim();
+        }
+
+        Ok(style)
+    }
+}
+
+impl FromStr for PathElement {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let command = text
+            .chars()
+            .next()
+            .ok_or_else(|| "Path command can't be empty!".to_string())?;
+        let values = parse_numbers(&text[command.len_utf8()..])?;
+
+        match (command, values.as_slice()) {
+            ('M', &[x, y]) => Ok(PathElement::MoveTo(Vec2::new(x, y))),
+            ('L', &[x, y]) => Ok(PathElement::LineTo(Vec2::new(x, y))),
+            ('A', &[rx, ry, _rotation, large_arc, sweep, x, y]) => {
+                if rx != ry {
+                    return Err(format!("Elliptic arcs are not supported: '{}'!", text));
+                }
+
+                if sweep != 1.0 {
+                    return Err(format!("Only clockwise arcs are supported: '{}'!", text));
+                }
+
+                Ok(PathElement::Arc {
+                    radius: rx,
+                    large_arc: large_arc == 1.0,
+                    end: Vec2::new(x, y),
+                })
+            }
+            ('M' | 'L' | 'A', _) => Err(format!("Wrong number of arguments in '{}'!", text)),
+            _ => Err(format!("Unsupported path command '{}'!", command)),
+        }
+    }
+}
+
+fn parse_children(node: Node) -> Result<Vec<SVGElement>, String> {
+    node.children()
+        .filter(|child| child.is_element())
+        .map(parse_element)
+        .collect()
+}
+
+fn parse_element(node: Node) -> Result<SVGElement, String> {
+    let element: SVGElement = match node.tag_name().name() {
+        "title" => Title(node.text().unwrap_or_default().to_string()).into(),
+        "style" => Style::from_str(node.text().unwrap_or_default())?.into(),
+        "g" => {
+            let mut group = Group::from(parse_children(node)?);
+            group.id = node.attribute("id").map(str::to_string);
+            if let Some(transform) = node.attribute("transform") {
+                group.affine2 = parse_transform(transform)?;
+            }
+            for attribute in node.attributes() {
+                if let Some(key) = attribute.name().strip_prefix("data-") {
+                    group.data.push(key, attribute.value());
+                }
+            }
+            group.into()
+        }
+        "circle" => {
+            let center = Vec2::new(
+                parse_number_attribute(node, "cx")?,
+                parse_number_attribute(node, "cy")?,
+            );
+            let circle = Circle::new(parse_number_attribute(node, "r")?);
+
+            // circles are always centered so an offset has to be moved into a group
+            if center == Vec2::ZERO {
+                circle.into()
+            } else {
+                let mut group = Group::new();
+                group.affine2 = Affine2::from_translation(center);
+                group.push(attach_attributes(circle.into(), node)?);
+                return Ok(group.into());
+            }
+        }
+        "line" => Line::new(
+            Vec2::new(
+                parse_number_attribute(node, "x1")?,
+                parse_number_attribute(node, "y1")?,
+            ),
+            Vec2::new(
+                parse_number_attribute(node, "x2")?,
+                parse_number_attribute(node, "y2")?,
+            ),
+        )
+        .into(),
+        "path" => {
+            let d = node
+                .attribute("d")
+                .ok_or_else(|| "Missing d attribute on <path>!".to_string())?;
+            parse_path(d)?.into()
+        }
+        tag => {
+            return Err(format!("Unsupported element <{}>!", tag));
+        }
+    };
+
+    attach_attributes(element, node)
+}
+
+fn attach_attributes(mut element: SVGElement, node: Node) -> Result<SVGElement, String> {
+    if let Some(class) = node.attribute("class") {
+        element.set_class(Class(class.to_string()));
+    }
+
+    if let Some(style) = node.attribute("style") {
+        element.set_style(InlineStyle::from_str(style)?);
+    }
+
+    Ok(element)
+}
+
+/// The path is written with a flipped y-axis so it has to be flipped back.
+fn parse_path(d: &str) -> Result<Path, String> {
+    let mut path = Path::new();
+
+    let command_starts = d
+        .char_indices()
+        // 'e' belongs to the exponent of a number
+        .filter(|(_, char)| char.is_ascii_alphabetic() && !matches!(char, 'e' | 'E'))
+        .map(|(index, _)| index)
+        .chain(std::iter::once(d.len()))
+        .collect::<Vec<_>>();
+
+    if command_starts
+        .first()
+        .map_or(!d.trim().is_empty(), |it| !d[..*it].trim().is_empty())
+    {
+        return Err(format!("Path has to start with a command: '{}'!", d));
+    }
+
+    for range in command_starts.windows(2) {
+        let element = match PathElement::from_str(&d[range[0]..range[1]])? {
+            PathElement::MoveTo(pos) => PathElement::MoveTo(Vec2::new(pos.x, -pos.y)),
+            PathElement::LineTo(pos) => PathElement::LineTo(Vec2::new(pos.x, -pos.y)),
+            PathElement::Arc {
+                radius,
+                large_arc,
+                end,
+            } => PathElement::Arc {
+                radius,
+                large_arc,
+                end: Vec2::new(end.x, -end.y),
+            },
+        };
+
+        path.push(element);
+    }
+
+    Ok(path)
+}
+
+fn parse_transform(transform: &str) -> Result<Affine2, String> {
+    let values = transform
+        .trim()
+        .strip_prefix("matrix(")
+        .and_then(|it| it.strip_suffix(')'))
+        .ok_or_else(|| format!("Only matrix transforms are supported: '{}'!", transform))?;
+
+    let values: [f32; 6] = parse_numbers(values)?
+        .try_into()
+        .map_err(|_| format!("Matrix needs 6 values: '{}'!", transform))?;
+
+    Ok(Affine2::from_cols_array(&values))
+}
+
+fn parse_number_attribute(node: Node, name: &str) -> Result<f32, String> {
+    node.attribute(name).map_or(Ok(0.0), parse_number)
+}
+
+fn parse_number(text: &str) -> Result<f32, String> {
+    text.trim()
+        .parse::<f32>()
+        .map_err(|_| format!("'{}' is not a number!", text))
+}
+
+fn parse_numbers(text: &str) -> Result<Vec<f32>, String> {
+    text.split(|char: char| char.is_whitespace() || char == ',')
+        .filter(|it| !it.is_empty())
+        .map(parse_number)
+        .collect()
+}
+
+fn parse_selector(selector: &str) -> Selector {
+    let selector = selector.trim();
+
+    match selector.strip_prefix('.') {
+        Some(class) => Selector::Class(class.to_string()),
+        None => Selector::Tag(selector.to_string()),
+    }
+}
+
+/// Splits `prelude { body } rest` while respecting nested blocks.
+fn split_block(text: &str) -> Result<(&str, &str, &str), String> {
+    let open = text
+        .find('{')
+        .ok_or_else(|| format!("Missing '{{' in '{}'!", text))?;
+
+    let mut depth = 0;
+
+    for (index, char) in text.char_indices().skip_while(|(index, _)| *index < open) {
+        match char {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok((
+                        text[..open].trim(),
+                        &text[open + 1..index],
+                        &text[index + 1..],
+                    ));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Err(format!("Missing '}}' in '{}'!", text))
+}
+
+fn parse_declarations(text: &str) -> Result<Vec<CSSRule>, String> {
+    let mut rules = Vec::new();
+
+    for declaration in text.split(';').map(str::trim).filter(|it| !it.is_empty()) {
+        let (property, value) = declaration
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid css declaration '{}'!", declaration))?;
+
+        let value = value.trim();
+
+        let rule = match property.trim() {
+            "stroke" => CSSRule::Stroke(parse_color(value)?),
+            "fill" => CSSRule::Fill(parse_color(value)?),
+            "stroke-width" => CSSRule::StrokeWidth(parse_number(value)?),
+            "stroke-linecap" => CSSRule::StrokeLineCap(parse_line_cap(value)?),
+            "stroke-dasharray" => CSSRule::StrokeDashArray(parse_numbers(value)?),
+            "stroke-dashoffset" => CSSRule::StrokeDashOffset(parse_number(value)?),
+            "opacity" => CSSRule::Opacity(parse_number(value)?),
+            "animation" => CSSRule::Animation(parse_animation(value)?),
+            property => {
+                debug!("Skip unsupported css property: {}", property);
+                continue;
+            }
+        };
+
+        rules.push(rule);
+    }
+
+    Ok(rules)
+}
+
+fn parse_color(value: &str) -> Result<Option<Rgb>, String> {
+    if value == "none" {
+        return Ok(None);
+    }
+
+    if let Some(hex) = value.strip_prefix('#') {
+        return Rgb::hex(hex).map(Some);
+    }
+
+    let channels = value
+        .strip_prefix("rgb(")
+        .and_then(|it| it.strip_suffix(')'))
+        .ok_or_else(|| format!("Unsupported color '{}'!", value))?;
+
+    match parse_numbers(channels)?.as_slice() {
+        &[r, g, b] => Ok(Some(Rgb::new(r / 255.0, g / 255.0, b / 255.0))),
+        _ => Err(format!("Invalid color '{}'!", value)),
+    }
+}
+
+fn parse_line_cap(value: &str) -> Result<StrokeLineCap, String> {
+    match value {
+        "butt" => Ok(StrokeLineCap::Butt),
+        "round" => Ok(StrokeLineCap::Round),
+        "square" => Ok(StrokeLineCap::Square),
+        _ => Err(format!("Invalid stroke-linecap '{}'!", value)),
+    }
+}
+
+fn parse_seconds(value: &str) -> Result<f32, String> {
+    value
+        .strip_suffix('s')
+        .ok_or_else(|| format!("Missing unit in '{}'!", value))
+        .and_then(parse_number)
+}
+
+/// Only the shorthand written by the animated export is supported.
+fn parse_animation(value: &str) -> Result<Animation, String> {
+    match value.split_whitespace().collect::<Vec<_>>().as_slice() {
+        &[name, duration, "linear", delay, "both"] => Ok(Animation {
+            name: name.to_string(),
+            duration: parse_seconds(duration)?,
+            delay: parse_seconds(delay)?,
+        }),
+        _ => Err(format!("Unsupported animation '{}'!", value)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_parse_path_with_flipped_y_axis() {
+        let path = parse_path("M 1 -2 A 10 10 0 1 1 3 -4 L 5 6").unwrap();
+
+        let result = path
+            .elements
+            .iter()
+            .map(|element| element.to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(result, vec!["M 1 2", "A 10 10 0 1 1 3 4", "L 5 -6"]);
+    }
+
+    #[test]
+    fn should_reject_unsupported_path_commands() {
+        assert!(parse_path("M 0 0 Q 1 1 2 2").is_err());
+        assert!(parse_path("M 0 0 A 1 2 0 0 1 1 1").is_err());
+        assert!(parse_path("1 2").is_err());
+    }
+
+    #[test]
+    fn should_parse_style_with_keyframes() {
+        let text = r#"
+            .stroke,
+            circle {
+                stroke: rgb(255, 0, 0);
+                fill: none;
+                stroke-linecap: round;
+            }
+            @keyframes draw {
+                to {
+                    stroke-dashoffset: 0;
+                }
+            }
+        "#;
+
+        let style = Style::from_str(text).unwrap();
+
+        let expected = r#"<style>
+    .stroke,
+    circle {
+        stroke: rgb(255, 0, 0);
+        fill: none;
+        stroke-linecap: round;
+    }
+    @keyframes draw {
+        to {
+            stroke-dashoffset: 0;
+        }
+    }
+</style>"#;
+
+        assert_eq!(style.to_string(), expected);
+    }
+
+    #[test]
+    fn should_parse_hand_made_svg() {
+        let text = r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 20 20">
+            <circle cx="10" cy="10" r="5" style="stroke: #00ff00; color: red"/>
+            <line x1="0" y1="0" x2="20" y2="20" class="stroke"/>
+        </svg>"##;
+
+        let svg = SVG::from_str(text).unwrap();
+
+        let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<svg
+  xmlns="http://www.w3.org/2000/svg"
+  xmlns:xlink="http://www.w3.org/1999/xlink"
+  viewBox="-10 -10 20 20"
+>
+    <g transform="matrix(1 0 0 1 -10 -10)">
+        <g transform="matrix(1 0 0 1 10 10)">
+            <circle cx="0" cy="0" r="5" style="stroke: rgb(0, 255, 0);"/>
+        </g>
+        <line x1="0" y1="0" x2="20" y2="20" class="stroke"/>
+    </g>
+</svg>"#;
+
+        assert_eq!(svg.to_string(), expected);
+    }
+
+    #[test]
+    fn should_parse_group_id() {
+        let text = r#"<svg viewBox="-5 -5 10 10"><g id="e7" data-kind="dot"></g></svg>"#;
+
+        let svg = SVG::from_str(text).unwrap();
+
+        match &svg.elements[..] {
+            [SVGElement::Group(group)] => {
+                assert_eq!(group.id.as_deref(), Some("e7"));
+                assert_eq!(group.data.get("kind"), Some("dot"));
+            }
+            elements => panic!("Unexpected elements: {:?}", elements),
+        }
+    }
+
+    #[test]
+    fn should_reject_unsupported_elements() {
+        let text = r#"<svg viewBox="-5 -5 10 10"><rect width="1" height="1"/></svg>"#;
+
+        assert_eq!(
+            SVG::from_str(text).err(),
+            Some("Unsupported element <rect>!".to_string())
+        );
+    }
+}
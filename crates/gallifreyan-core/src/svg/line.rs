@@ -1,8 +1,5 @@
-use super::{Class, Indent};
-use bevy::prelude::Vec2;
-use bevy_prototype_lyon::prelude::tess::path::path::Builder;
-use bevy_prototype_lyon::prelude::Geometry;
-use bevy_prototype_lyon::shapes;
+use super::{Class, Indent, InlineStyle};
+use glam::Vec2;
 use itertools::Itertools;
 use std::fmt::{Display, Formatter};
 
@@ -11,6 +8,7 @@ pub struct Line {
     pub from: Vec2,
     pub to: Vec2,
     pub class: Class,
+    pub style: InlineStyle,
 }
 
 impl Line {
@@ -19,8 +17,13 @@ impl Line {
             from,
             to,
             class: Class::default(),
+            style: InlineStyle::default(),
         }
     }
+
+    pub fn length(&self) -> f32 {
+        self.from.distance(self.to)
+    }
 }
 
 impl Display for Line {
@@ -31,6 +34,7 @@ impl Display for Line {
             format!("x2=\"{}\"", self.to.x),
             format!("y2=\"{}\"", self.to.y),
             format!("{}", self.class),
+            format!("{}", self.style),
         ];
 
         write!(
@@ -41,12 +45,6 @@ impl Display for Line {
     }
 }
 
-impl Geometry for Line {
-    fn add_geometry(&self, b: &mut Builder) {
-        shapes::Line(self.from, self.to).add_geometry(b);
-    }
-}
-
 impl Indent for Line {}
 
 #[cfg(test)]
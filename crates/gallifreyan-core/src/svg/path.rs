@@ -1,8 +1,5 @@
-use super::{Class, Indent};
-use bevy::prelude::Vec2;
-use bevy_prototype_lyon::prelude::tess::path::path::Builder;
-use bevy_prototype_lyon::prelude::Geometry;
-use bevy_prototype_lyon::shapes;
+use super::{Class, Indent, InlineStyle};
+use glam::Vec2;
 use itertools::Itertools;
 use std::fmt::{Display, Formatter};
 
@@ -10,6 +7,7 @@ use std::fmt::{Display, Formatter};
 pub struct Path {
     pub elements: Vec<PathElement>,
     pub class: Class,
+    pub style: InlineStyle,
 }
 
 impl Path {
@@ -17,6 +15,7 @@ impl Path {
         Self {
             elements: Vec::new(),
             class: Class::default(),
+            style: InlineStyle::default(),
         }
     }
 
@@ -24,12 +23,26 @@ impl Path {
         self.elements.push(element);
     }
 
+    pub fn length(&self) -> f32 {
+        let mut current_position = Vec2::ZERO;
+
+        self.elements
+            .iter()
+            .map(|element| {
+                let length = element.length(current_position);
+                current_position = element.end();
+                length
+            })
+            .sum()
+    }
+
     pub fn path(&self, flip_y_axis: bool) -> String {
         if flip_y_axis {
             self.elements
                 .iter()
                 .map(|element| match element {
                     PathElement::MoveTo(it) => PathElement::MoveTo(Vec2::new(it.x, -it.y)),
+                    PathElement::LineTo(it) => PathElement::LineTo(Vec2::new(it.x, -it.y)),
                     PathElement::Arc {
                         end,
                         large_arc,
@@ -56,6 +69,7 @@ impl From<Vec<PathElement>> for Path {
         Self {
             elements: value,
             class: Class::default(),
+            style: InlineStyle::default(),
         }
     }
 }
@@ -65,6 +79,7 @@ impl Display for Path {
         let attributes = [
             format!("d=\"{}\"", self.path(true)),
             format!("{}", self.class),
+            format!("{}", self.style),
         ];
 
         write!(
@@ -75,19 +90,10 @@ impl Display for Path {
     }
 }
 
-impl Geometry for Path {
-    fn add_geometry(&self, b: &mut Builder) {
-        shapes::SvgPathShape {
-            svg_doc_size_in_px: Default::default(),
-            svg_path_string: self.path(false),
-        }
-        .add_geometry(b);
-    }
-}
-
 #[derive(Debug, Copy, Clone)]
 pub enum PathElement {
     MoveTo(Vec2),
+    LineTo(Vec2),
     Arc {
         radius: f32,
         large_arc: bool,
@@ -95,12 +101,51 @@ pub enum PathElement {
     },
 }
 
+impl PathElement {
+    pub fn end(&self) -> Vec2 {
+        match self {
+            PathElement::MoveTo(pos) | PathElement::LineTo(pos) => *pos,
+            PathElement::Arc { end, .. } => *end,
+        }
+    }
+
+    pub fn length(&self, start: Vec2) -> f32 {
+        match self {
+            PathElement::MoveTo(_) => 0.0,
+            PathElement::LineTo(end) => start.distance(*end),
+            PathElement::Arc {
+                radius,
+                large_arc,
+                end,
+            } => {
+                let chord = start.distance(*end);
+
+                // svg scales up radii which are too small to connect both points
+                let radius = radius.max(chord / 2.0);
+
+                let small_arc_angle = 2.0 * (chord / (2.0 * radius)).min(1.0).asin();
+
+                let arc_angle = if *large_arc {
+                    std::f32::consts::TAU - small_arc_angle
+                } else {
+                    small_arc_angle
+                };
+
+                radius * arc_angle
+            }
+        }
+    }
+}
+
 impl Display for PathElement {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
             PathElement::MoveTo(pos) => {
                 write!(f, "M {} {}", pos.x, pos.y)
             }
+            PathElement::LineTo(pos) => {
+                write!(f, "L {} {}", pos.x, pos.y)
+            }
             PathElement::Arc {
                 radius,
                 large_arc,
@@ -160,4 +205,73 @@ mod test {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn should_calculate_small_arc_length() {
+        let mut path = Path::new();
+        path.push(PathElement::MoveTo(Vec2::new(10.0, 0.0)));
+        path.push(PathElement::Arc {
+            radius: 10.0,
+            large_arc: false,
+            end: Vec2::new(0.0, 10.0),
+        });
+
+        let result = path.length();
+        let expected = 5.0 * std::f32::consts::PI;
+
+        assert!(
+            (result - expected).abs() < 0.001,
+            "{} != {}",
+            result,
+            expected
+        );
+    }
+
+    #[test]
+    fn should_calculate_large_arc_length() {
+        let mut path = Path::new();
+        path.push(PathElement::MoveTo(Vec2::new(10.0, 0.0)));
+        path.push(PathElement::Arc {
+            radius: 10.0,
+            large_arc: true,
+            end: Vec2::new(0.0, 10.0),
+        });
+
+        let result = path.length();
+        let expected = 15.0 * std::f32::consts::PI;
+
+        assert!(
+            (result - expected).abs() < 0.001,
+            "{} != {}",
+            result,
+            expected
+        );
+    }
+
+    #[test]
+    fn should_calculate_length_of_multiple_arcs() {
+        let mut path = Path::new();
+        path.push(PathElement::MoveTo(Vec2::new(10.0, 0.0)));
+        path.push(PathElement::Arc {
+            radius: 10.0,
+            large_arc: false,
+            end: Vec2::new(-10.0, 0.0),
+        });
+        path.push(PathElement::MoveTo(Vec2::new(0.0, 10.0)));
+        path.push(PathElement::Arc {
+            radius: 1.0,
+            large_arc: false,
+            end: Vec2::new(0.0, -10.0),
+        });
+
+        let result = path.length();
+        let expected = 20.0 * std::f32::consts::PI;
+
+        assert!(
+            (result - expected).abs() < 0.001,
+            "{} != {}",
+            result,
+            expected
+        );
+    }
 }
@@ -0,0 +1,433 @@
+use super::DEFAULT_INDENTATION_DEPTH;
+use super::{Indent, ToCSSString};
+use itertools::Itertools;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Default, Clone)]
+pub struct Style {
+    pub rules: Vec<StyleRule>,
+    pub keyframes: Vec<Keyframes>,
+}
+
+impl Display for Style {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let rules = self
+            .rules
+            .iter()
+            .map(|rule| rule.indent(DEFAULT_INDENTATION_DEPTH))
+            .chain(
+                self.keyframes
+                    .iter()
+                    .map(|keyframes| keyframes.indent(DEFAULT_INDENTATION_DEPTH)),
+            )
+            .join("\n");
+
+        write!(f, "<style>\n{}\n</style>", rules)
+    }
+}
+
+impl Style {
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            keyframes: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, rule: StyleRule) {
+        self.rules.push(rule);
+    }
+
+    pub fn push_keyframes(&mut self, keyframes: Keyframes) {
+        self.keyframes.push(keyframes);
+    }
+}
+
+impl Indent for Style {}
+
+#[derive(Debug, Default, Clone)]
+pub struct StyleRule {
+    pub selectors: Vec<Selector>,
+    pub rules: Vec<CSSRule>,
+}
+
+impl Display for StyleRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let selectors = self
+            .selectors
+            .iter()
+            .map(|selector| selector.to_string())
+            .join(",\n");
+
+        let rules = self
+            .rules
+            .iter()
+            .map(|rule| rule.indent(DEFAULT_INDENTATION_DEPTH))
+            .join("\n");
+
+        write!(f, "{} {{\n{}\n}}", selectors, rules)
+    }
+}
+
+impl StyleRule {
+    pub fn new() -> Self {
+        Self {
+            selectors: Vec::new(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+impl Indent for StyleRule {}
+
+#[derive(Debug, Clone, Default)]
+pub struct Keyframes {
+    pub name: String,
+    pub frames: Vec<StyleRule>,
+}
+
+impl Display for Keyframes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let frames = self
+            .frames
+            .iter()
+            .map(|frame| frame.indent(DEFAULT_INDENTATION_DEPTH))
+            .join("\n");
+
+        write!(f, "@keyframes {} {{\n{}\n}}", self.name, frames)
+    }
+}
+
+impl Keyframes {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            frames: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, frame: StyleRule) {
+        self.frames.push(frame);
+    }
+}
+
+impl Indent for Keyframes {}
+
+#[derive(Debug, Clone)]
+pub enum Selector {
+    Class(String),
+    Tag(String),
+    Keyframe(String),
+}
+
+impl Display for Selector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Selector::Class(class) => {
+                write!(f, ".{}", class)
+            }
+            Selector::Tag(tag) => {
+                write!(f, "{}", tag)
+            }
+            Selector::Keyframe(keyframe) => {
+                write!(f, "{}", keyframe)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum CSSRule {
+    Stroke(Option<Rgb>),
+    Fill(Option<Rgb>),
+    StrokeWidth(f32),
+    StrokeLineCap(StrokeLineCap),
+    StrokeDashArray(Vec<f32>),
+    StrokeDashOffset(f32),
+    Opacity(f32),
+    Animation(Animation),
+}
+
+impl Display for CSSRule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CSSRule::Stroke(stroke) => {
+                write!(f, "stroke: {};", stroke.to_css_string())
+            }
+            CSSRule::Fill(fill) => {
+                write!(f, "fill: {};", fill.to_css_string())
+            }
+            CSSRule::StrokeWidth(width) => {
+                write!(f, "stroke-width: {};", width)
+            }
+            CSSRule::StrokeLineCap(cap) => {
+                write!(f, "stroke-linecap: {};", cap)
+            }
+            CSSRule::StrokeDashArray(dash_array) => {
+                write!(f, "stroke-dasharray: {};", dash_array.iter().join(" "))
+            }
+            CSSRule::StrokeDashOffset(offset) => {
+                write!(f, "stroke-dashoffset: {};", offset)
+            }
+            CSSRule::Opacity(opacity) => {
+                write!(f, "opacity: {};", opacity)
+            }
+            CSSRule::Animation(animation) => {
+                write!(f, "animation: {};", animation)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Animation {
+    pub name: String,
+    pub duration: f32,
+    pub delay: f32,
+}
+
+impl Display for Animation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}s linear {}s both",
+            self.name, self.duration, self.delay
+        )
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+pub enum StrokeLineCap {
+    Butt,
+    Round,
+    Square,
+}
+
+impl Display for StrokeLineCap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StrokeLineCap::Butt => {
+                write!(f, "butt")
+            }
+            StrokeLineCap::Round => {
+                write!(f, "round")
+            }
+            StrokeLineCap::Square => {
+                write!(f, "square")
+            }
+        }
+    }
+}
+
+impl Indent for CSSRule {}
+
+impl ToCSSString for Option<Rgb> {
+    fn to_css_string(&self) -> String {
+        match self {
+            Some(color) => {
+                format!(
+                    "rgb({}, {}, {})",
+                    color.r * 255.0,
+                    color.g * 255.0,
+                    color.b * 255.0
+                )
+            }
+            None => "none".to_string(),
+        }
+    }
+}
+
+/// Color with channels between 0 and 1.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Rgb {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl Rgb {
+    pub const BLACK: Self = Self::new(0.0, 0.0, 0.0);
+
+    pub const fn new(r: f32, g: f32, b: f32) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Parses `rgb`, `rgba`, `rrggbb` or `rrggbbaa` while the alpha channel is ignored.
+    pub fn hex(hex: &str) -> Result<Self, String> {
+        let channel = |digits: &str| {
+            u8::from_str_radix(digits, 16)
+                .map(|it| it as f32 / 255.0)
+                .map_err(|_| format!("Invalid hex color '{}'!", hex))
+        };
+
+        let short_channel = |index: usize| channel(&hex[index..=index].repeat(2));
+
+        match hex.len() {
+            3 | 4 if hex.is_ascii() => Ok(Self::new(
+                short_channel(0)?,
+                short_channel(1)?,
+                short_channel(2)?,
+            )),
+            6 | 8 if hex.is_ascii() => Ok(Self::new(
+                channel(&hex[0..2])?,
+                channel(&hex[2..4])?,
+                channel(&hex[4..6])?,
+            )),
+            _ => Err(format!("Invalid hex color '{}'!", hex)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Class(pub String);
+
+impl Display for Class {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            write!(f, "")
+        } else {
+            write!(f, "class=\"{}\"", self.0)
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct InlineStyle(pub Vec<CSSRule>);
+
+impl Display for InlineStyle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.0.is_empty() {
+            write!(f, "")
+        } else {
+            write!(f, "style=\"{}\"", self.0.iter().join(" "))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_create_style_tag() {
+        let mut style = Style::new();
+
+        let mut style_rule_1 = StyleRule::new();
+        style_rule_1
+            .selectors
+            .push(Selector::Class("word".to_string()));
+        style_rule_1
+            .selectors
+            .push(Selector::Tag("circle".to_string()));
+        style_rule_1.rules.push(CSSRule::Fill(None));
+        style_rule_1
+            .rules
+            .push(CSSRule::Stroke(Some(Rgb::new(1.0, 0.08, 0.58))));
+        style_rule_1.rules.push(CSSRule::StrokeWidth(2.0));
+        style_rule_1
+            .rules
+            .push(CSSRule::StrokeLineCap(StrokeLineCap::Round));
+
+        let mut style_rule_2 = StyleRule::new();
+        style_rule_2
+            .selectors
+            .push(Selector::Class("foo".to_string()));
+
+        style_rule_2
+            .rules
+            .push(CSSRule::Fill(Some(Rgb::new(1.0, 0.08, 0.58))));
+        style_rule_2.rules.push(CSSRule::Stroke(None));
+
+        style.push(style_rule_1);
+        style.push(style_rule_2);
+
+        let result = style.to_string();
+
+        let expected = r#"<style>
+    .word,
+    circle {
+        fill: none;
+        stroke: rgb(255, 20.4, 147.9);
+        stroke-width: 2;
+        stroke-linecap: round;
+    }
+    .foo {
+        fill: rgb(255, 20.4, 147.9);
+        stroke: none;
+    }
+</style>"#;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_create_style_tag_with_keyframes() {
+        let mut style = Style::new();
+
+        let mut style_rule = StyleRule::new();
+        style_rule
+            .selectors
+            .push(Selector::Class("foo".to_string()));
+        style_rule.rules.push(CSSRule::Fill(None));
+
+        let mut frame = StyleRule::new();
+        frame.selectors.push(Selector::Keyframe("to".to_string()));
+        frame.rules.push(CSSRule::StrokeDashOffset(0.0));
+
+        let mut keyframes = Keyframes::new("draw");
+        keyframes.push(frame);
+
+        style.push(style_rule);
+        style.push_keyframes(keyframes);
+
+        let result = style.to_string();
+
+        let expected = r#"<style>
+    .foo {
+        fill: none;
+    }
+    @keyframes draw {
+        to {
+            stroke-dashoffset: 0;
+        }
+    }
+</style>"#;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_create_inline_style_attribute() {
+        let style = InlineStyle(vec![
+            CSSRule::Stroke(Some(Rgb::new(1.0, 0.0, 0.0))),
+            CSSRule::StrokeWidth(2.5),
+            CSSRule::StrokeDashArray(vec![5.0, 3.0]),
+            CSSRule::Opacity(0.5),
+            CSSRule::Animation(Animation {
+                name: "draw".to_string(),
+                duration: 2.0,
+                delay: 0.5,
+            }),
+        ]);
+
+        let result = style.to_string();
+        let expected = r#"style="stroke: rgb(255, 0, 0); stroke-width: 2.5; stroke-dasharray: 5 3; opacity: 0.5; animation: draw 2s linear 0.5s both;""#;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_parse_hex_colors() {
+        assert_eq!(Rgb::hex("00ff00"), Ok(Rgb::new(0.0, 1.0, 0.0)));
+        assert_eq!(Rgb::hex("f00f"), Ok(Rgb::new(1.0, 0.0, 0.0)));
+        assert!(Rgb::hex("0f").is_err());
+        assert!(Rgb::hex("00gg00").is_err());
+    }
+
+    #[test]
+    fn should_create_empty_inline_style_attribute() {
+        let result = InlineStyle::default().to_string();
+
+        assert_eq!(result, "");
+    }
+}
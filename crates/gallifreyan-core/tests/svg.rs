@@ -0,0 +1,92 @@
+use gallifreyan_core::alphabet::Alphabet;
+use gallifreyan_core::layout::ReadingDirection;
+use gallifreyan_core::model::Sentence;
+
+fn assert_svg(text: &str, nest_vocals: bool, file: &str) {
+    let alphabet = Alphabet::sherman();
+    let result = Sentence::new(text, &nest_vocals, &alphabet, ReadingDirection::default())
+        .to_svg(&alphabet)
+        .to_string();
+    assert_eq!(result, file.replace("\r\n", "\n"));
+}
+
+#[test]
+fn vocal_nesting_a() {
+    assert_svg(
+        "abajatatha",
+        true,
+        include_str!("../../../tests/svg/abajatatha.svg"),
+    );
+}
+
+#[test]
+fn vocal_nesting_e() {
+    assert_svg(
+        "ebejetethe",
+        true,
+        include_str!("../../../tests/svg/ebejetethe.svg"),
+    );
+}
+
+#[test]
+fn vocal_nesting_i() {
+    assert_svg(
+        "ibijitithi",
+        true,
+        include_str!("../../../tests/svg/ibijitithi.svg"),
+    );
+}
+
+#[test]
+fn vocal_nesting_o() {
+    assert_svg(
+        "obojototho",
+        true,
+        include_str!("../../../tests/svg/obojototho.svg"),
+    );
+}
+
+#[test]
+fn vocal_nesting_u() {
+    assert_svg(
+        "ubujututhu",
+        true,
+        include_str!("../../../tests/svg/ubujututhu.svg"),
+    );
+}
+
+#[test]
+fn consonant_deep_cut() {
+    assert_svg(
+        "bchdhgf",
+        false,
+        include_str!("../../../tests/svg/bchdhgf.svg"),
+    );
+}
+
+#[test]
+fn consonant_inside() {
+    assert_svg(
+        "jphklcnpm",
+        false,
+        include_str!("../../../tests/svg/jphklcnpm.svg"),
+    );
+}
+
+#[test]
+fn consonant_shallow_cut() {
+    assert_svg(
+        "twhshrvws",
+        false,
+        include_str!("../../../tests/svg/twhshrvws.svg"),
+    );
+}
+
+#[test]
+fn consonant_on_line() {
+    assert_svg(
+        "thghyzqquxng",
+        false,
+        include_str!("../../../tests/svg/thghyzqquxng.svg"),
+    );
+}
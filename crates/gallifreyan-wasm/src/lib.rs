@@ -0,0 +1,171 @@
+//! JavaScript api to convert text into svgs without loading the editor.
+
+use gallifreyan_core::export::ExportOptions;
+use gallifreyan_core::layout::ReadingDirection;
+use gallifreyan_core::script::create_script_system;
+use gallifreyan_core::svg::Rgb;
+use serde::Deserialize;
+use wasm_bindgen::prelude::*;
+
+/// Passed as plain object like `{ script: "clockwork", strokeWidth: 2 }`, every option is
+/// optional.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct SvgOptions {
+    /// Id of the script system like `sherman` or `clockwork`, defaults to `sherman`.
+    pub script: Option<String>,
+    /// Nesting rules like `ba,*e` or an empty string to nest no vocals. All vocals get nested if
+    /// it's not set.
+    pub nesting: Option<String>,
+    /// Hex color of the strokes and dots with an optional leading `#`.
+    pub color: String,
+    pub stroke_width: f32,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            script: None,
+            nesting: None,
+            color: "#000000".to_string(),
+            stroke_width: 1.0,
+        }
+    }
+}
+
+/// Converts the text the same way as the editor and returns the exported svg.
+#[wasm_bindgen(js_name = toGallifreyanSvg)]
+pub fn to_gallifreyan_svg_js(text: &str, options: JsValue) -> Result<String, String> {
+    let options = if options.is_undefined() || options.is_null() {
+        SvgOptions::default()
+    } else {
+        serde_wasm_bindgen::from_value(options)
+            .map_err(|error| format!("Invalid options: {}", error))?
+    };
+
+    to_gallifreyan_svg(text, &options)
+}
+
+pub fn to_gallifreyan_svg(text: &str, options: &SvgOptions) -> Result<String, String> {
+    let export_options = ExportOptions {
+        color: Rgb::hex(options.color.trim_start_matches('#'))?,
+        stroke_width: options.stroke_width,
+    };
+
+    let script_system =
+        create_script_system(options.script.as_deref(), options.nesting.as_deref())?;
+
+    Ok(script_system
+        .to_svg(text, ReadingDirection::default(), &export_options)?
+        .to_string())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn fixture(svg: &str) -> String {
+        svg.replace("\r\n", "\n")
+    }
+
+    #[test]
+    fn should_nest_all_vocals_by_default() {
+        let result = to_gallifreyan_svg("abajatatha", &SvgOptions::default());
+
+        assert_eq!(
+            result,
+            Ok(fixture(include_str!("../../../tests/svg/abajatatha.svg")))
+        );
+    }
+
+    #[test]
+    fn should_nest_no_vocals() {
+        let options = SvgOptions {
+            nesting: Some(String::new()),
+            ..SvgOptions::default()
+        };
+
+        let result = to_gallifreyan_svg("bchdhgf", &options);
+
+        assert_eq!(
+            result,
+            Ok(fixture(include_str!("../../../tests/svg/bchdhgf.svg")))
+        );
+    }
+
+    #[test]
+    fn should_use_script_system() {
+        let options = SvgOptions {
+            script: Some("clockwork".to_string()),
+            ..SvgOptions::default()
+        };
+
+        let result = to_gallifreyan_svg("clock", &options).unwrap();
+
+        assert!(result.contains("<line"));
+        assert_eq!(
+            to_gallifreyan_svg(
+                "clock",
+                &SvgOptions {
+                    script: Some("cot".to_string()),
+                    ..SvgOptions::default()
+                }
+            ),
+            Err("'cot' is not a known script system!".to_string())
+        );
+    }
+
+    #[test]
+    fn should_sanitize_text() {
+        let result = to_gallifreyan_svg("  aba-jat, atha! ", &SvgOptions::default()).unwrap();
+
+        assert!(result.contains("<title>abajat atha</title>"));
+    }
+
+    #[test]
+    fn should_nest_custom_vocals() {
+        let options = SvgOptions {
+            nesting: Some("ja".to_string()),
+            ..SvgOptions::default()
+        };
+
+        let all = to_gallifreyan_svg("baja", &SvgOptions::default()).unwrap();
+        let custom = to_gallifreyan_svg("baja", &options).unwrap();
+
+        assert_ne!(all, custom);
+    }
+
+    #[test]
+    fn should_apply_export_options() {
+        let options = SvgOptions {
+            color: "#ff0000".to_string(),
+            stroke_width: 2.5,
+            ..SvgOptions::default()
+        };
+
+        let result = to_gallifreyan_svg("a", &options).unwrap();
+
+        assert!(result.contains("stroke: rgb(255, 0, 0);"));
+        assert!(result.contains("fill: rgb(255, 0, 0);"));
+        assert!(result.contains("stroke-width: 2.5;"));
+    }
+
+    #[test]
+    fn should_report_invalid_options() {
+        let invalid_rules = SvgOptions {
+            nesting: Some("b".to_string()),
+            ..SvgOptions::default()
+        };
+
+        let invalid_color = SvgOptions {
+            color: "red".to_string(),
+            ..SvgOptions::default()
+        };
+
+        assert_eq!(
+            to_gallifreyan_svg("ba", &invalid_rules),
+            Err("'b' is missing a vocal!".to_string())
+        );
+        assert!(to_gallifreyan_svg("ba", &invalid_color).is_err());
+    }
+}
@@ -0,0 +1,25 @@
+//! Headless serve mode of the app. It's its own binary because the `gallifreyan` binary requires
+//! the `gui` feature with a window, egui and x11, which a server shouldn't need to build or run.
+
+use gallifreyan_lib::serve::{ServeSettings, Server, DEFAULT_PORT};
+use tracing_subscriber::EnvFilter;
+
+fn main() {
+    tracing_subscriber::fmt()
+        .with_env_filter(
+            EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")),
+        )
+        .init();
+
+    let port = std::env::args()
+        .nth(1)
+        .map(|port| port.parse().expect("Port should be a number!"))
+        .unwrap_or(DEFAULT_PORT);
+
+    Server::bind(ServeSettings {
+        port,
+        ..Default::default()
+    })
+    .and_then(Server::run)
+    .expect("Failed to serve!");
+}
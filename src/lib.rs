@@ -1,3 +1,6 @@
-pub mod math;
+pub use gallifreyan_core::math;
+
 pub mod plugins;
+#[cfg(all(feature = "serve", not(target_arch = "wasm32")))]
+pub mod serve;
 pub mod utils;
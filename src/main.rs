@@ -37,6 +37,7 @@ fn main() {
         .add_plugin(plugins::svg::SVGPlugin)
         .add_plugin(plugins::interaction::InteractionPlugin)
         .add_plugin(plugins::selection::SelectionPlugin)
+        .add_plugin(plugins::reference_image::ReferenceImagePlugin)
         .add_plugin(plugins::file::FilePlugin);
 
     #[cfg(not(target_arch = "wasm32"))]
@@ -1,8 +1,36 @@
 pub mod color_theme;
+#[cfg(feature = "native-dialogs")]
 pub mod file;
+pub mod glyph;
 pub mod interaction;
+#[cfg(feature = "render")]
+pub mod reference_image;
+#[cfg(feature = "gui")]
 pub mod selection;
 pub mod svg;
+#[cfg(feature = "gui")]
 pub mod svg_view;
 pub mod text_converter;
+#[cfg(feature = "gui")]
 pub mod ui;
+
+use bevy::app::{PluginGroup, PluginGroupBuilder};
+
+/// Plugins to convert and draw Gallifreyan text without the editor ui, e.g. for [`glyph::GlyphBundle`].
+///
+/// Disable the `ShapePlugin` of lyon if it's already added to the app.
+pub struct GallifreyanPlugins;
+
+impl PluginGroup for GallifreyanPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        let group = PluginGroupBuilder::start::<Self>()
+            .add(text_converter::TextConverterPlugin)
+            .add(svg::SVGPlugin)
+            .add(glyph::GlyphPlugin);
+
+        #[cfg(feature = "render")]
+        let group = group.add(bevy_prototype_lyon::plugin::ShapePlugin);
+
+        group
+    }
+}
@@ -1,15 +1,21 @@
 use bevy::prelude::*;
 use bevy::utils::HashMap;
+#[cfg(feature = "gui")]
 use bevy_egui::egui::Color32;
+#[cfg(feature = "gui")]
 use bevy_egui::{egui, EguiContexts};
 
 pub struct ColorThemePlugin;
 
 impl Plugin for ColorThemePlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<ColorTheme>();
+
+        #[cfg(feature = "gui")]
+        app.add_startup_system(setup_color_theme);
+
+        #[cfg(feature = "render")]
         app.insert_resource(ClearColor(Color::rgb_u8(27, 27, 27)))
-            .init_resource::<ColorTheme>()
-            .add_startup_system(setup_color_theme)
             .add_system(update_clear_color);
     }
 }
@@ -23,6 +29,7 @@ pub enum Theme {
 pub const BACKGROUND_COLOR: &str = "BACKGROUND_COLOR";
 pub const DRAW_COLOR: &str = "DRAW_COLOR";
 pub const SELECT_COLOR: &str = "SELECT_COLOR";
+pub const HIGHLIGHT_COLOR: &str = "HIGHLIGHT_COLOR";
 
 #[derive(Component)]
 pub struct ColorDependency(pub &'static str);
@@ -38,6 +45,7 @@ impl ColorTheme {
         self.theme
     }
 
+    #[cfg(feature = "gui")]
     pub fn set_theme(&mut self, theme: Theme, ctx: &egui::Context) {
         let visuals = match theme {
             Theme::Dark => egui::Visuals::dark(),
@@ -61,15 +69,39 @@ impl ColorTheme {
     }
 }
 
+/// Same colors as the default egui visuals so that the theme works without the ui.
 impl Default for ColorTheme {
     fn default() -> Self {
-        Self {
+        let mut color_theme = Self {
             theme: Theme::Dark,
             values: HashMap::new(),
-        }
+        };
+
+        color_theme.insert(
+            BACKGROUND_COLOR,
+            Color::rgb_u8(27, 27, 27),
+            Color::rgb_u8(248, 248, 248),
+        );
+
+        color_theme.insert(DRAW_COLOR, Color::WHITE, Color::BLACK);
+
+        color_theme.insert(
+            SELECT_COLOR,
+            Color::rgb_u8(0, 92, 128),
+            Color::rgb_u8(144, 209, 255),
+        );
+
+        color_theme.insert(
+            HIGHLIGHT_COLOR,
+            Color::rgb_u8(255, 143, 0),
+            Color::rgb_u8(255, 100, 0),
+        );
+
+        color_theme
     }
 }
 
+#[cfg(feature = "gui")]
 fn setup_color_theme(mut color_theme: ResMut<ColorTheme>, mut egui_contexts: EguiContexts) {
     color_theme.set_theme(Theme::Dark, egui_contexts.ctx_mut());
 
@@ -89,8 +121,15 @@ fn setup_color_theme(mut color_theme: ResMut<ColorTheme>, mut egui_contexts: Egu
         dark.selection.bg_fill.into_color(),
         light.selection.bg_fill.into_color(),
     );
+
+    color_theme.insert(
+        HIGHLIGHT_COLOR,
+        dark.warn_fg_color.into_color(),
+        light.warn_fg_color.into_color(),
+    );
 }
 
+#[cfg(feature = "render")]
 fn update_clear_color(mut clear_color: ResMut<ClearColor>, color_theme: Res<ColorTheme>) {
     if !color_theme.is_changed() {
         return;
@@ -99,10 +138,12 @@ fn update_clear_color(mut clear_color: ResMut<ClearColor>, color_theme: Res<Colo
     *clear_color = ClearColor(color_theme.get(BACKGROUND_COLOR).unwrap());
 }
 
+#[cfg(feature = "gui")]
 pub trait IntoColor {
     fn into_color(self) -> Color;
 }
 
+#[cfg(feature = "gui")]
 impl IntoColor for Color32 {
     fn into_color(self) -> Color {
         Color::rgba_u8(self.r(), self.g(), self.b(), self.a())
@@ -2,13 +2,20 @@
 #[cfg_attr(target_arch = "wasm32", path = "file/wasm.rs")]
 pub mod os;
 
+use crate::plugins::reference_image::{LoadReferenceImage, ReferenceImage};
+use crate::plugins::svg::animation::DrawAnimation;
 use crate::plugins::svg::export::SVGExportSystemParams;
+use crate::plugins::svg::metadata::ImportSVG;
+use crate::plugins::svg::SVG;
 use crate::plugins::text_converter::components::{
-    Dot, Letter, LineSlot, NestedVocalPositionCorrection, Sentence, Word,
+    CustomDecoration, Dot, Letter, LineSlot, NestedVocalPositionCorrection, Sentence, Word,
 };
+use crate::plugins::text_converter::document::DocumentOptions;
+use crate::plugins::text_converter::script::ScriptElementKind;
 use crate::utils::event_set::*;
 use bevy::prelude::*;
 use futures::channel::oneshot;
+use std::str::FromStr;
 
 pub struct FilePlugin;
 
@@ -22,11 +29,24 @@ impl Plugin for FilePlugin {
         app.init_non_send_resource::<FileHandles>()
             .init_non_send_resource::<FileHandleReceiver>();
 
-        app.add_event_set::<FileActions>()
+        app.init_resource::<ExportSettings>()
+            .init_resource::<FileContentReceiver>()
+            .init_resource::<ExportResultReceiver>()
+            .add_event_set::<FileActions>()
+            .add_event::<ExportCompleted>()
+            .add_event::<ExportFailed>()
             .add_system(handle_file_handle_action_event)
             .add_system(receive_file_handle.after(handle_file_handle_action_event))
             .add_system(handle_save_event.after(receive_file_handle))
-            .add_system(handle_export_event.after(receive_file_handle));
+            .add_system(handle_export_event.after(receive_file_handle))
+            .add_system(handle_animated_export_event.after(receive_file_handle))
+            .add_system(handle_import_event.after(receive_file_handle))
+            .add_system(receive_file_content.after(handle_import_event))
+            .add_system(
+                receive_export_result
+                    .after(handle_export_event)
+                    .after(handle_animated_export_event),
+            );
     }
 }
 
@@ -34,7 +54,9 @@ event_set!(pub FileActions {
     FileHandleAction,
     Load,
     Save,
-    Export
+    Export,
+    AnimatedExport,
+    Import
 });
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -42,6 +64,9 @@ pub enum FileHandleAction {
     Open,
     Save,
     Export,
+    AnimatedExport,
+    Import,
+    OpenReferenceImage,
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -53,19 +78,56 @@ pub struct Save;
 #[derive(Debug, Copy, Clone)]
 pub struct Export;
 
+#[derive(Debug, Copy, Clone)]
+pub struct AnimatedExport;
+
+#[derive(Debug, Copy, Clone)]
+pub struct Import;
+
+/// Sent in [`CoreSet::Update`] once the exported svg was written to the file.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct ExportCompleted {
+    pub animated: bool,
+}
+
+/// Sent in [`CoreSet::Update`] if the svg couldn't be created or written to the file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExportFailed {
+    pub animated: bool,
+    pub error: String,
+}
+
+#[derive(Debug, Default, Resource)]
+pub struct ExportSettings {
+    pub embed_metadata: bool,
+}
+
 #[derive(Default)]
 #[cfg_attr(not(target_arch = "wasm32"), derive(Resource))]
 pub struct FileHandles {
     pub ron: Option<os::FileHandle>,
     pub svg: Option<os::FileHandle>,
+    /// Kept apart from [`FileHandles::svg`], so an export never overwrites the imported file.
+    pub import: Option<os::FileHandle>,
 }
 
 type FileHandleChannelType = (os::FileHandle, FileHandleAction);
 
+type FileContentChannelType = (Vec<u8>, FileHandleAction);
+
+type ExportResultChannelType = Result<(), String>;
+
 #[derive(Default)]
 #[cfg_attr(not(target_arch = "wasm32"), derive(Resource))]
 pub struct FileHandleReceiver(Option<oneshot::Receiver<FileHandleChannelType>>);
 
+#[derive(Default, Resource)]
+pub struct FileContentReceiver(Option<oneshot::Receiver<FileContentChannelType>>);
+
+/// Whether the export is animated and the receiver of the result of writing it.
+#[derive(Default, Resource)]
+pub struct ExportResultReceiver(Option<(bool, oneshot::Receiver<ExportResultChannelType>)>);
+
 fn handle_file_handle_action_event(
     mut events: EventReader<FileHandleAction>,
     mut file_handle_receiver: os::FileHandleReceiverResourceMut,
@@ -81,6 +143,7 @@ fn receive_file_handle(
     mut file_handle_receiver: os::FileHandleReceiverResourceMut,
     mut file_handles: os::FileHandlesResourceMut,
     mut file_actions: FileActions,
+    mut file_content_receiver: ResMut<FileContentReceiver>,
 ) {
     if let Some(mut receiver) = file_handle_receiver.0.take() {
         match receiver.try_recv() {
@@ -97,6 +160,17 @@ fn receive_file_handle(
                     file_handles.svg = Some(path_buffer);
                     file_actions.dispatch(Export);
                 }
+                FileHandleAction::AnimatedExport => {
+                    file_handles.svg = Some(path_buffer);
+                    file_actions.dispatch(AnimatedExport);
+                }
+                FileHandleAction::Import => {
+                    file_handles.import = Some(path_buffer);
+                    file_actions.dispatch(Import);
+                }
+                FileHandleAction::OpenReferenceImage => {
+                    load_file_content(path_buffer, action, &mut file_content_receiver);
+                }
             },
             Ok(None) => {
                 file_handle_receiver.0 = Some(receiver);
@@ -106,6 +180,7 @@ fn receive_file_handle(
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn handle_save_event(
     world: &World,
     mut events: EventReader<Save>,
@@ -119,6 +194,10 @@ fn handle_save_event(
             With<NestedVocalPositionCorrection>,
             With<Dot>,
             With<LineSlot>,
+            With<ReferenceImage>,
+            With<DocumentOptions>,
+            With<ScriptElementKind>,
+            With<CustomDecoration>,
         )>,
     >,
 ) {
@@ -133,7 +212,7 @@ fn handle_save_event(
             match scene.serialize_ron(type_registry) {
                 Ok(data) => {
                     info!("Save to file: {:?}", path_buffer);
-                    os::save_to_file(path_buffer, data);
+                    os::save_to_file(path_buffer, data, None);
                 }
                 Err(error) => {
                     error!("{}", error);
@@ -147,19 +226,172 @@ fn handle_export_event(
     mut events: EventReader<Export>,
     file_handles: os::FileHandlesResource,
     svg_export: SVGExportSystemParams,
+    export_settings: Res<ExportSettings>,
+    mut export_result_receiver: ResMut<ExportResultReceiver>,
+    mut export_failed_events: EventWriter<ExportFailed>,
 ) {
     if events.iter().last().is_some() {
         if let Some(path_buffer) = file_handles.svg.clone() {
             info!("Export to file: {:?}", path_buffer);
 
-            match svg_export.create_svg() {
+            match create_svg(&svg_export, &export_settings) {
                 Ok(svg) => {
-                    os::save_to_file(path_buffer, svg.to_string());
+                    save_export(
+                        path_buffer,
+                        svg.to_string(),
+                        false,
+                        &mut export_result_receiver,
+                    );
                 }
                 Err(err) => {
                     error!("Failed to export file to svg: {}", err);
+                    export_failed_events.send(ExportFailed {
+                        animated: false,
+                        error: err,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn handle_animated_export_event(
+    mut events: EventReader<AnimatedExport>,
+    file_handles: os::FileHandlesResource,
+    svg_export: SVGExportSystemParams,
+    draw_animation: Res<DrawAnimation>,
+    export_settings: Res<ExportSettings>,
+    mut export_result_receiver: ResMut<ExportResultReceiver>,
+    mut export_failed_events: EventWriter<ExportFailed>,
+) {
+    if events.iter().last().is_some() {
+        if let Some(path_buffer) = file_handles.svg.clone() {
+            info!("Export animation to file: {:?}", path_buffer);
+
+            match create_svg(&svg_export, &export_settings) {
+                Ok(mut svg) => {
+                    draw_animation.apply(&mut svg);
+                    save_export(
+                        path_buffer,
+                        svg.to_string(),
+                        true,
+                        &mut export_result_receiver,
+                    );
+                }
+                Err(err) => {
+                    error!("Failed to export file to animated svg: {}", err);
+                    export_failed_events.send(ExportFailed {
+                        animated: true,
+                        error: err,
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn save_export(
+    path_buffer: os::FileHandle,
+    content: String,
+    animated: bool,
+    export_result_receiver: &mut ExportResultReceiver,
+) {
+    let (sender, receiver) = oneshot::channel::<ExportResultChannelType>();
+    os::save_to_file(path_buffer, content, Some(sender));
+    export_result_receiver.0 = Some((animated, receiver));
+}
+
+fn receive_export_result(
+    mut export_result_receiver: ResMut<ExportResultReceiver>,
+    mut export_completed_events: EventWriter<ExportCompleted>,
+    mut export_failed_events: EventWriter<ExportFailed>,
+) {
+    if let Some((animated, mut receiver)) = export_result_receiver.0.take() {
+        match receiver.try_recv() {
+            Ok(Some(Ok(()))) => {
+                export_completed_events.send(ExportCompleted { animated });
+            }
+            Ok(Some(Err(error))) => {
+                export_failed_events.send(ExportFailed { animated, error });
+            }
+            Ok(None) => {
+                export_result_receiver.0 = Some((animated, receiver));
+            }
+            Err(_canceled) => {}
+        }
+    }
+}
+
+fn create_svg(
+    svg_export: &SVGExportSystemParams,
+    export_settings: &ExportSettings,
+) -> Result<SVG, String> {
+    if export_settings.embed_metadata {
+        svg_export.create_svg_with_metadata()
+    } else {
+        svg_export.create_svg()
+    }
+}
+
+fn handle_import_event(
+    mut events: EventReader<Import>,
+    file_handles: os::FileHandlesResource,
+    mut file_content_receiver: ResMut<FileContentReceiver>,
+) {
+    if events.iter().last().is_some() {
+        if let Some(path_buffer) = file_handles.import.clone() {
+            info!("Import from file: {:?}", path_buffer);
+            load_file_content(
+                path_buffer,
+                FileHandleAction::Import,
+                &mut file_content_receiver,
+            );
+        }
+    }
+}
+
+fn load_file_content(
+    path_buffer: os::FileHandle,
+    action: FileHandleAction,
+    file_content_receiver: &mut FileContentReceiver,
+) {
+    let (sender, receiver) = oneshot::channel::<FileContentChannelType>();
+    os::load_from_file(path_buffer, action, sender);
+    file_content_receiver.0 = Some(receiver);
+}
+
+fn receive_file_content(
+    mut file_content_receiver: ResMut<FileContentReceiver>,
+    mut import_svg_events: EventWriter<ImportSVG>,
+    mut load_reference_image_events: EventWriter<LoadReferenceImage>,
+) {
+    if let Some(mut receiver) = file_content_receiver.0.take() {
+        match receiver.try_recv() {
+            Ok(Some((content, action))) => match action {
+                FileHandleAction::Import => {
+                    match String::from_utf8(content)
+                        .map_err(|err| err.to_string())
+                        .and_then(|content| SVG::from_str(&content))
+                    {
+                        Ok(svg) => {
+                            import_svg_events.send(ImportSVG(svg));
+                        }
+                        Err(err) => {
+                            error!("Failed to parse svg: {}", err);
+                        }
+                    }
+                }
+                FileHandleAction::OpenReferenceImage => {
+                    load_reference_image_events.send(LoadReferenceImage(content));
                 }
+                _ => {
+                    error!("Unexpected file content for {:?}", action);
+                }
+            },
+            Ok(None) => {
+                file_content_receiver.0 = Some(receiver);
             }
+            Err(_canceled) => {}
         }
     }
 }
@@ -18,6 +18,9 @@ pub fn spawn_file_handle_task(
     const SVG: &str = "Scalable Vector Graphics";
     const SVG_EXTENSIONS: &[&str] = &["svg"];
 
+    const IMAGE: &str = "Image";
+    const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg"];
+
     let task = async move {
         let file_dialog = rfd::FileDialog::new();
 
@@ -28,7 +31,13 @@ pub fn spawn_file_handle_task(
             super::FileHandleAction::Save => {
                 file_dialog.add_filter(RON, RON_EXTENSIONS).save_file()
             }
-            super::FileHandleAction::Export => {
+            super::FileHandleAction::OpenReferenceImage => {
+                file_dialog.add_filter(IMAGE, IMAGE_EXTENSIONS).pick_file()
+            }
+            super::FileHandleAction::Import => {
+                file_dialog.add_filter(SVG, SVG_EXTENSIONS).pick_file()
+            }
+            super::FileHandleAction::Export | super::FileHandleAction::AnimatedExport => {
                 file_dialog.add_filter(SVG, SVG_EXTENSIONS).save_file()
             }
         };
@@ -45,23 +54,64 @@ pub fn spawn_file_handle_task(
     AsyncComputeTaskPool::get().spawn(task).detach();
 }
 
-pub fn save_to_file(file_handle: FileHandle, content: String) {
+pub fn save_to_file(
+    file_handle: FileHandle,
+    content: String,
+    result_sender: Option<oneshot::Sender<Result<(), String>>>,
+) {
     IoTaskPool::get()
         .spawn(async move {
-            if let Err(error) = std::fs::write(file_handle.clone(), content) {
-                let msg = format!("{}", error);
+            let result =
+                std::fs::write(file_handle.clone(), content).map_err(|error| format!("{}", error));
 
-                error!(msg);
+            if let Err(msg) = &result {
+                error!("{}", msg);
 
                 rfd::MessageDialog::new()
                     .set_title("Failed to save file")
-                    .set_description(&msg)
+                    .set_description(msg)
                     .set_buttons(rfd::MessageButtons::Ok)
                     .set_level(rfd::MessageLevel::Error)
                     .show();
             } else {
                 info!("Successfully wrote to file: {:?}", file_handle);
             }
+
+            if let Some(result_sender) = result_sender {
+                if result_sender.send(result).is_err() {
+                    error!("Couldn't send save result because receiver was already closed!");
+                }
+            }
+        })
+        .detach();
+}
+
+pub fn load_from_file(
+    file_handle: FileHandle,
+    action: super::FileHandleAction,
+    sender: oneshot::Sender<super::FileContentChannelType>,
+) {
+    IoTaskPool::get()
+        .spawn(async move {
+            match std::fs::read(file_handle.clone()) {
+                Ok(content) => {
+                    if sender.send((content, action)).is_err() {
+                        error!("Couldn't send file content because receiver was already closed!");
+                    }
+                }
+                Err(error) => {
+                    let msg = format!("{}", error);
+
+                    error!(msg);
+
+                    rfd::MessageDialog::new()
+                        .set_title("Failed to load file")
+                        .set_description(&msg)
+                        .set_buttons(rfd::MessageButtons::Ok)
+                        .set_level(rfd::MessageLevel::Error)
+                        .show();
+                }
+            }
         })
         .detach();
 }
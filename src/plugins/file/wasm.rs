@@ -20,6 +20,15 @@ extern "C" {
     #[wasm_bindgen(catch)]
     async fn saveSVGFile() -> Result<JsValue, JsValue>;
 
+    #[wasm_bindgen(catch)]
+    async fn openSVGFile() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch)]
+    async fn openImageFile() -> Result<JsValue, JsValue>;
+
+    #[wasm_bindgen(catch)]
+    async fn loadFromFile(file_handle: JsValue) -> Result<JsValue, JsValue>;
+
     #[wasm_bindgen(catch)]
     async fn saveToFile(file_handle: JsValue, data: String) -> Result<(), JsValue>;
 }
@@ -32,7 +41,11 @@ pub fn spawn_file_handle_task(
         let file_handle = match action {
             super::FileHandleAction::Open => openRONFile().await,
             super::FileHandleAction::Save => saveRONFile().await,
-            super::FileHandleAction::Export => saveSVGFile().await,
+            super::FileHandleAction::Import => openSVGFile().await,
+            super::FileHandleAction::OpenReferenceImage => openImageFile().await,
+            super::FileHandleAction::Export | super::FileHandleAction::AnimatedExport => {
+                saveSVGFile().await
+            }
         };
 
         match file_handle {
@@ -50,17 +63,23 @@ pub fn spawn_file_handle_task(
     AsyncComputeTaskPool::get().spawn_local(task).detach();
 }
 
-pub fn save_to_file(file_handle: FileHandle, content: String) {
+pub fn save_to_file(
+    file_handle: FileHandle,
+    content: String,
+    result_sender: Option<oneshot::Sender<Result<(), String>>>,
+) {
     AsyncComputeTaskPool::get()
         .spawn_local(async move {
-            if let Err(error) = saveToFile(file_handle.clone(), content).await {
-                let msg = format!("{:?}", error);
+            let result = saveToFile(file_handle.clone(), content)
+                .await
+                .map_err(|error| format!("{:?}", error));
 
-                error!(msg);
+            if let Err(msg) = &result {
+                error!("{}", msg);
 
                 rfd::AsyncMessageDialog::new()
                     .set_title("Failed to save file")
-                    .set_description(&msg)
+                    .set_description(msg)
                     .set_buttons(rfd::MessageButtons::Ok)
                     .set_level(rfd::MessageLevel::Error)
                     .show()
@@ -68,6 +87,44 @@ pub fn save_to_file(file_handle: FileHandle, content: String) {
             } else {
                 info!("Successfully wrote to file: {:?}", file_handle);
             }
+
+            if let Some(result_sender) = result_sender {
+                if result_sender.send(result).is_err() {
+                    error!("Couldn't send save result because receiver was already closed!");
+                }
+            }
+        })
+        .detach();
+}
+
+pub fn load_from_file(
+    file_handle: FileHandle,
+    action: super::FileHandleAction,
+    sender: oneshot::Sender<super::FileContentChannelType>,
+) {
+    AsyncComputeTaskPool::get()
+        .spawn_local(async move {
+            match loadFromFile(file_handle).await {
+                Ok(content) => {
+                    let content = js_sys::Uint8Array::new(&content).to_vec();
+                    if sender.send((content, action)).is_err() {
+                        error!("Couldn't send file content because receiver was already closed!");
+                    }
+                }
+                Err(error) => {
+                    let msg = format!("{:?}", error);
+
+                    error!(msg);
+
+                    rfd::AsyncMessageDialog::new()
+                        .set_title("Failed to load file")
+                        .set_description(&msg)
+                        .set_buttons(rfd::MessageButtons::Ok)
+                        .set_level(rfd::MessageLevel::Error)
+                        .show()
+                        .await;
+                }
+            }
         })
         .detach();
 }
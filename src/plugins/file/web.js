@@ -12,6 +12,14 @@ const SVG = {
     }
 }
 
+const IMAGE = {
+    description: "Image",
+    accept: {
+        "image/png": [".png"],
+        "image/jpeg": [".jpg", ".jpeg"]
+    }
+}
+
 export const openRONFile = async () => {
     const options = {
         multiple: false,
@@ -43,6 +51,33 @@ export const saveSVGFile = async () => {
     return await window.showSaveFilePicker(options);
 };
 
+export const openSVGFile = async () => {
+    const options = {
+        multiple: false,
+        excludeAcceptAllOption: true,
+        types: [SVG]
+    };
+
+    const [fileHandle] = await window.showOpenFilePicker(options);
+    return fileHandle;
+};
+
+export const openImageFile = async () => {
+    const options = {
+        multiple: false,
+        excludeAcceptAllOption: true,
+        types: [IMAGE]
+    };
+
+    const [fileHandle] = await window.showOpenFilePicker(options);
+    return fileHandle;
+};
+
+export const loadFromFile = async (fileHandle) => {
+    const file = await fileHandle.getFile();
+    return new Uint8Array(await file.arrayBuffer());
+};
+
 export const saveToFile = async (fileHandle, data) => {
     const writableStream = await fileHandle.createWritable();
 
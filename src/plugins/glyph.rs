@@ -0,0 +1,201 @@
+use crate::plugins::color_theme::ColorTheme;
+use crate::plugins::svg::StyleOverride;
+use crate::plugins::text_converter::alphabet::AlphabetSettings;
+use crate::plugins::text_converter::components::{Sentence, SentenceBundle, Text, SVG_SIZE};
+use crate::plugins::text_converter::{sanitize_text_input, systems, TextConverterBaseSet};
+use crate::utils::update_if_changed::update_if_changed;
+use bevy::prelude::*;
+
+pub struct GlyphPlugin;
+
+impl Plugin for GlyphPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ColorTheme>()
+            .add_system(
+                sync_glyph_text
+                    .in_base_set(TextConverterBaseSet::TextConverter)
+                    .before(systems::sentence::convert_sentence),
+            )
+            .add_system(update_glyph_style);
+    }
+}
+
+/// Gallifreyan text which gets drawn as a child sentence of the entity.
+#[derive(Debug, Clone, PartialEq, Component)]
+pub struct Glyph {
+    pub text: String,
+    /// Width of the glyph which matches the size of the exported svg.
+    pub size: f32,
+    pub color: Color,
+}
+
+impl Default for Glyph {
+    fn default() -> Self {
+        Self {
+            text: String::new(),
+            size: SVG_SIZE,
+            color: Color::BLACK,
+        }
+    }
+}
+
+#[derive(Bundle, Default)]
+pub struct GlyphBundle {
+    pub glyph: Glyph,
+    pub spatial_bundle: SpatialBundle,
+}
+
+impl GlyphBundle {
+    pub fn new(text: impl Into<String>, size: f32, color: Color) -> Self {
+        Self {
+            glyph: Glyph {
+                text: text.into(),
+                size,
+                color,
+            },
+            spatial_bundle: SpatialBundle::default(),
+        }
+    }
+}
+
+fn sync_glyph_text(
+    mut commands: Commands,
+    glyph_query: Query<(Entity, &Glyph, Option<&Children>), Changed<Glyph>>,
+    mut sentence_query: Query<&mut Text, With<Sentence>>,
+    alphabet_settings: Res<AlphabetSettings>,
+) {
+    for (glyph_entity, glyph, children) in glyph_query.iter() {
+        let new_text = sanitize_text_input(&glyph.text, alphabet_settings.current());
+        let mut sentences =
+            sentence_query.iter_many_mut(children.into_iter().flat_map(|it| it.iter()));
+
+        if let Some(mut sentence_text) = sentences.fetch_next() {
+            update_if_changed!(**sentence_text, new_text, "Update glyph: {} -> {}");
+        } else {
+            debug!("Spawn glyph: {}", new_text);
+            commands
+                .entity(glyph_entity)
+                .with_children(|child_builder| {
+                    child_builder.spawn(SentenceBundle::new(new_text));
+                });
+        }
+    }
+}
+
+/// Scales the sentence to the glyph size and colors all of its elements including new ones.
+fn update_glyph_style(
+    glyph_query: Query<(&Glyph, &Children)>,
+    mut sentence_query: Query<(Entity, &mut Transform), With<Sentence>>,
+    children_query: Query<&Children>,
+    mut style_override_query: Query<&mut StyleOverride>,
+) {
+    for (glyph, children) in glyph_query.iter() {
+        let mut sentences = sentence_query.iter_many_mut(children.iter());
+
+        while let Some((sentence_entity, mut transform)) = sentences.fetch_next() {
+            let scale = Vec3::splat(glyph.size / SVG_SIZE);
+
+            if transform.scale != scale {
+                transform.scale = scale;
+            }
+
+            let entities = std::iter::once(sentence_entity)
+                .chain(children_query.iter_descendants(sentence_entity));
+            let mut style_overrides = style_override_query.iter_many_mut(entities);
+
+            while let Some(mut style_override) = style_overrides.fetch_next() {
+                if style_override.color != Some(glyph.color) {
+                    style_override.color = Some(glyph.color);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::plugins::svg::SVGPlugin;
+    use crate::plugins::text_converter::components::Word;
+    use crate::plugins::text_converter::TextConverterPlugin;
+
+    fn create_app() -> App {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin)
+            .add_plugin(SVGPlugin)
+            .add_plugin(GlyphPlugin);
+
+        app
+    }
+
+    fn sentence_text(app: &mut App) -> String {
+        app.world
+            .query_filtered::<&Text, With<Sentence>>()
+            .single(&app.world)
+            .to_string()
+    }
+
+    #[test]
+    fn should_draw_glyph_as_child_sentence() {
+        let mut app = create_app();
+
+        let glyph_entity = app
+            .world
+            .spawn(GlyphBundle::new("Ab, c!", 100.0, Color::RED))
+            .id();
+
+        app.update();
+        app.update();
+
+        let (parent, transform) = app
+            .world
+            .query_filtered::<(&Parent, &Transform), With<Sentence>>()
+            .single(&app.world);
+
+        assert_eq!(parent.get(), glyph_entity);
+        assert_eq!(transform.scale, Vec3::splat(0.1));
+        assert_eq!(sentence_text(&mut app), "Ab c");
+
+        let colors: Vec<Option<Color>> = app
+            .world
+            .query::<&StyleOverride>()
+            .iter(&app.world)
+            .map(|style_override| style_override.color)
+            .collect();
+
+        assert!(!colors.is_empty());
+        assert!(colors.iter().all(|color| *color == Some(Color::RED)));
+    }
+
+    #[test]
+    fn should_update_glyph_text() {
+        let mut app = create_app();
+
+        let glyph_entity = app
+            .world
+            .spawn(GlyphBundle::new("ab", 100.0, Color::RED))
+            .id();
+
+        app.update();
+
+        app.world.get_mut::<Glyph>(glyph_entity).unwrap().text = "ab cd".to_string();
+
+        app.update();
+
+        assert_eq!(sentence_text(&mut app), "ab cd");
+        assert_eq!(
+            app.world
+                .query_filtered::<(), With<Word>>()
+                .iter(&app.world)
+                .count(),
+            2
+        );
+        assert_eq!(
+            app.world
+                .query_filtered::<(), With<Sentence>>()
+                .iter(&app.world)
+                .count(),
+            1
+        );
+    }
+}
@@ -26,6 +26,7 @@ impl Interaction {
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn add_interaction(
     mut commands: Commands,
     query: Query<
@@ -47,6 +48,7 @@ fn add_interaction(
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn update_circle_hitbox(
     mut with_radius_query: Query<
         (&mut Interaction, &Radius, &GlobalTransform),
@@ -66,6 +68,7 @@ fn update_circle_hitbox(
     }
 }
 
+#[allow(clippy::type_complexity)]
 fn update_line_slot_hitbox(
     mut line_slot_query: Query<
         (&mut Interaction, &GlobalTransform),
@@ -0,0 +1,148 @@
+use bevy::prelude::*;
+use bevy::render::texture::{CompressedImageFormats, ImageType};
+
+/// Drawn below the lyon shapes which are placed at z = 0.1.
+const REFERENCE_IMAGE_Z: f32 = 0.0;
+
+pub struct ReferenceImagePlugin;
+
+impl Plugin for ReferenceImagePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ReferenceImage>()
+            .add_event::<LoadReferenceImage>()
+            .add_system(handle_load_reference_image_event)
+            .add_system(add_reference_image_sprite.after(handle_load_reference_image_event))
+            .add_system(update_reference_image.after(add_reference_image_sprite));
+    }
+}
+
+/// An image in the background to trace existing art. It's not part of the svg export.
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct ReferenceImage {
+    pub opacity: f32,
+    pub scale: f32,
+    pub offset: Vec2,
+    /// in degrees
+    pub rotation: f32,
+    /// encoded png or jpeg so that it can be saved with the project
+    pub data: Vec<u8>,
+}
+
+impl Default for ReferenceImage {
+    fn default() -> Self {
+        Self {
+            opacity: 0.5,
+            scale: 1.0,
+            offset: Vec2::ZERO,
+            rotation: 0.0,
+            data: Vec::new(),
+        }
+    }
+}
+
+pub struct LoadReferenceImage(pub Vec<u8>);
+
+fn image_extension(data: &[u8]) -> Option<&'static str> {
+    const PNG_SIGNATURE: &[u8] = &[0x89, b'P', b'N', b'G'];
+    const JPEG_SIGNATURE: &[u8] = &[0xFF, 0xD8, 0xFF];
+
+    if data.starts_with(PNG_SIGNATURE) {
+        Some("png")
+    } else if data.starts_with(JPEG_SIGNATURE) {
+        Some("jpeg")
+    } else {
+        None
+    }
+}
+
+fn decode_image(data: &[u8]) -> Result<Image, String> {
+    let extension = image_extension(data)
+        .ok_or_else(|| "Only png and jpeg images are supported!".to_string())?;
+
+    Image::from_buffer(
+        data,
+        ImageType::Extension(extension),
+        CompressedImageFormats::NONE,
+        true,
+    )
+    .map_err(|err| err.to_string())
+}
+
+fn handle_load_reference_image_event(
+    mut commands: Commands,
+    mut events: EventReader<LoadReferenceImage>,
+    reference_image_query: Query<Entity, With<ReferenceImage>>,
+) {
+    if let Some(LoadReferenceImage(data)) = events.iter().last() {
+        for entity in reference_image_query.iter() {
+            commands.entity(entity).despawn();
+        }
+
+        debug!("Spawn reference image");
+        commands.spawn(ReferenceImage {
+            data: data.clone(),
+            ..default()
+        });
+    }
+}
+
+/// Also restores the sprite of a reference image which was loaded from a project file.
+fn add_reference_image_sprite(
+    mut commands: Commands,
+    query: Query<(Entity, &ReferenceImage), Added<ReferenceImage>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    for (entity, reference_image) in query.iter() {
+        match decode_image(&reference_image.data) {
+            Ok(image) => {
+                commands.entity(entity).insert(SpriteBundle {
+                    texture: images.add(image),
+                    ..default()
+                });
+            }
+            Err(err) => {
+                error!("Failed to load reference image: {}", err);
+                commands.entity(entity).despawn();
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_reference_image(
+    mut query: Query<
+        (&ReferenceImage, &mut Transform, &mut Sprite),
+        Or<(Changed<ReferenceImage>, Added<Sprite>)>,
+    >,
+) {
+    for (reference_image, mut transform, mut sprite) in query.iter_mut() {
+        *transform = Transform {
+            translation: reference_image.offset.extend(REFERENCE_IMAGE_Z),
+            rotation: Quat::from_rotation_z(-reference_image.rotation.to_radians()),
+            scale: Vec3::splat(reference_image.scale),
+        };
+
+        sprite.color = Color::WHITE.with_a(reference_image.opacity);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_detect_image_extension() {
+        assert_eq!(
+            image_extension(&[0x89, b'P', b'N', b'G', 0x0D]),
+            Some("png")
+        );
+        assert_eq!(image_extension(&[0xFF, 0xD8, 0xFF, 0xE0]), Some("jpeg"));
+        assert_eq!(image_extension(b"<svg"), None);
+    }
+
+    #[test]
+    fn should_reject_unsupported_image() {
+        assert!(decode_image(b"GIF89a").is_err());
+    }
+}
@@ -1,8 +1,14 @@
 use crate::math::angle::{Angle, Radian};
-use crate::plugins::color_theme::{ColorDependency, ColorTheme, DRAW_COLOR, SELECT_COLOR};
+use crate::plugins::color_theme::{
+    ColorDependency, ColorTheme, DRAW_COLOR, HIGHLIGHT_COLOR, SELECT_COLOR,
+};
 use crate::plugins::interaction::Interaction;
+use crate::plugins::svg::StyleOverride;
 use crate::plugins::svg_view::{ViewMode, WorldCursor};
-use crate::plugins::text_converter::components::PositionData;
+use crate::plugins::text_converter::components::{
+    CustomDecoration, ElementId, Letter, PositionData,
+};
+use crate::plugins::text_converter::decoration::{AddDecoration, RemoveDecoration};
 use crate::utils::contains::Contains;
 use bevy::app::{App, Plugin};
 use bevy::ecs::query::QuerySingleError;
@@ -16,9 +22,19 @@ pub struct SelectionPlugin;
 impl Plugin for SelectionPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<Select>()
+            .add_event::<SelectElement>()
+            .add_event::<SelectionChanged>()
+            .add_event::<Highlight>()
+            .add_system(handle_select_element_events.before(handle_select_events))
             .add_system(handle_select_events)
+            .add_system(handle_highlight_events)
             .add_systems(
-                (remove_selection_color, set_selection_color)
+                (
+                    remove_selection_color,
+                    remove_highlight_color,
+                    set_selection_color,
+                    set_highlight_color,
+                )
                     .chain()
                     .in_base_set(CoreSet::PostUpdate),
             )
@@ -26,40 +42,86 @@ impl Plugin for SelectionPlugin {
                 (drag, select_on_click)
                     .chain()
                     .in_set(OnUpdate(ViewMode::Select)),
-            );
+            )
+            .add_system(edit_custom_decorations.in_set(OnUpdate(ViewMode::Select)));
     }
 }
 
 pub struct Select(pub Option<Entity>);
 
+/// Same as [`Select`] for external code which only knows the id of the element.
+pub struct SelectElement(pub Option<ElementId>);
+
+/// Sent in [`CoreSet::Update`] when a [`Select`] event changed the selection.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct SelectionChanged {
+    pub old: Option<Entity>,
+    pub new: Option<Entity>,
+}
+
 #[derive(Copy, Clone, Component)]
 #[component(storage = "SparseSet")]
 pub struct Selected;
 
+/// Temporarily draws an entity in the highlight color without selecting it, e.g. while hovering
+/// its letter in the ui.
+pub struct Highlight(pub Option<Entity>);
+
+#[derive(Copy, Clone, Component)]
+#[component(storage = "SparseSet")]
+pub struct Highlighted;
+
+fn handle_select_element_events(
+    mut events: EventReader<SelectElement>,
+    mut select_events: EventWriter<Select>,
+    element_id_query: Query<(Entity, &ElementId)>,
+) {
+    if let Some(&SelectElement(element_id)) = events.iter().last() {
+        match element_id {
+            Some(element_id) => match element_id_query.iter().find(|(_, id)| **id == element_id) {
+                Some((entity, _)) => select_events.send(Select(Some(entity))),
+                None => warn!("Couldn't find element to select: {}", element_id),
+            },
+            None => select_events.send(Select(None)),
+        }
+    }
+}
+
 fn handle_select_events(
     mut commands: Commands,
     mut events: EventReader<Select>,
+    mut selection_changed_events: EventWriter<SelectionChanged>,
     selected_query: Query<Entity, With<Selected>>,
 ) {
     if let Some(&Select(new_selection)) = events.iter().last() {
         match (selected_query.get_single(), new_selection) {
-            (Ok(old_selection), Some(new_selection)) => {
-                if old_selection != new_selection {
-                    debug!(
-                        "Update selection: {:?} -> {:?}",
-                        old_selection, new_selection
-                    );
-                    commands.entity(old_selection).remove::<Selected>();
-                    commands.entity(new_selection).insert(Selected);
-                }
+            (Ok(old_selection), Some(new_selection)) if old_selection != new_selection => {
+                debug!(
+                    "Update selection: {:?} -> {:?}",
+                    old_selection, new_selection
+                );
+                commands.entity(old_selection).remove::<Selected>();
+                commands.entity(new_selection).insert(Selected);
+                selection_changed_events.send(SelectionChanged {
+                    old: Some(old_selection),
+                    new: Some(new_selection),
+                });
             }
             (Ok(old_selection), None) => {
                 debug!("Deselect: {:?}", old_selection);
                 commands.entity(old_selection).remove::<Selected>();
+                selection_changed_events.send(SelectionChanged {
+                    old: Some(old_selection),
+                    new: None,
+                });
             }
             (Err(QuerySingleError::NoEntities(_)), Some(new_selection)) => {
                 debug!("Select: {:?}", new_selection);
                 commands.entity(new_selection).insert(Selected);
+                selection_changed_events.send(SelectionChanged {
+                    old: None,
+                    new: Some(new_selection),
+                });
             }
             (Err(QuerySingleError::MultipleEntities(_)), _) => {
                 error!("More than one selected entity!");
@@ -69,13 +131,37 @@ fn handle_select_events(
     }
 }
 
+fn handle_highlight_events(
+    mut commands: Commands,
+    mut events: EventReader<Highlight>,
+    highlighted_query: Query<Entity, With<Highlighted>>,
+    entity_query: Query<Entity>,
+) {
+    if let Some(&Highlight(new_highlight)) = events.iter().last() {
+        if highlighted_query.get_single().ok() == new_highlight {
+            return;
+        }
+
+        for old_highlight in highlighted_query.iter() {
+            commands.entity(old_highlight).remove::<Highlighted>();
+        }
+
+        if let Some(new_highlight) = new_highlight.filter(|it| entity_query.contains(*it)) {
+            debug!("Highlight: {:?}", new_highlight);
+            commands.entity(new_highlight).insert(Highlighted);
+        }
+    }
+}
+
 #[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
 struct DrawModeParams<'w, 's> {
     draw_mode_query: Query<
         'w,
         's,
         (
             &'static mut ColorDependency,
+            Option<&'static StyleOverride>,
             Option<&'static mut Stroke>,
             Option<&'static mut Fill>,
         ),
@@ -91,9 +177,14 @@ impl<'w, 's> DrawModeParams<'w, 's> {
             let entities = std::iter::once(entity).chain(children);
             let mut iter = self.draw_mode_query.iter_many_mut(entities);
 
-            while let Some((mut color_dependency, mut stroke, mut fill)) = iter.fetch_next() {
+            while let Some((mut color_dependency, style_override, mut stroke, mut fill)) =
+                iter.fetch_next()
+            {
                 *color_dependency = ColorDependency(dependency);
 
+                let new_color =
+                    style_override.map_or(new_color, |it| it.apply_color(dependency, new_color));
+
                 if let Some(stroke) = stroke.as_mut() {
                     stroke.color = new_color;
                 }
@@ -126,6 +217,33 @@ fn remove_selection_color(
     }
 }
 
+fn set_highlight_color(
+    new_highlight_query: Query<Entity, Added<Highlighted>>,
+    mut draw_mode_params: DrawModeParams,
+) {
+    for new_highlight in new_highlight_query.iter() {
+        draw_mode_params.set_color_for_entity_and_children(new_highlight, HIGHLIGHT_COLOR);
+    }
+}
+
+/// Restores the selection color in case the highlighted entity is part of the selection.
+fn remove_highlight_color(
+    mut removed_highlights: RemovedComponents<Highlighted>,
+    selected_query: Query<Entity, With<Selected>>,
+    mut draw_mode_params: DrawModeParams,
+) {
+    let mut any_removed = false;
+
+    for removed_highlight in &mut removed_highlights {
+        draw_mode_params.set_color_for_entity_and_children(removed_highlight, DRAW_COLOR);
+        any_removed = true;
+    }
+
+    if let (true, Ok(selection)) = (any_removed, selected_query.get_single()) {
+        draw_mode_params.set_color_for_entity_and_children(selection, SELECT_COLOR);
+    }
+}
+
 fn select_on_click(
     mut events: EventWriter<Select>,
     world_cursor: Res<WorldCursor>,
@@ -217,6 +335,68 @@ fn drag(
     }
 }
 
+/// With a letter selected the keys D, L and C add a dot, line or circle at the cursor, Delete
+/// removes the selected custom decoration.
+#[allow(clippy::type_complexity)]
+fn edit_custom_decorations(
+    world_cursor: Res<WorldCursor>,
+    egui_contexts: EguiContexts,
+    keyboard_input: Res<Input<KeyCode>>,
+    selected_query: Query<
+        (
+            Entity,
+            &GlobalTransform,
+            Option<&Letter>,
+            Option<&CustomDecoration>,
+        ),
+        With<Selected>,
+    >,
+    mut add_decoration_events: EventWriter<AddDecoration>,
+    mut remove_decoration_events: EventWriter<RemoveDecoration>,
+) {
+    let Ok((selected_entity, global_transform, letter, custom_decoration)) =
+        selected_query.get_single()
+    else {
+        return;
+    };
+
+    if egui_contexts.ctx().wants_keyboard_input() {
+        return;
+    }
+
+    if custom_decoration.is_some() && keyboard_input.just_pressed(KeyCode::Delete) {
+        remove_decoration_events.send(RemoveDecoration(selected_entity));
+        return;
+    }
+
+    if letter.is_none() {
+        return;
+    }
+
+    let decoration = [
+        (KeyCode::D, CustomDecoration::Dot),
+        (KeyCode::L, CustomDecoration::Line),
+        (KeyCode::C, CustomDecoration::Circle),
+    ]
+    .into_iter()
+    .find(|(key_code, _)| keyboard_input.just_pressed(*key_code))
+    .map(|(_, decoration)| decoration);
+
+    if let Some(decoration) = decoration {
+        let position = global_transform
+            .affine()
+            .inverse()
+            .transform_point3(world_cursor.pos.extend(0.0))
+            .truncate();
+
+        add_decoration_events.send(AddDecoration {
+            letter: selected_entity,
+            decoration,
+            position: Some(position),
+        });
+    }
+}
+
 fn get_clicked_entity(
     hit_box_query: &Query<(Entity, &Interaction)>,
     world_cursor_pos: Vec2,
@@ -0,0 +1,281 @@
+use super::export::FILL_CLASS;
+use super::{
+    Animation, CSSRule, Class, InlineStyle, Keyframes, SVGElement, Selector, Style, StyleRule, SVG,
+};
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+const DRAW_ANIMATION: &str = "draw";
+const APPEAR_ANIMATION: &str = "appear";
+
+#[derive(Debug, Copy, Clone, PartialEq, Resource)]
+pub struct DrawAnimation {
+    pub duration: f32,
+    pub stagger: f32,
+}
+
+impl Default for DrawAnimation {
+    fn default() -> Self {
+        Self {
+            duration: 1.0,
+            stagger: 0.25,
+        }
+    }
+}
+
+impl DrawAnimation {
+    /// Strokes are drawn by animating their dash offset from their full length down to 0
+    /// and fills fade in once it's their turn.
+    /// The order is the reading order of the svg tree meaning that each nesting level
+    /// (sentence, words, letters, decorations) is drawn completely before the next one starts.
+    /// Dashed strokes fade in like fills because the dash offset animation would replace their
+    /// dash pattern.
+    pub fn apply(&self, svg: &mut SVG) {
+        let element_paths = reading_order(&svg.elements);
+
+        for (index, (element_path, is_fill, is_dashed)) in element_paths.into_iter().enumerate() {
+            let element = get_element_mut(&mut svg.elements, &element_path);
+            let delay = index as f32 * self.stagger;
+
+            let mut rules = Vec::new();
+
+            if is_fill || is_dashed {
+                rules.push(CSSRule::Animation(Animation {
+                    name: APPEAR_ANIMATION.to_string(),
+                    duration: self.duration,
+                    delay,
+                }));
+            } else if let Some(length) = element_length(element) {
+                rules.push(CSSRule::StrokeDashArray(vec![length]));
+                rules.push(CSSRule::StrokeDashOffset(length));
+                rules.push(CSSRule::Animation(Animation {
+                    name: DRAW_ANIMATION.to_string(),
+                    duration: self.duration,
+                    delay,
+                }));
+            }
+
+            if let Some(style) = element.style_mut() {
+                style.0.extend(rules);
+            }
+        }
+
+        let style = svg.elements.iter_mut().find_map(|element| match element {
+            SVGElement::Style(style) => Some(style),
+            _ => None,
+        });
+
+        match style {
+            Some(style) => {
+                add_keyframes(style);
+            }
+            None => {
+                let mut style = Style::new();
+                add_keyframes(&mut style);
+                svg.elements.insert(0, style.into());
+            }
+        }
+    }
+}
+
+fn add_keyframes(style: &mut Style) {
+    let mut draw_frame = StyleRule::new();
+    draw_frame
+        .selectors
+        .push(Selector::Keyframe("to".to_string()));
+    draw_frame.rules.push(CSSRule::StrokeDashOffset(0.0));
+
+    let mut draw = Keyframes::new(DRAW_ANIMATION);
+    draw.push(draw_frame);
+
+    let mut appear_frame = StyleRule::new();
+    appear_frame
+        .selectors
+        .push(Selector::Keyframe("from".to_string()));
+    appear_frame.rules.push(CSSRule::Opacity(0.0));
+
+    let mut appear = Keyframes::new(APPEAR_ANIMATION);
+    appear.push(appear_frame);
+
+    style.push_keyframes(draw);
+    style.push_keyframes(appear);
+}
+
+fn element_length(element: &SVGElement) -> Option<f32> {
+    match element {
+        SVGElement::Circle(circle) => Some(circle.length()),
+        SVGElement::Line(line) => Some(line.length()),
+        SVGElement::Path(path) => Some(path.length()),
+        SVGElement::Title(_) | SVGElement::Group(_) | SVGElement::Style(_) => None,
+    }
+}
+
+fn is_fill(class: &Class, is_parent_fill: bool) -> bool {
+    if class.0.is_empty() {
+        is_parent_fill
+    } else {
+        class.0 == FILL_CLASS
+    }
+}
+
+/// The dash array is inherited like the class.
+fn is_dashed(style: &InlineStyle, is_parent_dashed: bool) -> bool {
+    is_parent_dashed
+        || style
+            .0
+            .iter()
+            .any(|rule| matches!(rule, CSSRule::StrokeDashArray(_)))
+}
+
+/// Breadth first so that parents are always drawn before their children.
+fn reading_order(elements: &[SVGElement]) -> Vec<(Vec<usize>, bool, bool)> {
+    let mut order = Vec::new();
+
+    let mut queue: VecDeque<(Vec<usize>, &SVGElement, bool, bool)> = elements
+        .iter()
+        .enumerate()
+        .map(|(index, element)| (vec![index], element, false, false))
+        .collect();
+
+    while let Some((element_path, element, is_parent_fill, is_parent_dashed)) = queue.pop_front() {
+        match element {
+            SVGElement::Group(group) => {
+                let is_group_fill = is_fill(&group.class, is_parent_fill);
+                let is_group_dashed = is_dashed(&group.style, is_parent_dashed);
+
+                queue.extend(group.elements.iter().enumerate().map(|(index, child)| {
+                    let mut child_path = element_path.clone();
+                    child_path.push(index);
+                    (child_path, child, is_group_fill, is_group_dashed)
+                }));
+            }
+            SVGElement::Circle(circle) => {
+                order.push((
+                    element_path,
+                    is_fill(&circle.class, is_parent_fill),
+                    is_dashed(&circle.style, is_parent_dashed),
+                ));
+            }
+            SVGElement::Line(line) => {
+                order.push((
+                    element_path,
+                    is_fill(&line.class, is_parent_fill),
+                    is_dashed(&line.style, is_parent_dashed),
+                ));
+            }
+            SVGElement::Path(path) => {
+                order.push((
+                    element_path,
+                    is_fill(&path.class, is_parent_fill),
+                    is_dashed(&path.style, is_parent_dashed),
+                ));
+            }
+            SVGElement::Title(_) | SVGElement::Style(_) => {}
+        }
+    }
+
+    order
+}
+
+fn get_element_mut<'a>(
+    elements: &'a mut [SVGElement],
+    element_path: &[usize],
+) -> &'a mut SVGElement {
+    let element = &mut elements[element_path[0]];
+
+    if element_path.len() == 1 {
+        return element;
+    }
+
+    match element {
+        SVGElement::Group(group) => get_element_mut(&mut group.elements, &element_path[1..]),
+        _ => unreachable!("Only groups can have children!"),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::plugins::svg::{Circle, Group, Line, Title};
+
+    #[test]
+    fn should_animate_elements_in_reading_order() {
+        let mut fill_circle = Circle::new(1.0);
+        fill_circle.class = Class(FILL_CLASS.to_string());
+
+        let mut inner_group = Group::new();
+        inner_group.push(Line::new(Vec2::ZERO, Vec2::new(0.0, 2.0)));
+
+        let mut outer_group = Group::new();
+        outer_group.push(Line::new(Vec2::ZERO, Vec2::new(3.0, 4.0)));
+        outer_group.push(inner_group);
+        outer_group.push(fill_circle);
+
+        let mut svg = SVG::new(10.0);
+        svg.push(Title("TITLE".to_string()));
+        svg.push(outer_group);
+
+        DrawAnimation {
+            duration: 2.0,
+            stagger: 0.5,
+        }
+        .apply(&mut svg);
+
+        let result = svg.to_string();
+
+        let expected = r#"<?xml version="1.0" encoding="UTF-8"?>
+<svg
+  xmlns="http://www.w3.org/2000/svg"
+  xmlns:xlink="http://www.w3.org/1999/xlink"
+  viewBox="-5 -5 10 10"
+>
+    <style>
+        @keyframes draw {
+            to {
+                stroke-dashoffset: 0;
+            }
+        }
+        @keyframes appear {
+            from {
+                opacity: 0;
+            }
+        }
+    </style>
+    <title>TITLE</title>
+    <g transform="matrix(1 0 0 1 0 0)">
+        <line x1="0" y1="0" x2="3" y2="4" style="stroke-dasharray: 5; stroke-dashoffset: 5; animation: draw 2s linear 0s both;"/>
+        <g transform="matrix(1 0 0 1 0 0)">
+            <line x1="0" y1="0" x2="0" y2="2" style="stroke-dasharray: 2; stroke-dashoffset: 2; animation: draw 2s linear 1s both;"/>
+        </g>
+        <circle cx="0" cy="0" r="1" class="fill" style="animation: appear 2s linear 0.5s both;"/>
+    </g>
+</svg>"#;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_fade_in_dashed_strokes() {
+        let mut dashed_line = Line::new(Vec2::ZERO, Vec2::new(3.0, 4.0));
+        dashed_line.style = InlineStyle(vec![CSSRule::StrokeDashArray(vec![4.0, 2.0])]);
+
+        let mut dashed_group = Group::new();
+        dashed_group.style = InlineStyle(vec![CSSRule::StrokeDashArray(vec![1.0])]);
+        dashed_group.push(Circle::new(1.0));
+
+        let mut svg = SVG::new(10.0);
+        svg.push(dashed_line);
+        svg.push(dashed_group);
+
+        DrawAnimation::default().apply(&mut svg);
+
+        let result = svg.to_string();
+
+        assert!(result
+            .contains(r#"style="stroke-dasharray: 4 2; animation: appear 1s linear 0s both;""#));
+        assert!(result.contains(
+            r#"<circle cx="0" cy="0" r="1" style="animation: appear 1s linear 0.25s both;"/>"#
+        ));
+        assert!(!result.contains("stroke-dashoffset: 5"));
+    }
+}
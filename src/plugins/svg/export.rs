@@ -1,119 +1,261 @@
+use crate::plugins::svg::metadata::{create_data_attributes, ElementKindQuery};
 use crate::plugins::svg::{
-    CSSRule, Class, Group, SVGElement, Selector, StrokeLineCap, Style, StyleRule, Title, ToAffine2,
-    SVG,
+    Class, DrawStyle, Group, SVGElement, SVGShape, StyleOverride, ToAffine2, SVG,
 };
-use crate::plugins::text_converter::components::{LineSlot, Sentence, Text, SVG_SIZE};
-use bevy::ecs::query::QuerySingleError;
+use crate::plugins::text_converter::components::{
+    sort_sentences, CustomDecoration, ElementId, LineSlot, Paragraph, PositionData, Radius,
+    Sentence, SentenceIndex, Text, OUTER_CIRCLE_SIZE,
+};
+use crate::plugins::text_converter::{SentenceFrame, SentenceFrameSettings};
 use bevy::ecs::system::SystemParam;
-use bevy::math::Affine2;
 use bevy::prelude::*;
-use bevy_prototype_lyon::prelude::{Fill, Stroke};
+pub use gallifreyan_core::export::{FILL_CLASS, STROKE_CLASS};
+use gallifreyan_core::{export, model};
 use std::string::ToString;
 
-const FILL_CLASS: &str = "fill";
-const STROKE_CLASS: &str = "stroke";
-
 type ComponentQuery<'w, 's> = Query<
     'w,
     's,
     (
+        Entity,
         &'static Transform,
-        Option<&'static SVGElement>,
+        Option<&'static SVGShape>,
         Option<&'static Children>,
-        Option<&'static Stroke>,
-        Option<&'static Fill>,
+        Option<&'static DrawStyle>,
+        Option<&'static StyleOverride>,
+        Option<&'static LineSlot>,
+    ),
+>;
+
+type MetadataQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        ElementKindQuery,
+        Option<&'static ElementId>,
+        Option<&'static Text>,
+        Option<&'static Radius>,
+        Option<&'static PositionData>,
+        Option<&'static CustomDecoration>,
+    ),
+>;
+
+type SentenceQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Option<&'static SentenceIndex>,
+        &'static Text,
+        &'static Transform,
+        Option<&'static Radius>,
     ),
-    Without<LineSlot>,
+    (With<Sentence>, Without<Parent>),
 >;
 
 #[derive(SystemParam)]
 pub struct SVGExportSystemParams<'w, 's> {
-    sentence_query: Query<'w, 's, (Entity, &'static Text), With<Sentence>>,
+    sentence_query: SentenceQuery<'w, 's>,
+    paragraph_query: Query<'w, 's, (Entity, &'static SVGShape), With<Paragraph>>,
     component_query: ComponentQuery<'w, 's>,
+    metadata_query: MetadataQuery<'w, 's>,
+    sentence_frame: Res<'w, SentenceFrameSettings>,
 }
 
 impl<'w, 's> SVGExportSystemParams<'w, 's> {
-    pub fn create_svg(&self) -> Result<SVG, QuerySingleError> {
-        self.sentence_query
-            .get_single()
-            .map(|(sentence_entity, text)| {
-                let mut svg = SVG::new(SVG_SIZE);
+    pub fn create_svg(&self) -> Result<SVG, String> {
+        self.create(false)
+    }
 
-                svg.push(Title(text.to_string()));
+    /// Adds the element id and data attributes to every element so that the svg can be imported
+    /// again.
+    pub fn create_svg_with_metadata(&self) -> Result<SVG, String> {
+        self.create(true)
+    }
 
-                let style = {
-                    let mut style = Style::new();
+    /// All sentences in their order together with the paragraph circle in a fitted document.
+    fn create(&self, with_metadata: bool) -> Result<SVG, String> {
+        let mut sentences: Vec<_> = self
+            .sentence_query
+            .iter()
+            .map(|(entity, index, text, transform, radius)| {
+                (index, (entity, text, transform, radius))
+            })
+            .collect();
 
-                    let mut stroke_rule = StyleRule::new();
-                    stroke_rule
-                        .selectors
-                        .push(Selector::Class(STROKE_CLASS.to_string()));
-                    stroke_rule.rules.push(CSSRule::Stroke(Some(Color::BLACK)));
-                    stroke_rule.rules.push(CSSRule::Fill(None));
-                    stroke_rule.rules.push(CSSRule::StrokeWidth(1.0));
-                    stroke_rule
-                        .rules
-                        .push(CSSRule::StrokeLineCap(StrokeLineCap::Round));
+        if sentences.is_empty() {
+            return Err("There is no sentence to export!".to_string());
+        }
+
+        sort_sentences(&mut sentences);
 
-                    style.push(stroke_rule);
+        let mut min = Vec2::splat(f32::MAX);
+        let mut max = Vec2::splat(f32::MIN);
+        let mut extend_bounds = |center: Vec3, radius: f32| {
+            min = min.min(center.truncate() - radius);
+            max = max.max(center.truncate() + radius);
+        };
 
-                    let mut fill_rule = StyleRule::new();
-                    fill_rule
-                        .selectors
-                        .push(Selector::Class(FILL_CLASS.to_string()));
-                    fill_rule.rules.push(CSSRule::Fill(Some(Color::BLACK)));
-                    fill_rule.rules.push(CSSRule::Stroke(None));
+        for (_, (_, _, transform, radius)) in &sentences {
+            let radius = radius.map_or(model::Sentence::radius(), |it| **it);
+            extend_bounds(
+                transform.translation,
+                (radius + OUTER_CIRCLE_SIZE) * transform.scale.x,
+            );
+        }
 
-                    style.push(fill_rule);
+        let paragraph = self.paragraph_query.get_single().ok();
 
-                    style
-                };
+        if let Some((_, SVGShape(SVGElement::Circle(circle)))) = paragraph {
+            extend_bounds(Vec3::ZERO, circle.radius);
+        }
 
-                svg.push(style);
+        let title = sentences
+            .iter()
+            .map(|(_, (_, text, ..))| text.as_str())
+            .collect::<Vec<_>>()
+            .join(". ");
 
-                let mut group = Group::new();
+        let mut group = self.convert_components_to_svg(
+            paragraph.map(|(entity, _)| entity),
+            export::create_root_group(),
+            with_metadata,
+        );
 
-                // mirror along y-axis because svg uses a mirrored y-axis
-                group.affine2 = Affine2 {
-                    translation: Vec2::ZERO,
-                    matrix2: Mat2::from_cols(Vec2::X, Vec2::NEG_Y),
-                };
+        let is_sentence_group_dropped = **self.sentence_frame == SentenceFrame::None;
 
-                group = self.convert_components_to_svg([sentence_entity], group);
+        for (_, (sentence_entity, ..)) in &sentences {
+            group = if is_sentence_group_dropped {
+                if with_metadata {
+                    group.push(self.create_frameless_sentence_metadata(*sentence_entity));
+                }
 
-                svg.push(group);
+                self.convert_sentence_children_to_svg(*sentence_entity, group, with_metadata)
+            } else {
+                self.convert_components_to_svg([*sentence_entity], group, with_metadata)
+            };
+        }
 
-                svg
-            })
+        Ok(export::create_fitted_document(
+            &title,
+            group,
+            min,
+            max,
+            &export::ExportOptions::default(),
+        ))
+    }
+
+    /// The metadata of a sentence whose group is dropped is kept in an empty group in front of
+    /// its words so that the svg can still be imported.
+    fn create_frameless_sentence_metadata(&self, sentence_entity: Entity) -> Group {
+        let mut group = Group::new();
+
+        if let Ok((kind, element_id, text, radius, position_data, _)) =
+            self.metadata_query.get(sentence_entity)
+        {
+            group.id = element_id.map(ToString::to_string);
+            group.data = create_data_attributes(
+                kind,
+                text,
+                radius,
+                position_data,
+                None,
+                Some(SentenceFrame::None),
+            );
+        }
+
+        group
+    }
+
+    /// Moves the words of a sentence without a frame directly into the group.
+    fn convert_sentence_children_to_svg(
+        &self,
+        sentence_entity: Entity,
+        mut group: Group,
+        with_metadata: bool,
+    ) -> Group {
+        let Ok((_, transform, _, children, ..)) = self.component_query.get(sentence_entity) else {
+            return group;
+        };
+
+        let children = self.convert_components_to_svg(
+            children.into_iter().flatten().copied(),
+            Group::new(),
+            with_metadata,
+        );
+
+        for mut element in children.elements {
+            if let SVGElement::Group(child_group) = &mut element {
+                child_group.affine2 = transform.to_affine2() * child_group.affine2;
+            }
+
+            group.push(element);
+        }
+
+        group
     }
 
     fn convert_components_to_svg(
         &self,
         entities: impl IntoIterator<Item = Entity>,
         mut group: Group,
+        with_metadata: bool,
     ) -> Group {
-        for (transform, svg_element, children, stroke, fill) in
+        for (entity, transform, svg_element, children, draw_style, style_override, line_slot) in
             self.component_query.iter_many(entities)
         {
+            // line slots are only placeholders and are only exported for their metadata
+            if line_slot.is_some() && !with_metadata {
+                continue;
+            }
+
+            let svg_element = svg_element.filter(|_| line_slot.is_none());
+
             let mut local_group = Group::new();
             local_group.affine2 = transform.to_affine2();
 
+            if with_metadata {
+                if let Ok((kind, element_id, text, radius, position_data, custom_decoration)) =
+                    self.metadata_query.get(entity)
+                {
+                    local_group.id = element_id.map(ToString::to_string);
+                    local_group.data = create_data_attributes(
+                        kind,
+                        text,
+                        radius,
+                        position_data,
+                        custom_decoration,
+                        None,
+                    );
+                }
+            }
+
             if let Some(svg_element) = svg_element {
-                let mut svg_element = svg_element.clone();
+                let mut svg_element = svg_element.0.clone();
+
+                let is_fill = matches!(draw_style, Some(DrawStyle::Fill));
 
-                if stroke.is_some() {
-                    svg_element.set_class(Class(STROKE_CLASS.to_string()))
+                match draw_style {
+                    Some(DrawStyle::Stroke) => {
+                        svg_element.set_class(Class(STROKE_CLASS.to_string()))
+                    }
+                    Some(DrawStyle::Fill) => svg_element.set_class(Class(FILL_CLASS.to_string())),
+                    None => {}
                 }
 
-                if fill.is_some() {
-                    svg_element.set_class(Class(FILL_CLASS.to_string()))
+                if let Some(style_override) = style_override {
+                    svg_element.set_style(style_override.to_inline_style(is_fill));
                 }
 
                 local_group.push(svg_element);
             }
 
             if let Some(children) = children {
-                group.push(self.convert_components_to_svg(children.iter().copied(), local_group));
+                group.push(self.convert_components_to_svg(
+                    children.iter().copied(),
+                    local_group,
+                    with_metadata,
+                ));
             } else {
                 group.push(local_group);
             }
@@ -126,13 +268,14 @@ impl<'w, 's> SVGExportSystemParams<'w, 's> {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::plugins::svg::{Circle, Path, PathElement};
+    use crate::plugins::svg::{Circle, Path, PathElement, SVGElement};
     use crate::plugins::text_converter::components::*;
     use std::sync::mpsc::sync_channel;
 
     #[test]
     fn export() {
         let mut app = App::new();
+        app.init_resource::<SentenceFrameSettings>();
 
         let (sender, receiver) = sync_channel::<String>(1);
 
@@ -144,13 +287,16 @@ mod test {
         let sentence = (
             Sentence,
             Text("bphva".to_string()),
-            SVGElement::Group(Group {
+            SVGShape(SVGElement::Group(Group {
+                id: None,
                 elements: vec![Circle::new(460.0).into(), Circle::new(450.0).into()],
                 affine2: Default::default(),
                 class: Default::default(),
-            }),
+                style: Default::default(),
+                data: Default::default(),
+            })),
             Transform::IDENTITY,
-            Stroke::color(Color::BLACK),
+            DrawStyle::Stroke,
         );
 
         app.world.spawn(sentence).with_children(|child_builder| {
@@ -169,9 +315,9 @@ mod test {
             });
 
             let word = (
-                SVGElement::Path(path),
+                SVGShape(SVGElement::Path(path)),
                 Transform::IDENTITY,
-                Stroke::color(Color::BLACK),
+                DrawStyle::Stroke,
             );
 
             child_builder.spawn(word).with_children(|child_builder| {
@@ -184,28 +330,28 @@ mod test {
                 });
 
                 let b = (
-                    SVGElement::Path(b_path),
+                    SVGShape(SVGElement::Path(b_path)),
                     Transform::from_xyz(0.0, -174.375, 0.0),
-                    Stroke::color(Color::BLACK),
+                    DrawStyle::Stroke,
                 );
 
                 child_builder.spawn(b);
 
                 let ph = (
-                    SVGElement::Circle(Circle::new(67.5)),
+                    SVGShape(SVGElement::Circle(Circle::new(67.5))),
                     Transform {
                         translation: Vec3::new(107.17064, 61.875015, 0.0),
                         rotation: Quat::from_xyzw(0.0, 0.0, 0.86602545, 0.49999997),
                         scale: Vec3::ONE,
                     },
-                    Stroke::color(Color::BLACK),
+                    DrawStyle::Stroke,
                 );
 
                 child_builder.spawn(ph).with_children(|child_builder| {
                     let dot = (
-                        SVGElement::Circle(Circle::new(6.75)),
+                        SVGShape(SVGElement::Circle(Circle::new(6.75))),
                         Transform::from_xyz(-0.000005015882, 57.375, 0.0),
-                        Fill::color(Color::BLACK),
+                        DrawStyle::Fill,
                     );
 
                     child_builder.spawn(dot);
@@ -220,13 +366,13 @@ mod test {
                 });
 
                 let v = (
-                    SVGElement::Path(v_path),
+                    SVGShape(SVGElement::Path(v_path)),
                     Transform {
                         translation: Vec3::new(-194.85574, 112.499985, 0.0),
                         rotation: Quat::from_xyzw(0.0, 0.0, 0.8660254, -0.50000006),
                         scale: Vec3::ONE,
                     },
-                    Stroke::color(Color::BLACK),
+                    DrawStyle::Stroke,
                 );
 
                 child_builder.spawn(v).with_children(|child_builder| {
@@ -236,9 +382,9 @@ mod test {
                         .spawn(position_correction)
                         .with_children(|child_builder| {
                             let a = (
-                                SVGElement::Circle(Circle::new(27.0)),
+                                SVGShape(SVGElement::Circle(Circle::new(27.0))),
                                 Transform::from_xyz(0.0, -265.5, 0.0),
-                                Stroke::color(Color::BLACK),
+                                DrawStyle::Stroke,
                             );
 
                             child_builder.spawn(a);
@@ -0,0 +1,767 @@
+use super::{DataAttributes, SVGElement, SVG};
+use crate::math::angle::{Angle, Degree};
+use crate::plugins::text_converter::components::{
+    sort_sentences, AnglePlacement, CircleChildren, CustomDecoration, Dot, DotBundle, ElementId,
+    ElementIdGenerator, Letter, LineSlot, LineSlotBundle, LineSlotChildren, NestedVocal,
+    NestedVocalPositionCorrection, NestingSettings, PositionData, Radius, Sentence, SentenceIndex,
+    Text, Word,
+};
+use crate::plugins::text_converter::systems::sentence::arrange_sentences;
+use crate::plugins::text_converter::{
+    SentenceFrame, SentenceFrameSettings, SetText, TextConverterBaseSet,
+};
+use bevy::prelude::*;
+use bevy::utils::HashSet;
+use gallifreyan_core::alphabet::{Consonant, Nesting, Vocal};
+use gallifreyan_core::{alphabet, layout};
+use std::str::FromStr;
+
+const KIND: &str = "kind";
+const TEXT: &str = "text";
+const CONSONANT: &str = "consonant";
+const VOCAL: &str = "vocal";
+const RADIUS: &str = "radius";
+const ANGLE: &str = "angle";
+const DISTANCE: &str = "distance";
+const ANGLE_PLACEMENT: &str = "angle-placement";
+const FRAME: &str = "frame";
+const CUSTOM: &str = "custom";
+
+pub struct MetadataPlugin;
+
+impl Plugin for MetadataPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ImportSVG>()
+            .add_event::<ImportSVGFailed>()
+            .init_resource::<PendingMetadata>()
+            .add_system(handle_import_svg_event)
+            .add_system(
+                apply_pending_metadata
+                    .in_base_set(TextConverterBaseSet::PostTextConverter)
+                    .after(arrange_sentences),
+            );
+    }
+}
+
+/// Recreates the sentences of an svg which was exported with metadata.
+pub struct ImportSVG(pub SVG);
+
+/// Sent when an svg couldn't be imported or its layout couldn't be restored, e.g. because it was
+/// exported with another alphabet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportSVGFailed(pub String);
+
+/// The imported sentences in their order.
+#[derive(Default, Resource)]
+struct PendingMetadata(Option<Vec<MetadataNode>>);
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum ElementKind {
+    Sentence,
+    Word,
+    Letter,
+    NestedVocal,
+    PositionCorrection,
+    Dot,
+    LineSlot,
+}
+
+impl ElementKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ElementKind::Sentence => "sentence",
+            ElementKind::Word => "word",
+            ElementKind::Letter => "letter",
+            ElementKind::NestedVocal => "nested-vocal",
+            ElementKind::PositionCorrection => "position-correction",
+            ElementKind::Dot => "dot",
+            ElementKind::LineSlot => "line-slot",
+        }
+    }
+}
+
+impl TryFrom<&str> for ElementKind {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let kind = match value {
+            "sentence" => ElementKind::Sentence,
+            "word" => ElementKind::Word,
+            "letter" => ElementKind::Letter,
+            "nested-vocal" => ElementKind::NestedVocal,
+            "position-correction" => ElementKind::PositionCorrection,
+            "dot" => ElementKind::Dot,
+            "line-slot" => ElementKind::LineSlot,
+            _ => return Err(format!("'{}' is not a valid element kind!", value)),
+        };
+
+        Ok(kind)
+    }
+}
+
+pub type ElementKindQuery = AnyOf<(
+    &'static Sentence,
+    &'static Word,
+    &'static Letter,
+    &'static NestedVocal,
+    &'static NestedVocalPositionCorrection,
+    &'static Dot,
+    &'static LineSlot,
+)>;
+
+pub type ElementKindItem<'a> = (
+    Option<&'a Sentence>,
+    Option<&'a Word>,
+    Option<&'a Letter>,
+    Option<&'a NestedVocal>,
+    Option<&'a NestedVocalPositionCorrection>,
+    Option<&'a Dot>,
+    Option<&'a LineSlot>,
+);
+
+impl From<ElementKindItem<'_>> for ElementKind {
+    fn from(value: ElementKindItem) -> Self {
+        match value {
+            (Some(_), ..) => ElementKind::Sentence,
+            (_, Some(_), ..) => ElementKind::Word,
+            // nested vocals are letters as well
+            (_, _, _, Some(_), ..) => ElementKind::NestedVocal,
+            (_, _, Some(_), ..) => ElementKind::Letter,
+            (_, _, _, _, Some(_), ..) => ElementKind::PositionCorrection,
+            (.., Some(_), None) => ElementKind::Dot,
+            _ => ElementKind::LineSlot,
+        }
+    }
+}
+
+/// Only sentences whose group was dropped from the svg get a frame.
+pub fn create_data_attributes(
+    kind: ElementKindItem,
+    text: Option<&Text>,
+    radius: Option<&Radius>,
+    position_data: Option<&PositionData>,
+    custom_decoration: Option<&CustomDecoration>,
+    frame: Option<SentenceFrame>,
+) -> DataAttributes {
+    let mut data = DataAttributes::default();
+
+    data.push(KIND, ElementKind::from(kind).as_str());
+
+    if let Some(text) = text {
+        data.push(TEXT, &**text);
+    }
+
+    match kind.2.map(|letter| **letter) {
+        Some(alphabet::Letter::Consonant(consonant)) => {
+            data.push(CONSONANT, format!("{:?}", consonant));
+        }
+        Some(alphabet::Letter::Vocal(vocal)) => {
+            data.push(VOCAL, format!("{:?}", vocal));
+        }
+        Some(alphabet::Letter::ConsonantWithVocal { consonant, vocal }) => {
+            data.push(CONSONANT, format!("{:?}", consonant));
+            data.push(VOCAL, format!("{:?}", vocal));
+        }
+        None => {}
+    }
+
+    if let Some(radius) = radius {
+        data.push(RADIUS, **radius);
+    }
+
+    if let Some(position_data) = position_data {
+        data.push(ANGLE, position_data.angle.inner());
+        data.push(DISTANCE, position_data.distance);
+        data.push(
+            ANGLE_PLACEMENT,
+            match position_data.angle_placement {
+                AnglePlacement::Absolute => "absolute",
+                AnglePlacement::Relative => "relative",
+            },
+        );
+    }
+
+    if let Some(custom_decoration) = custom_decoration {
+        data.push(CUSTOM, custom_decoration.as_str());
+    }
+
+    if let Some(frame) = frame {
+        data.push(FRAME, frame.as_str());
+    }
+
+    data
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct MetadataNode {
+    id: Option<ElementId>,
+    kind: ElementKind,
+    text: Option<String>,
+    radius: Option<Radius>,
+    position_data: Option<PositionData>,
+    letter: Option<alphabet::Letter>,
+    custom_decoration: Option<CustomDecoration>,
+    frame: Option<SentenceFrame>,
+    children: Vec<MetadataNode>,
+}
+
+impl TryFrom<&DataAttributes> for MetadataNode {
+    type Error = String;
+
+    fn try_from(data: &DataAttributes) -> Result<Self, Self::Error> {
+        let kind = ElementKind::try_from(
+            data.get(KIND)
+                .ok_or_else(|| "Missing data-kind attribute!".to_string())?,
+        )?;
+
+        let parse_number = |key: &str| -> Result<Option<f32>, String> {
+            data.get(key)
+                .map(|value| {
+                    value
+                        .parse::<f32>()
+                        .map_err(|_| format!("data-{} '{}' is not a number!", key, value))
+                })
+                .transpose()
+        };
+
+        let position_data = match (parse_number(ANGLE)?, parse_number(DISTANCE)?) {
+            (Some(angle), Some(distance)) => Some(PositionData(layout::PositionData {
+                angle: Degree::new(angle),
+                distance,
+                angle_placement: match data.get(ANGLE_PLACEMENT) {
+                    Some("relative") => AnglePlacement::Relative,
+                    _ => AnglePlacement::Absolute,
+                },
+            })),
+            _ => None,
+        };
+
+        let consonant = data
+            .get(CONSONANT)
+            .map(|value| {
+                Consonant::from_str(value)
+                    .map_err(|_| format!("data-{} '{}' is not a consonant!", CONSONANT, value))
+            })
+            .transpose()?;
+        let vocal = data
+            .get(VOCAL)
+            .map(|value| {
+                Vocal::from_str(value)
+                    .map_err(|_| format!("data-{} '{}' is not a vocal!", VOCAL, value))
+            })
+            .transpose()?;
+
+        let letter = match (consonant, vocal) {
+            (Some(consonant), Some(vocal)) => {
+                Some(alphabet::Letter::ConsonantWithVocal { consonant, vocal })
+            }
+            (Some(consonant), None) => Some(alphabet::Letter::Consonant(consonant)),
+            (None, Some(vocal)) => Some(alphabet::Letter::Vocal(vocal)),
+            (None, None) => None,
+        };
+
+        Ok(Self {
+            id: None,
+            kind,
+            text: data.get(TEXT).map(str::to_string),
+            radius: parse_number(RADIUS)?.map(Radius),
+            position_data,
+            letter,
+            custom_decoration: data
+                .get(CUSTOM)
+                .map(CustomDecoration::try_from)
+                .transpose()?,
+            frame: data.get(FRAME).map(SentenceFrame::try_from).transpose()?,
+            children: Vec::new(),
+        })
+    }
+}
+
+/// Collects all elements with metadata while keeping their nesting. The words of a sentence
+/// without a frame follow its metadata instead of being nested in it.
+fn collect_metadata_nodes(elements: &[SVGElement]) -> Result<Vec<MetadataNode>, String> {
+    let mut nodes: Vec<MetadataNode> = Vec::new();
+
+    for element in elements {
+        if let SVGElement::Group(group) = element {
+            let children = collect_metadata_nodes(&group.elements)?;
+
+            if group.data.get(KIND).is_some() {
+                let mut node = MetadataNode::try_from(&group.data)?;
+                node.id = group.id.as_deref().map(str::parse).transpose()?;
+                node.children = children;
+                nodes.push(node);
+            } else {
+                nodes.extend(children);
+            }
+        }
+    }
+
+    let mut nested_nodes: Vec<MetadataNode> = Vec::with_capacity(nodes.len());
+
+    for node in nodes {
+        match nested_nodes.last_mut() {
+            Some(sentence)
+                if sentence.frame == Some(SentenceFrame::None)
+                    && node.kind != ElementKind::Sentence =>
+            {
+                sentence.children.push(node);
+            }
+            _ => nested_nodes.push(node),
+        }
+    }
+
+    Ok(nested_nodes)
+}
+
+/// The nesting which creates the same letters as the imported ones or `None` if the current
+/// nesting already does.
+fn imported_nesting(
+    sentences: &[MetadataNode],
+    current: &NestingSettings,
+) -> Option<NestingSettings> {
+    fn collect(
+        node: &MetadataNode,
+        nested: &mut HashSet<(Consonant, Vocal)>,
+        not_nested: &mut HashSet<(Consonant, Vocal)>,
+    ) {
+        let letters: Vec<alphabet::Letter> = node
+            .children
+            .iter()
+            .filter(|child| child.kind == ElementKind::Letter)
+            .filter_map(|child| child.letter)
+            .collect();
+
+        for letter in &letters {
+            if let alphabet::Letter::ConsonantWithVocal { consonant, vocal } = letter {
+                nested.insert((*consonant, *vocal));
+            }
+        }
+
+        for pair in letters.windows(2) {
+            if let [alphabet::Letter::Consonant(consonant), alphabet::Letter::Vocal(vocal)] = pair {
+                not_nested.insert((*consonant, *vocal));
+            }
+        }
+
+        for child in &node.children {
+            collect(child, nested, not_nested);
+        }
+    }
+
+    let mut nested = HashSet::new();
+    let mut not_nested = HashSet::new();
+
+    for sentence in sentences {
+        collect(sentence, &mut nested, &mut not_nested);
+    }
+
+    let is_matching = nested
+        .iter()
+        .all(|(consonant, vocal)| current.can_nest(*consonant, *vocal))
+        && !not_nested
+            .iter()
+            .any(|(consonant, vocal)| current.can_nest(*consonant, *vocal));
+
+    if is_matching {
+        None
+    } else if not_nested.is_empty() {
+        Some(NestingSettings::All)
+    } else if nested.is_empty() {
+        Some(NestingSettings::None)
+    } else {
+        Some(NestingSettings::Custom(nested))
+    }
+}
+
+fn handle_import_svg_event(
+    mut events: EventReader<ImportSVG>,
+    mut set_text_events: EventWriter<SetText>,
+    mut failed_events: EventWriter<ImportSVGFailed>,
+    mut pending_metadata: ResMut<PendingMetadata>,
+    mut sentence_frame: ResMut<SentenceFrameSettings>,
+    mut nesting_settings: ResMut<NestingSettings>,
+) {
+    if let Some(ImportSVG(svg)) = events.iter().last() {
+        let sentences = collect_metadata_nodes(&svg.elements).and_then(|nodes| {
+            let sentences: Vec<MetadataNode> = nodes
+                .into_iter()
+                .filter(|node| node.kind == ElementKind::Sentence)
+                .collect();
+
+            if sentences.is_empty() {
+                Err("SVG doesn't contain any sentence metadata!".to_string())
+            } else {
+                Ok(sentences)
+            }
+        });
+
+        match sentences {
+            Ok(sentences) => {
+                let text = sentences
+                    .iter()
+                    .map(|sentence| sentence.text.clone().unwrap_or_default())
+                    .collect::<Vec<_>>()
+                    .join(&alphabet::SENTENCE_SEPARATOR.to_string());
+
+                info!("Import sentences: {:?}", text);
+
+                if let Some(frame) = sentences.iter().find_map(|sentence| sentence.frame) {
+                    if **sentence_frame != frame {
+                        sentence_frame.0 = frame;
+                    }
+                }
+
+                if let Some(nesting) = imported_nesting(&sentences, &nesting_settings) {
+                    info!("Restore nesting of the imported svg");
+                    *nesting_settings = nesting;
+                }
+
+                set_text_events.send(SetText(text));
+                pending_metadata.0 = Some(sentences);
+            }
+            Err(error) => {
+                error!("Failed to import svg: {}", error);
+                failed_events.send(ImportSVGFailed(error));
+            }
+        }
+    }
+}
+
+type MetadataQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        ElementKindQuery,
+        Option<&'static Text>,
+        Option<&'static Children>,
+        Option<&'static mut ElementId>,
+        Option<&'static mut Radius>,
+        Option<&'static mut PositionData>,
+    ),
+>;
+
+/// Runs after the text converter created the entities of the imported text.
+///
+/// Elements without an imported id get a new one so that they can't collide with the imported
+/// ids.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn apply_pending_metadata(
+    mut commands: Commands,
+    mut failed_events: EventWriter<ImportSVGFailed>,
+    mut pending_metadata: ResMut<PendingMetadata>,
+    sentence_query: Query<(Entity, Option<&SentenceIndex>), (With<Sentence>, Without<Parent>)>,
+    mut metadata_query: MetadataQuery,
+    children_query: Query<&Children>,
+    mut decoration_children_query: DecorationChildrenQuery,
+    mut element_id_generator: ResMut<ElementIdGenerator>,
+) {
+    if let Some(sentences) = pending_metadata.0.take() {
+        let mut sentence_entities: Vec<_> = sentence_query
+            .iter()
+            .map(|(entity, index)| (index, entity))
+            .collect();
+        sort_sentences(&mut sentence_entities);
+
+        if sentence_entities.len() != sentences.len() {
+            let error = format!(
+                "Expected {} sentences but found {}!",
+                sentences.len(),
+                sentence_entities.len()
+            );
+            error!("Failed to apply imported metadata: {}", error);
+            failed_events.send(ImportSVGFailed(error));
+            return;
+        }
+
+        for sentence in &sentences {
+            reserve_element_ids(sentence, &mut element_id_generator);
+        }
+
+        for (sentence, (_, sentence_entity)) in sentences.iter().zip(sentence_entities) {
+            let mut imported = HashSet::new();
+            if let Err(error) = apply_metadata(
+                sentence,
+                sentence_entity,
+                &mut metadata_query,
+                &mut imported,
+                &mut commands,
+                &mut decoration_children_query,
+            ) {
+                error!("Failed to apply imported metadata: {}", error);
+                failed_events.send(ImportSVGFailed(error));
+            }
+
+            let entities = std::iter::once(sentence_entity)
+                .chain(children_query.iter_descendants(sentence_entity))
+                .filter(|entity| !imported.contains(entity));
+            let mut elements = metadata_query.iter_many_mut(entities);
+
+            while let Some((_, _, _, element_id, ..)) = elements.fetch_next() {
+                if let Some(mut element_id) = element_id {
+                    *element_id = element_id_generator.generate();
+                }
+            }
+        }
+    }
+}
+
+fn reserve_element_ids(node: &MetadataNode, element_id_generator: &mut ElementIdGenerator) {
+    if let Some(id) = node.id {
+        element_id_generator.reserve(id);
+    }
+
+    for child in &node.children {
+        reserve_element_ids(child, element_id_generator);
+    }
+}
+
+type DecorationChildrenQuery<'w, 's> =
+    Query<'w, 's, (&'static mut CircleChildren, &'static mut LineSlotChildren)>;
+
+/// Custom decorations aren't created by the text converter and are spawned by the import.
+fn spawn_imported_decoration(
+    node: &MetadataNode,
+    decoration: CustomDecoration,
+    letter: Entity,
+    commands: &mut Commands,
+    decoration_children_query: &mut DecorationChildrenQuery,
+) {
+    let Ok((mut dots, mut line_slots)) = decoration_children_query.get_mut(letter) else {
+        warn!(
+            "Can't import {:?} into {:?}, it's not a letter",
+            decoration, letter
+        );
+        return;
+    };
+
+    let position_data = node.position_data.unwrap_or_default();
+
+    let mut entity_commands = match decoration {
+        CustomDecoration::Dot | CustomDecoration::Circle => {
+            let entity_commands = commands.spawn((
+                DotBundle {
+                    dot: Dot,
+                    radius: node.radius.unwrap_or_default(),
+                    position_data,
+                },
+                decoration,
+            ));
+            dots.push(entity_commands.id());
+            entity_commands
+        }
+        CustomDecoration::Line => {
+            let entity_commands = commands.spawn((
+                LineSlotBundle {
+                    line_slot: Default::default(),
+                    position_data,
+                },
+                decoration,
+            ));
+            line_slots.push(entity_commands.id());
+            entity_commands
+        }
+    };
+
+    if let Some(element_id) = node.id {
+        entity_commands.insert(element_id);
+    }
+
+    let decoration_entity = entity_commands.id();
+    debug!("Import {:?} {:?}", decoration, decoration_entity);
+    commands.entity(letter).add_child(decoration_entity);
+}
+
+fn apply_metadata(
+    node: &MetadataNode,
+    entity: Entity,
+    metadata_query: &mut MetadataQuery,
+    imported: &mut HashSet<Entity>,
+    commands: &mut Commands,
+    decoration_children_query: &mut DecorationChildrenQuery,
+) -> Result<(), String> {
+    let children = match metadata_query.get_mut(entity) {
+        Ok((kind, text, children, element_id, radius, position_data)) => {
+            let letter = kind.2.map(|letter| **letter);
+
+            if ElementKind::from(kind) != node.kind {
+                return Err(format!(
+                    "Imported {:?} doesn't match {:?}!",
+                    node.kind, entity
+                ));
+            }
+
+            if node.text.as_deref() != text.map(|it| it.as_str()) {
+                return Err(format!(
+                    "Imported text {:?} doesn't match {:?}!",
+                    node.text,
+                    text.map(|it| it.as_str())
+                ));
+            }
+
+            if node.letter.is_some() && node.letter != letter {
+                return Err(format!(
+                    "Imported letter {:?} doesn't match {:?}, the svg was exported with another alphabet!",
+                    node.letter, letter
+                ));
+            }
+
+            if let (Some(mut element_id), Some(new_element_id)) = (element_id, node.id) {
+                *element_id = new_element_id;
+                imported.insert(entity);
+            }
+
+            if let (Some(mut radius), Some(new_radius)) = (radius, node.radius) {
+                *radius = new_radius;
+            }
+
+            if let (Some(mut position_data), Some(new_position_data)) =
+                (position_data, node.position_data)
+            {
+                *position_data = new_position_data;
+            }
+
+            children.map(|it| it.to_vec()).unwrap_or_default()
+        }
+        Err(_) => {
+            return Ok(());
+        }
+    };
+
+    let mut child_kinds: Vec<Option<(Entity, ElementKind)>> = children
+        .into_iter()
+        .filter_map(|child| {
+            metadata_query
+                .get(child)
+                .ok()
+                .map(|(kind, ..)| Some((child, ElementKind::from(kind))))
+        })
+        .collect();
+
+    // children are matched in order per kind because dots and letters share the children
+    for child_node in &node.children {
+        if let Some(decoration) = child_node.custom_decoration {
+            spawn_imported_decoration(
+                child_node,
+                decoration,
+                entity,
+                commands,
+                decoration_children_query,
+            );
+            continue;
+        }
+
+        let child_entity = child_kinds
+            .iter_mut()
+            .find(|it| matches!(it, Some((_, kind)) if *kind == child_node.kind))
+            .and_then(Option::take);
+
+        match child_entity {
+            Some((child_entity, _)) => {
+                apply_metadata(
+                    child_node,
+                    child_entity,
+                    metadata_query,
+                    imported,
+                    commands,
+                    decoration_children_query,
+                )?;
+            }
+            None => {
+                return Err(format!(
+                    "Couldn't find entity for imported {:?}!",
+                    child_node.kind
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::plugins::svg::Group;
+
+    #[test]
+    fn should_collect_nested_metadata_nodes() {
+        let mut dot = Group::new();
+        dot.data.push(KIND, "dot");
+        dot.data.push(RADIUS, 2.5);
+        dot.data.push(ANGLE, 45.0);
+        dot.data.push(DISTANCE, 10.0);
+        dot.data.push(ANGLE_PLACEMENT, "relative");
+
+        // groups without metadata are skipped
+        let mut wrapper = Group::new();
+        wrapper.push(dot);
+
+        let mut letter = Group::new();
+        letter.id = Some("e3".to_string());
+        letter.data.push(KIND, "letter");
+        letter.data.push(TEXT, "b");
+        letter.push(wrapper);
+
+        let result = collect_metadata_nodes(&[letter.into()]).unwrap();
+
+        let expected = vec![MetadataNode {
+            id: Some(ElementId(3)),
+            kind: ElementKind::Letter,
+            text: Some("b".to_string()),
+            radius: None,
+            position_data: None,
+            letter: None,
+            custom_decoration: None,
+            frame: None,
+            children: vec![MetadataNode {
+                id: None,
+                kind: ElementKind::Dot,
+                text: None,
+                radius: Some(Radius(2.5)),
+                position_data: Some(PositionData(layout::PositionData {
+                    angle: Degree::new(45.0),
+                    distance: 10.0,
+                    angle_placement: AnglePlacement::Relative,
+                })),
+                letter: None,
+                custom_decoration: None,
+                frame: None,
+                children: vec![],
+            }],
+        }];
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_nest_words_of_frameless_sentence() {
+        let mut sentence = Group::new();
+        sentence.data.push(KIND, "sentence");
+        sentence.data.push(TEXT, "ba");
+        sentence.data.push(FRAME, "none");
+
+        let mut word = Group::new();
+        word.data.push(KIND, "word");
+        word.data.push(TEXT, "ba");
+
+        let mut root = Group::new();
+        root.push(sentence);
+        root.push(word);
+
+        let result = collect_metadata_nodes(&[root.into()]).unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].frame, Some(SentenceFrame::None));
+        assert_eq!(result[0].children.len(), 1);
+        assert_eq!(result[0].children[0].kind, ElementKind::Word);
+    }
+
+    #[test]
+    fn should_reject_unknown_kind() {
+        let mut group = Group::new();
+        group.data.push(KIND, "unknown");
+
+        assert!(collect_metadata_nodes(&[group.into()]).is_err());
+    }
+}
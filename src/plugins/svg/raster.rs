@@ -0,0 +1,284 @@
+use super::export::FILL_CLASS;
+use super::render::add_element_geometry;
+use super::{SVGElement, DEFAULT_STROKE_WIDTH, SVG};
+use bevy::math::Affine2;
+use bevy::prelude::*;
+use bevy::render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_prototype_lyon::prelude::tess::path::path::Builder;
+use bevy_prototype_lyon::prelude::tess::{
+    BuffersBuilder, FillTessellator, FillVertex, StrokeTessellator, StrokeVertex, VertexBuffers,
+};
+use bevy_prototype_lyon::prelude::*;
+use gallifreyan_core::alphabet::Alphabet;
+use gallifreyan_core::model;
+
+/// Samples per pixel along each axis which are used for anti-aliasing.
+const SUB_SAMPLES: usize = 4;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RasterSettings {
+    /// Width and height of the image in pixels.
+    pub resolution: u32,
+    pub color: Color,
+    /// in svg units so that it scales with the resolution
+    pub stroke_width: f32,
+}
+
+impl Default for RasterSettings {
+    fn default() -> Self {
+        Self {
+            resolution: 512,
+            color: Color::BLACK,
+            stroke_width: DEFAULT_STROKE_WIDTH,
+        }
+    }
+}
+
+pub fn rasterize_sentence(
+    sentence: &model::Sentence,
+    alphabet: &Alphabet,
+    settings: &RasterSettings,
+) -> Image {
+    rasterize_svg(&sentence.to_svg(alphabet), settings)
+}
+
+/// Tessellates the svg with lyon and fills the triangles on the cpu into a transparent image.
+pub fn rasterize_svg(svg: &SVG, settings: &RasterSettings) -> Image {
+    let resolution = settings.resolution as usize;
+    let sample_resolution = resolution * SUB_SAMPLES;
+    let samples_per_unit = sample_resolution as f32 / svg.size;
+
+    // the view box is centered on the origin
+    let to_samples = Affine2::from_scale_angle_translation(
+        Vec2::splat(samples_per_unit),
+        0.0,
+        Vec2::splat(sample_resolution as f32 / 2.0),
+    );
+
+    let mut triangles = Vec::new();
+    collect_triangles(
+        &svg.elements,
+        to_samples,
+        settings.stroke_width,
+        // half a pixel is precise enough and keeps the number of triangles low
+        svg.size / settings.resolution as f32 / 2.0,
+        &mut triangles,
+    );
+
+    let mut samples = vec![false; sample_resolution * sample_resolution];
+
+    for triangle in &triangles {
+        fill_triangle(triangle, sample_resolution, &mut samples);
+    }
+
+    let [r, g, b, a] = settings
+        .color
+        .as_rgba_f32()
+        .map(|channel| (channel.clamp(0.0, 1.0) * 255.0).round() as u8);
+    let mut data = Vec::with_capacity(resolution * resolution * 4);
+
+    for y in 0..resolution {
+        for x in 0..resolution {
+            let covered = (0..SUB_SAMPLES)
+                .flat_map(|sub_y| {
+                    let row = (y * SUB_SAMPLES + sub_y) * sample_resolution;
+                    (0..SUB_SAMPLES).map(move |sub_x| row + x * SUB_SAMPLES + sub_x)
+                })
+                .filter(|index| samples[*index])
+                .count();
+
+            let alpha = a as usize * covered / (SUB_SAMPLES * SUB_SAMPLES);
+
+            data.extend_from_slice(&[r, g, b, alpha as u8]);
+        }
+    }
+
+    Image::new(
+        Extent3d {
+            width: settings.resolution,
+            height: settings.resolution,
+            depth_or_array_layers: 1,
+        },
+        TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+    )
+}
+
+fn collect_triangles(
+    elements: &[SVGElement],
+    affine2: Affine2,
+    stroke_width: f32,
+    tolerance: f32,
+    triangles: &mut Vec<[Vec2; 3]>,
+) {
+    for element in elements {
+        let is_fill = match element {
+            SVGElement::Group(group) => {
+                collect_triangles(
+                    &group.elements,
+                    affine2 * group.affine2,
+                    stroke_width,
+                    tolerance,
+                    triangles,
+                );
+                continue;
+            }
+            SVGElement::Circle(circle) => circle.class.0 == FILL_CLASS,
+            SVGElement::Line(line) => line.class.0 == FILL_CLASS,
+            SVGElement::Path(path) => path.class.0 == FILL_CLASS,
+            SVGElement::Title(_) | SVGElement::Style(_) => continue,
+        };
+
+        let mut builder = Builder::new();
+        add_element_geometry(element, &mut builder);
+        let path = builder.build();
+
+        let mut buffers: VertexBuffers<Vec2, u32> = VertexBuffers::new();
+
+        let result = if is_fill {
+            FillTessellator::new().tessellate_path(
+                &path,
+                &FillOptions::DEFAULT.with_tolerance(tolerance),
+                &mut BuffersBuilder::new(&mut buffers, |vertex: FillVertex| {
+                    Vec2::new(vertex.position().x, vertex.position().y)
+                }),
+            )
+        } else {
+            StrokeTessellator::new().tessellate_path(
+                &path,
+                &StrokeOptions::DEFAULT
+                    .with_line_cap(LineCap::Round)
+                    .with_line_join(LineJoin::Round)
+                    .with_line_width(stroke_width)
+                    .with_tolerance(tolerance),
+                &mut BuffersBuilder::new(&mut buffers, |vertex: StrokeVertex| {
+                    Vec2::new(vertex.position().x, vertex.position().y)
+                }),
+            )
+        };
+
+        if let Err(error) = result {
+            error!("Failed to tessellate {:?}: {:?}", element, error);
+            continue;
+        }
+
+        triangles.extend(buffers.indices.chunks_exact(3).map(|indices| {
+            [
+                affine2.transform_point2(buffers.vertices[indices[0] as usize]),
+                affine2.transform_point2(buffers.vertices[indices[1] as usize]),
+                affine2.transform_point2(buffers.vertices[indices[2] as usize]),
+            ]
+        }));
+    }
+}
+
+/// Marks all samples whose center is inside of the triangle.
+fn fill_triangle(triangle: &[Vec2; 3], sample_resolution: usize, samples: &mut [bool]) {
+    let [a, b, c] = *triangle;
+
+    let edge = |from: Vec2, to: Vec2, point: Vec2| (to - from).perp_dot(point - from);
+
+    let area = edge(a, b, c);
+
+    if area == 0.0 {
+        return;
+    }
+
+    let min = a.min(b).min(c).floor().max(Vec2::ZERO);
+    let max = a
+        .max(b)
+        .max(c)
+        .ceil()
+        .min(Vec2::splat(sample_resolution as f32));
+
+    for y in min.y as usize..max.y as usize {
+        for x in min.x as usize..max.x as usize {
+            let point = Vec2::new(x as f32 + 0.5, y as f32 + 0.5);
+
+            let w0 = edge(b, c, point) * area.signum();
+            let w1 = edge(c, a, point) * area.signum();
+            let w2 = edge(a, b, point) * area.signum();
+
+            if w0 >= 0.0 && w1 >= 0.0 && w2 >= 0.0 {
+                samples[y * sample_resolution + x] = true;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gallifreyan_core::layout::ReadingDirection;
+
+    fn alpha(image: &Image, x: usize, y: usize) -> u8 {
+        image.data[(y * image.size().x as usize + x) * 4 + 3]
+    }
+
+    #[test]
+    fn should_fill_triangle_samples() {
+        let mut samples = vec![false; 16];
+
+        fill_triangle(
+            &[
+                Vec2::new(0.0, 0.0),
+                Vec2::new(4.0, 0.0),
+                Vec2::new(0.0, 4.0),
+            ],
+            4,
+            &mut samples,
+        );
+
+        let covered: Vec<usize> = samples
+            .iter()
+            .enumerate()
+            .filter(|(_, it)| **it)
+            .map(|(index, _)| index)
+            .collect();
+
+        assert_eq!(covered, vec![0, 1, 2, 3, 4, 5, 6, 8, 9, 12]);
+    }
+
+    #[test]
+    fn should_rasterize_sentence() {
+        let alphabet = Alphabet::sherman();
+        let sentence = model::Sentence::new("bad", &true, &alphabet, ReadingDirection::default());
+        let settings = RasterSettings {
+            resolution: 100,
+            color: Color::RED,
+            stroke_width: 10.0,
+        };
+
+        let image = rasterize_sentence(&sentence, &alphabet, &settings);
+
+        assert_eq!(image.size(), Vec2::new(100.0, 100.0));
+        assert_eq!(image.data.len(), 100 * 100 * 4);
+
+        // both circles of the sentence at the right border
+        assert_eq!(alpha(&image, 95, 50), 255);
+        assert_eq!(&image.data[(50 * 100 + 95) * 4..][..3], &[255, 0, 0]);
+        // outside of the sentence
+        assert_eq!(alpha(&image, 2, 50), 0);
+        assert_eq!(alpha(&image, 0, 0), 0);
+        // center of the word
+        assert_eq!(alpha(&image, 50, 50), 0);
+    }
+
+    #[test]
+    fn should_rasterize_deterministic() {
+        let alphabet = Alphabet::sherman();
+        let sentence =
+            model::Sentence::new("gallifreyan", &true, &alphabet, ReadingDirection::default());
+        let settings = RasterSettings {
+            resolution: 64,
+            ..default()
+        };
+
+        let first = rasterize_sentence(&sentence, &alphabet, &settings);
+        let second = rasterize_sentence(&sentence, &alphabet, &settings);
+
+        assert_eq!(first.data, second.data);
+        assert!(first.data.chunks_exact(4).any(|pixel| pixel[3] > 0));
+    }
+}
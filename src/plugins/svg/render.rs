@@ -0,0 +1,370 @@
+use super::{DrawStyle, SVGElement, SVGShape, StyleOverride, DEFAULT_STROKE_WIDTH};
+use crate::plugins::color_theme::{ColorDependency, ColorTheme, DRAW_COLOR};
+use crate::plugins::text_converter::components::{
+    Dot, Letter, LineSlot, Paragraph, Sentence, Word,
+};
+use crate::plugins::text_converter::script::ScriptElementKind;
+use crate::plugins::text_converter::TextConverterBaseSet;
+use bevy::prelude::*;
+use bevy_prototype_lyon::entity::ShapeBundle;
+use bevy_prototype_lyon::prelude::tess::path::iterator::PathIterator;
+use bevy_prototype_lyon::prelude::tess::path::path::Builder;
+use bevy_prototype_lyon::prelude::tess::path::PathEvent;
+use bevy_prototype_lyon::prelude::*;
+use bevy_prototype_lyon::shapes;
+
+/// Draws the svg elements of the entities with lyon.
+pub struct SVGRenderPlugin;
+
+impl Plugin for SVGRenderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            (draw, update_colors, update_style_override).in_base_set(CoreSet::PostUpdate),
+        )
+        .add_system(add_shape.in_base_set(TextConverterBaseSet::PostTextConverter));
+    }
+}
+
+impl Geometry for SVGShape {
+    fn add_geometry(&self, b: &mut Builder) {
+        add_element_geometry(&self.0, b);
+    }
+}
+
+pub(super) fn add_element_geometry(element: &SVGElement, b: &mut Builder) {
+    match element {
+        SVGElement::Title(_) => {
+            error!("Cannot convert title to geometry!");
+        }
+        SVGElement::Group(group) => {
+            for element in &group.elements {
+                add_element_geometry(element, b);
+            }
+        }
+        SVGElement::Circle(circle) => {
+            shapes::Circle {
+                radius: circle.radius,
+                center: Default::default(),
+            }
+            .add_geometry(b);
+        }
+        SVGElement::Line(line) => {
+            shapes::Line(line.from, line.to).add_geometry(b);
+        }
+        SVGElement::Path(path) => {
+            shapes::SvgPathShape {
+                svg_doc_size_in_px: Default::default(),
+                svg_path_string: path.path(false),
+            }
+            .add_geometry(b);
+        }
+        SVGElement::Style(_) => {
+            error!("Cannot convert style to geometry!");
+        }
+    }
+}
+
+const STROKE_OPTIONS: StrokeOptions = StrokeOptions::DEFAULT
+    .with_line_cap(LineCap::Round)
+    .with_line_join(LineJoin::Round)
+    .with_line_width(DEFAULT_STROKE_WIDTH);
+
+fn new_stroke(color: Color) -> Stroke {
+    Stroke {
+        options: STROKE_OPTIONS,
+        color,
+    }
+}
+
+fn new_fill(color: Color) -> Fill {
+    Fill {
+        options: FillOptions::DEFAULT,
+        color,
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_colors(
+    color_theme: Res<ColorTheme>,
+    mut query: Query<(
+        &ColorDependency,
+        Option<&StyleOverride>,
+        Option<&mut Stroke>,
+        Option<&mut Fill>,
+    )>,
+) {
+    if !color_theme.is_changed() {
+        return;
+    }
+
+    for (dependency, style_override, mut stroke, mut fill) in query.iter_mut() {
+        if let Some(new_color) = color_theme.get(dependency.0) {
+            let new_color =
+                style_override.map_or(new_color, |it| it.apply_color(dependency.0, new_color));
+
+            if let Some(stroke) = stroke.as_mut() {
+                stroke.color = new_color;
+            }
+
+            if let Some(fill) = fill.as_mut() {
+                fill.color = new_color;
+            }
+        } else {
+            error!("Couldn't find {} key in color theme!", dependency.0);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_style_override(
+    color_theme: Res<ColorTheme>,
+    mut query: Query<
+        (
+            &StyleOverride,
+            &ColorDependency,
+            Option<&mut Stroke>,
+            Option<&mut Fill>,
+        ),
+        Changed<StyleOverride>,
+    >,
+) {
+    for (style_override, dependency, mut stroke, mut fill) in query.iter_mut() {
+        if let Some(new_color) = color_theme.get(dependency.0) {
+            let new_color = style_override.apply_color(dependency.0, new_color);
+
+            if let Some(stroke) = stroke.as_mut() {
+                stroke.color = new_color;
+                stroke.options.line_width = style_override.stroke_width();
+            }
+
+            if let Some(fill) = fill.as_mut() {
+                fill.color = new_color;
+            }
+        } else {
+            error!("Couldn't find {} key in color theme!", dependency.0);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn add_shape(
+    mut commands: Commands,
+    stroke_query: Query<
+        Entity,
+        (
+            Or<(
+                Added<Sentence>,
+                Added<Word>,
+                Added<Letter>,
+                Added<LineSlot>,
+                Added<Paragraph>,
+            )>,
+            Without<ScriptElementKind>,
+        ),
+    >,
+    fill_query: Query<Entity, Added<Dot>>,
+    script_element_query: Query<(Entity, &DrawStyle), Added<ScriptElementKind>>,
+    color_theme: Res<ColorTheme>,
+) {
+    let script_stroke_entities = script_element_query
+        .iter()
+        .filter(|(_, draw_style)| **draw_style == DrawStyle::Stroke)
+        .map(|(entity, _)| entity);
+    let script_fill_entities = script_element_query
+        .iter()
+        .filter(|(_, draw_style)| **draw_style == DrawStyle::Fill)
+        .map(|(entity, _)| entity);
+
+    for entity in stroke_query.iter().chain(script_stroke_entities) {
+        commands
+            .entity(entity)
+            .insert(ShapeBundle {
+                transform: Transform::from_xyz(0.0, 0.0, 0.1),
+                ..default()
+            })
+            .insert(new_stroke(color_theme.get(DRAW_COLOR).unwrap()))
+            .insert(ColorDependency(DRAW_COLOR));
+    }
+
+    for entity in fill_query.iter().chain(script_fill_entities) {
+        commands
+            .entity(entity)
+            .insert(ShapeBundle {
+                transform: Transform::from_xyz(0.0, 0.0, 0.1),
+                ..default()
+            })
+            .insert(new_fill(color_theme.get(DRAW_COLOR).unwrap()))
+            .insert(ColorDependency(DRAW_COLOR));
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn draw(
+    mut query: Query<
+        (
+            &SVGShape,
+            Option<&StyleOverride>,
+            Option<&Stroke>,
+            &mut Path,
+        ),
+        Or<(Changed<SVGShape>, Changed<StyleOverride>)>,
+    >,
+) {
+    for (svg_element, style_override, stroke, mut path) in query.iter_mut() {
+        let mut path_builder = Builder::new();
+        svg_element.add_geometry(&mut path_builder);
+        let geometry = path_builder.build();
+
+        *path = match style_override {
+            Some(style_override) if stroke.is_some() && !style_override.dash_pattern.is_empty() => {
+                Path(apply_dash_pattern(&geometry, &style_override.dash_pattern))
+            }
+            _ => Path(geometry),
+        };
+    }
+}
+
+/// Lyon can't tessellate dashed strokes so the geometry itself gets split into dashes.
+fn apply_dash_pattern(geometry: &tess::path::Path, dash_pattern: &[f32]) -> tess::path::Path {
+    let dash_pattern = if dash_pattern.len().is_multiple_of(2) {
+        dash_pattern.to_vec()
+    } else {
+        // same as svg an odd number of values gets repeated to yield an even number of values
+        dash_pattern.repeat(2)
+    };
+
+    if dash_pattern.iter().any(|it| *it < 0.0) || dash_pattern.iter().sum::<f32>() <= 0.0 {
+        return geometry.clone();
+    }
+
+    let mut dashes = Dashes {
+        builder: Builder::new(),
+        dash_pattern: &dash_pattern,
+        index: 0,
+        remaining: dash_pattern[0],
+        is_open: false,
+    };
+
+    for event in geometry.iter().flattened(0.1) {
+        match event {
+            PathEvent::Begin { .. } => dashes.restart(),
+            PathEvent::Line { from, to } => dashes.add_segment(from, to),
+            PathEvent::End {
+                last,
+                first,
+                close: true,
+            } => dashes.add_segment(last, first),
+            PathEvent::End { close: false, .. } => dashes.end(),
+            _ => {}
+        }
+    }
+
+    dashes.end();
+    dashes.builder.build()
+}
+
+/// Walks along the segments of a path and only keeps the parts inside of dashes.
+struct Dashes<'a> {
+    builder: Builder,
+    dash_pattern: &'a [f32],
+    index: usize,
+    remaining: f32,
+    is_open: bool,
+}
+
+impl Dashes<'_> {
+    fn end(&mut self) {
+        if self.is_open {
+            self.builder.end(false);
+            self.is_open = false;
+        }
+    }
+
+    /// Same as svg every subpath starts at the beginning of the dash pattern.
+    fn restart(&mut self) {
+        self.end();
+        self.index = 0;
+        self.remaining = self.dash_pattern[0];
+    }
+
+    fn add_segment(&mut self, from: tess::math::Point, to: tess::math::Point) {
+        let length = from.distance_to(to);
+        let mut travelled = 0.0;
+
+        while travelled < length {
+            while self.remaining <= 0.0 {
+                self.end();
+                self.index = (self.index + 1) % self.dash_pattern.len();
+                self.remaining = self.dash_pattern[self.index];
+            }
+
+            let step = self.remaining.min(length - travelled);
+            let is_dash = self.index.is_multiple_of(2);
+
+            if is_dash {
+                if !self.is_open {
+                    self.builder.begin(from.lerp(to, travelled / length));
+                    self.is_open = true;
+                }
+
+                self.builder
+                    .line_to(from.lerp(to, (travelled + step) / length));
+            }
+
+            travelled += step;
+            self.remaining -= step;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn should_split_geometry_into_dashes() {
+        let mut builder = Builder::new();
+        builder.begin(tess::math::point(0.0, 0.0));
+        builder.line_to(tess::math::point(10.0, 0.0));
+        builder.end(false);
+
+        let result = apply_dash_pattern(&builder.build(), &[2.0, 3.0]);
+
+        let dashes = result
+            .iter()
+            .filter_map(|event| match event {
+                PathEvent::Line { from, to } => Some((from.x, to.x)),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(dashes, vec![(0.0, 2.0), (5.0, 7.0)]);
+    }
+
+    #[test]
+    fn should_restart_dashes_for_each_subpath() {
+        let mut builder = Builder::new();
+        builder.begin(tess::math::point(0.0, 0.0));
+        builder.line_to(tess::math::point(4.0, 0.0));
+        builder.end(false);
+        builder.begin(tess::math::point(0.0, 10.0));
+        builder.line_to(tess::math::point(4.0, 10.0));
+        builder.end(false);
+
+        let result = apply_dash_pattern(&builder.build(), &[6.0, 2.0]);
+
+        let lines = result
+            .iter()
+            .filter_map(|event| match event {
+                PathEvent::Line { from, to } => Some(((from.x, from.y), (to.x, to.y))),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        // without a line between the end of the first subpath and the start of the second
+        assert_eq!(
+            lines,
+            vec![((0.0, 0.0), (4.0, 0.0)), ((0.0, 10.0), (4.0, 10.0))]
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use super::{CSSRule, InlineStyle, ToRgb};
+use crate::plugins::color_theme::DRAW_COLOR;
+use bevy::prelude::*;
+
+pub const DEFAULT_STROKE_WIDTH: f32 = 1.0;
+
+#[derive(Debug, Default, Clone, PartialEq, Component)]
+pub struct StyleOverride {
+    pub color: Option<Color>,
+    pub width: Option<f32>,
+    pub dash_pattern: Vec<f32>,
+    pub opacity: Option<f32>,
+}
+
+impl StyleOverride {
+    /// Only the draw color gets overridden so that highlights like the selection stay visible.
+    pub fn apply_color(&self, dependency: &str, color: Color) -> Color {
+        if dependency != DRAW_COLOR {
+            return color;
+        }
+
+        let color = self.color.unwrap_or(color);
+
+        match self.opacity {
+            Some(opacity) => color.with_a(color.a() * opacity),
+            None => color,
+        }
+    }
+
+    pub fn stroke_width(&self) -> f32 {
+        self.width.unwrap_or(DEFAULT_STROKE_WIDTH)
+    }
+
+    pub fn to_inline_style(&self, is_fill: bool) -> InlineStyle {
+        let mut rules = Vec::new();
+
+        if is_fill {
+            if let Some(color) = self.color {
+                rules.push(CSSRule::Fill(Some(color.to_rgb())));
+            }
+        } else {
+            if let Some(color) = self.color {
+                rules.push(CSSRule::Stroke(Some(color.to_rgb())));
+            }
+
+            if let Some(width) = self.width {
+                rules.push(CSSRule::StrokeWidth(width));
+            }
+
+            if !self.dash_pattern.is_empty() {
+                rules.push(CSSRule::StrokeDashArray(self.dash_pattern.clone()));
+            }
+        }
+
+        if let Some(opacity) = self.opacity {
+            rules.push(CSSRule::Opacity(opacity));
+        }
+
+        InlineStyle(rules)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::plugins::color_theme::SELECT_COLOR;
+
+    #[test]
+    fn should_create_stroke_inline_style() {
+        let style_override = StyleOverride {
+            color: Some(Color::RED),
+            width: Some(3.0),
+            dash_pattern: vec![4.0, 2.0],
+            opacity: Some(0.5),
+        };
+
+        let result = style_override.to_inline_style(false).to_string();
+        let expected = r#"style="stroke: rgb(255, 0, 0); stroke-width: 3; stroke-dasharray: 4 2; opacity: 0.5;""#;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_ignore_stroke_rules_for_fill_inline_style() {
+        let style_override = StyleOverride {
+            color: Some(Color::RED),
+            width: Some(3.0),
+            dash_pattern: vec![4.0, 2.0],
+            opacity: None,
+        };
+
+        let result = style_override.to_inline_style(true).to_string();
+        let expected = r#"style="fill: rgb(255, 0, 0);""#;
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn should_not_override_selection_color() {
+        let style_override = StyleOverride {
+            color: Some(Color::RED),
+            opacity: Some(0.5),
+            ..default()
+        };
+
+        assert_eq!(
+            style_override.apply_color(SELECT_COLOR, Color::BLUE),
+            Color::BLUE
+        );
+        assert_eq!(
+            style_override.apply_color(DRAW_COLOR, Color::BLUE),
+            Color::RED.with_a(0.5)
+        );
+    }
+}
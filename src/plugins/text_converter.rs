@@ -1,19 +1,27 @@
+pub mod alphabet;
 pub mod components;
+pub mod decoration;
+pub mod document;
+pub mod events;
+pub mod model;
+pub mod script;
 pub mod systems;
 
 use bevy::prelude::*;
-use components::NestingSettings;
-use itertools::Itertools;
-use lazy_static::lazy_static;
-use regex::{Regex, RegexBuilder};
-
-lazy_static! {
-    static ref VALID_LETTER: Regex = RegexBuilder::new(r"[cpwstg]h?|ng?|qu?|[aeioubdhfjklmrvyzx]")
-        .case_insensitive(true)
-        .build()
-        .unwrap();
-}
-
+use components::{ElementId, ElementIdGenerator, NestingSettings};
+pub use gallifreyan_core::alphabet::sanitize_text_input;
+pub use gallifreyan_core::layout::{
+    DecorationLayout, ReadingDirection, SentenceArrangement, SentenceFrame,
+};
+pub use gallifreyan_core::normalization::{normalize_sentences, NormalizationPolicy};
+
+/// Chained before [`CoreSet::Update`]:
+/// - `TextConverter` turns the last [`SetText`] into the entities of the sentence.
+/// - `PostTextConverter` arranges the sentences, applies models and sends [`events::TextConverted`] for the new entities.
+/// - `PostTextConverterFlush` applies the commands of `PostTextConverter`.
+///
+/// Selection and export events are sent in [`CoreSet::Update`] and
+/// [`events::GeometryChanged`] in [`CoreSet::PostUpdate`] after all of them.
 #[derive(SystemSet, Debug, Eq, PartialEq, Copy, Clone, Hash)]
 #[system_set(base)]
 pub enum TextConverterBaseSet {
@@ -27,7 +35,14 @@ pub struct TextConverterPlugin;
 impl Plugin for TextConverterPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<SetText>()
+            .register_type::<ElementId>()
             .insert_resource(NestingSettings::All)
+            .init_resource::<ElementIdGenerator>()
+            .init_resource::<NormalizationSettings>()
+            .init_resource::<SentenceArrangementSettings>()
+            .init_resource::<SentenceFrameSettings>()
+            .init_resource::<ReadingDirectionSettings>()
+            .init_resource::<DecorationLayoutSettings>()
             .configure_sets(
                 (
                     TextConverterBaseSet::TextConverter,
@@ -40,6 +55,8 @@ impl Plugin for TextConverterPlugin {
             .add_systems(
                 (
                     systems::sentence::convert_sentence,
+                    systems::sentence::apply_sentence_frame_settings,
+                    systems::sentence::apply_reading_direction_settings,
                     apply_system_buffers,
                     systems::word::convert_words,
                     apply_system_buffers,
@@ -50,89 +67,66 @@ impl Plugin for TextConverterPlugin {
                     systems::dot::convert_dots,
                     systems::line_slot::convert_line_slots,
                     apply_system_buffers,
+                    systems::element_id::assign_element_ids,
+                    apply_system_buffers,
                 )
                     .chain()
-                    .in_base_set(TextConverterBaseSet::TextConverter),
+                    .in_base_set(TextConverterBaseSet::TextConverter)
+                    .in_set(script::ScriptSystemSet::Sherman),
+            )
+            .add_system(
+                systems::element_id::reserve_element_ids
+                    .in_base_set(TextConverterBaseSet::TextConverter)
+                    .before(script::ScriptSystemSet::Sherman)
+                    .before(script::ScriptSystemSet::Other),
+            )
+            .add_system(
+                systems::sentence::arrange_sentences
+                    .in_base_set(TextConverterBaseSet::PostTextConverter),
             )
             .add_system(
                 apply_system_buffers.in_base_set(TextConverterBaseSet::PostTextConverterFlush),
-            );
+            )
+            .add_plugin(alphabet::AlphabetPlugin)
+            .add_plugin(script::ScriptPlugin)
+            .add_plugin(model::ModelPlugin)
+            .add_plugin(decoration::DecorationPlugin)
+            .add_plugin(document::DocumentPlugin)
+            .add_plugin(events::ChangeEventsPlugin);
     }
 }
 
 pub struct SetText(pub String);
 
-pub fn split_word_to_chars(word: &str) -> impl Iterator<Item = &str> {
-    VALID_LETTER.find_iter(word).map(|matched| matched.as_str())
-}
+/// How the text input handles characters which aren't part of the alphabet.
+#[derive(Resource, Debug, Copy, Clone, Default, PartialEq, Eq, Deref, DerefMut)]
+pub struct NormalizationSettings(pub NormalizationPolicy);
+
+/// How the sentences of a text with multiple sentences are placed.
+#[derive(Resource, Debug, Copy, Clone, Default, PartialEq, Eq, Deref, DerefMut)]
+pub struct SentenceArrangementSettings(pub SentenceArrangement);
 
-pub fn sanitize_text_input(text: &str) -> String {
-    text.split_whitespace()
-        .map(split_word_to_chars)
-        .map(|mut word| word.join(""))
-        .filter(|word| !word.is_empty())
-        .join(" ")
+/// Whether sentences are drawn with a double, single or without circle around their words.
+#[derive(Resource, Debug, Copy, Clone, Default, PartialEq, Eq, Deref, DerefMut)]
+pub struct SentenceFrameSettings(pub SentenceFrame);
+
+/// Where words and letters start and in which direction they follow each other.
+#[derive(Resource, Debug, Copy, Clone, Default, PartialEq)]
+pub struct ReadingDirectionSettings {
+    pub reading_direction: ReadingDirection,
+    /// Draws a tick on the sentence circle at the start angle.
+    pub show_start_marker: bool,
 }
 
+/// How the dots and lines of letters without a
+/// [`components::DecorationLayoutOverride`] are placed.
+#[derive(Resource, Debug, Copy, Clone, Default, PartialEq, Deref, DerefMut)]
+pub struct DecorationLayoutSettings(pub DecorationLayout);
+
 #[cfg(test)]
 mod test {
     use super::*;
-
-    #[test]
-    fn should_split_lower_case_word() {
-        let result: Vec<&str> =
-            split_word_to_chars("aeioubjtthphwhghchkshydlrzcqgnvquhpwxfmsng").collect();
-        let expected = [
-            "a", "e", "i", "o", "u", "b", "j", "t", "th", "ph", "wh", "gh", "ch", "k", "sh", "y",
-            "d", "l", "r", "z", "c", "q", "g", "n", "v", "qu", "h", "p", "w", "x", "f", "m", "s",
-            "ng",
-        ];
-
-        assert_eq!(result, expected);
-    }
-
-    #[test]
-    fn should_split_upper_case_word() {
-        let result: Vec<&str> =
-            split_word_to_chars("AEIOUBJTTHPHWHGHCHKSHYDLRZCQGNVQUHPWXFMSNG").collect();
-        let expected = [
-            "A", "E", "I", "O", "U", "B", "J", "T", "TH", "PH", "WH", "GH", "CH", "K", "SH", "Y",
-            "D", "L", "R", "Z", "C", "Q", "G", "N", "V", "QU", "H", "P", "W", "X", "F", "M", "S",
-            "NG",
-        ];
-
-        assert_eq!(result, expected);
-    }
-
-    #[test]
-    fn should_split_mixed_case_double_letters() {
-        let result: Vec<&str> = split_word_to_chars("tHThpHPhwHWhgHGhcHChsHShqUQunGNg").collect();
-        let expected = [
-            "tH", "Th", "pH", "Ph", "wH", "Wh", "gH", "Gh", "cH", "Ch", "sH", "Sh", "qU", "Qu",
-            "nG", "Ng",
-        ];
-
-        assert_eq!(result, expected);
-    }
-
-    #[test]
-    fn should_filter_invalid_letters() {
-        let result: Vec<&str> =
-            split_word_to_chars("äöü+*~#'i#-_.:,;<>|@n€^°1!2²\"3§³4$5v%6&7/{a8([9)l]0=i}ßd?\\´`")
-                .collect();
-        let expected = ["i", "n", "v", "a", "l", "i", "d"];
-
-        assert_eq!(result, expected);
-    }
-
-    #[test]
-    fn should_sanitize_text_input() {
-        let result =
-            sanitize_text_input("äöü+*~#'i#-_.:,;<>|@n€^°1!2²\"3§³4$5v%6&7/{a8([9)l]0=i}ßd?\\´`");
-        let expected = "invalid";
-
-        assert_eq!(result, expected);
-    }
+    use itertools::Itertools;
 
     pub fn test_component_update<C: Component + Clone, F: Component>(
         text_before: &str,
@@ -0,0 +1,172 @@
+use super::components::{Text, Word};
+use bevy::prelude::*;
+use gallifreyan_core::alphabet::Alphabet;
+use std::sync::Arc;
+
+/// Alphabet files in this directory are loaded in addition to the builtin alphabets.
+#[cfg(not(target_arch = "wasm32"))]
+pub const ALPHABET_DIRECTORY: &str = "alphabets";
+
+pub struct AlphabetPlugin;
+
+impl Plugin for AlphabetPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AlphabetSettings>()
+            .add_system(apply_alphabet_settings);
+
+        #[cfg(not(target_arch = "wasm32"))]
+        app.add_startup_system(load_alphabet_directory);
+    }
+}
+
+/// The alphabets to choose from, the selected one is used to convert and draw the letters.
+#[derive(Resource)]
+pub struct AlphabetSettings {
+    pub alphabets: Vec<Arc<Alphabet>>,
+    pub selected: usize,
+}
+
+impl Default for AlphabetSettings {
+    fn default() -> Self {
+        Self {
+            alphabets: Alphabet::builtin(),
+            selected: 0,
+        }
+    }
+}
+
+impl AlphabetSettings {
+    pub fn current(&self) -> &Arc<Alphabet> {
+        &self.alphabets[self.selected]
+    }
+}
+
+/// Loads every `.ron` file of the directory and skips the invalid ones.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn load_alphabet_files(directory: &std::path::Path) -> Vec<Arc<Alphabet>> {
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return vec![];
+    };
+
+    let mut paths: Vec<_> = entries
+        .filter_map(|entry| entry.ok().map(|it| it.path()))
+        .filter(|path| path.extension() == Some("ron".as_ref()))
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .filter_map(|path| {
+            let alphabet = std::fs::read_to_string(&path)
+                .map_err(|error| error.to_string())
+                .and_then(|content| Alphabet::from_ron(&content));
+
+            match alphabet {
+                Ok(alphabet) => {
+                    info!("Loaded alphabet '{}' from {:?}", alphabet.name(), path);
+                    Some(Arc::new(alphabet))
+                }
+                Err(error) => {
+                    warn!("Failed to load alphabet {:?}: {}", path, error);
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn load_alphabet_directory(mut alphabet_settings: ResMut<AlphabetSettings>) {
+    let alphabets = load_alphabet_files(std::path::Path::new(ALPHABET_DIRECTORY));
+    alphabet_settings.alphabets.extend(alphabets);
+}
+
+/// Converts the letters of all words again as graphemes, placements and decorations might have
+/// changed. The existing letters are updated, so they keep their ids and overrides.
+fn apply_alphabet_settings(
+    alphabet_settings: Res<AlphabetSettings>,
+    mut word_query: Query<&mut Text, With<Word>>,
+    mut last_selected: Local<Option<Arc<Alphabet>>>,
+) {
+    if !alphabet_settings.is_changed() {
+        return;
+    }
+
+    let alphabet = alphabet_settings.current();
+
+    if !matches!(last_selected.replace(Arc::clone(alphabet)), Some(last) if !Arc::ptr_eq(&last, alphabet))
+    {
+        return;
+    }
+
+    debug!("Set alphabet: {}", alphabet.name());
+
+    for mut text in word_query.iter_mut() {
+        text.set_changed();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::plugins::text_converter::components::{
+        CircleChildren, ElementId, Letter, NestedVocal,
+    };
+    use crate::plugins::text_converter::{SetText, TextConverterPlugin};
+    use gallifreyan_core::alphabet::{self, Consonant, Vocal};
+
+    #[test]
+    fn should_update_letters_when_alphabet_changes() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText("ca".to_string()));
+        app.update();
+
+        let mut letter_query = app
+            .world
+            .query_filtered::<(Entity, &ElementId, &Letter, &CircleChildren), Without<NestedVocal>>(
+            );
+        let (entity, element_id, _, dots) = letter_query.single(&app.world);
+        let (entity, element_id) = (entity, *element_id);
+        assert_eq!(dots.len(), 4);
+
+        app.world.resource_mut::<AlphabetSettings>().selected = 1;
+        app.update();
+        app.update();
+
+        let (new_entity, new_element_id, letter, dots) = letter_query.single(&app.world);
+
+        assert_eq!(new_entity, entity);
+        assert_eq!(*new_element_id, element_id);
+        assert_eq!(
+            **letter,
+            alphabet::Letter::ConsonantWithVocal {
+                consonant: Consonant::K,
+                vocal: Vocal::A
+            }
+        );
+        assert_eq!(dots.len(), 2);
+    }
+
+    #[test]
+    fn should_load_alphabet_files() {
+        let directory = std::env::temp_dir().join("gallifreyan_alphabet_files");
+        std::fs::create_dir_all(&directory).unwrap();
+        std::fs::write(
+            directory.join("a.ron"),
+            gallifreyan_core::alphabet::SHERMAN_PHONETIC,
+        )
+        .unwrap();
+        std::fs::write(directory.join("b.ron"), "(name: \"Invalid\")").unwrap();
+        std::fs::write(directory.join("c.txt"), "").unwrap();
+
+        let alphabets = load_alphabet_files(&directory);
+
+        assert_eq!(alphabets.len(), 1);
+        assert_eq!(alphabets[0].name(), "Sherman (C and Q as K)");
+        assert!(load_alphabet_files(&directory.join("missing")).is_empty());
+    }
+}
@@ -1,19 +1,24 @@
+mod custom_decoration;
 mod dot;
 mod letter;
 mod line_slot;
+mod nested_vocal;
 mod sentence;
 mod word;
 
+pub use custom_decoration::*;
 pub use dot::*;
+pub use gallifreyan_core::layout::{AnglePlacement, OUTER_CIRCLE_SIZE, SVG_SIZE};
 pub use letter::*;
 pub use line_slot::*;
+pub use nested_vocal::*;
 pub use sentence::*;
 pub use word::*;
 
-use crate::math::angle::Degree;
 use bevy::prelude::*;
-
-pub const SVG_SIZE: f32 = 1000.0;
+use gallifreyan_core::layout;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 #[derive(Default, Component, Deref, DerefMut)]
 pub struct CircleChildren(pub Vec<Entity>);
@@ -27,21 +32,43 @@ pub struct Text(pub String);
 #[derive(Debug, Default, Copy, Clone, PartialEq, PartialOrd, Component, Deref, DerefMut)]
 pub struct Radius(pub f32);
 
-#[derive(Debug, Default, Copy, Clone, PartialEq, Component)]
-pub struct PositionData {
-    pub angle: Degree,
-    pub distance: f32,
-    pub angle_placement: AnglePlacement,
+#[derive(Debug, Default, Copy, Clone, PartialEq, Component, Deref, DerefMut)]
+pub struct PositionData(pub layout::PositionData);
+
+/// Identifies an element across reconversions, saves and imports unlike its [`Entity`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Component, Reflect)]
+#[reflect(Component)]
+pub struct ElementId(pub u64);
+
+/// Written as `e<number>` so that it's a valid id attribute in svgs.
+impl Display for ElementId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "e{}", self.0)
+    }
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Eq)]
-pub enum AnglePlacement {
-    Absolute,
-    Relative,
+impl FromStr for ElementId {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        text.strip_prefix('e')
+            .and_then(|number| number.parse().ok())
+            .map(ElementId)
+            .ok_or_else(|| format!("'{}' is not a valid element id!", text))
+    }
 }
 
-impl Default for AnglePlacement {
-    fn default() -> Self {
-        Self::Absolute
+#[derive(Debug, Default, Resource)]
+pub struct ElementIdGenerator(u64);
+
+impl ElementIdGenerator {
+    pub fn generate(&mut self) -> ElementId {
+        self.0 += 1;
+        ElementId(self.0)
+    }
+
+    /// Makes sure that an id which was loaded from a file won't be generated again.
+    pub fn reserve(&mut self, id: ElementId) {
+        self.0 = self.0.max(id.0);
     }
 }
@@ -0,0 +1,35 @@
+use bevy::prelude::*;
+
+/// Dots, lines and circles which are added to a letter by hand in addition to the ones of its
+/// consonant or vocal. They are kept when the text is converted again.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Component, Reflect)]
+#[reflect(Component)]
+pub enum CustomDecoration {
+    #[default]
+    Dot,
+    Line,
+    Circle,
+}
+
+impl CustomDecoration {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CustomDecoration::Dot => "dot",
+            CustomDecoration::Line => "line",
+            CustomDecoration::Circle => "circle",
+        }
+    }
+}
+
+impl TryFrom<&str> for CustomDecoration {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "dot" => Ok(CustomDecoration::Dot),
+            "line" => Ok(CustomDecoration::Line),
+            "circle" => Ok(CustomDecoration::Circle),
+            _ => Err(format!("'{}' is not a valid custom decoration!", value)),
+        }
+    }
+}
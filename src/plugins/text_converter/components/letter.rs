@@ -1,99 +1,25 @@
-mod consonant;
-mod vocal;
-
 use super::{CircleChildren, LineSlotChildren, PositionData, Radius, Text};
 use bevy::prelude::*;
 use bevy::utils::HashSet;
-pub use consonant::*;
-pub use vocal::*;
-
-#[derive(Debug, Copy, Clone, PartialEq, Eq, Component)]
-pub enum Letter {
-    Vocal(Vocal),
-    Consonant(Consonant),
-    ConsonantWithVocal { consonant: Consonant, vocal: Vocal },
-}
-
-impl Default for Letter {
-    fn default() -> Self {
-        Self::Consonant(Consonant::B)
-    }
-}
-
-impl Letter {
-    pub fn is_cutting(&self) -> bool {
-        match self {
-            Self::Consonant(consonant) | Self::ConsonantWithVocal { consonant, .. } => {
-                match ConsonantPlacement::from(*consonant) {
-                    ConsonantPlacement::DeepCut | ConsonantPlacement::ShallowCut => true,
-                    ConsonantPlacement::OnLine | ConsonantPlacement::Inside => false,
-                }
-            }
-            Self::Vocal(_) => false,
-        }
-    }
-
-    pub fn dots(&self) -> usize {
-        match self {
-            Letter::Vocal(vocal) => VocalDecoration::from(*vocal).dots(),
-            Letter::Consonant(consonant) | Letter::ConsonantWithVocal { consonant, .. } => {
-                ConsonantDecoration::from(*consonant).dots()
-            }
-        }
-    }
-
-    pub fn lines(&self) -> usize {
-        match self {
-            Letter::Vocal(vocal) => VocalDecoration::from(*vocal).lines(),
-            Letter::Consonant(consonant) | Letter::ConsonantWithVocal { consonant, .. } => {
-                ConsonantDecoration::from(*consonant).lines()
-            }
-        }
-    }
+use gallifreyan_core::alphabet;
+use gallifreyan_core::alphabet::Alphabet;
+pub use gallifreyan_core::alphabet::{
+    Consonant, ConsonantDecoration, ConsonantPlacement, Nesting, Vocal, VocalDecoration,
+    VocalPlacement,
+};
+use gallifreyan_core::layout::{DecorationLayout, ReadingDirection};
 
-    pub fn radius(&self, word_radius: f32, number_of_letters: usize) -> f32 {
-        match self {
-            Letter::Vocal(vocal) => vocal.radius(word_radius, number_of_letters),
-            Letter::Consonant(consonant) | Letter::ConsonantWithVocal { consonant, .. } => {
-                consonant.radius(word_radius, number_of_letters)
-            }
-        }
-    }
-
-    pub fn position_data(
-        &self,
-        word_radius: f32,
-        number_of_letters: usize,
-        index: usize,
-    ) -> PositionData {
-        match self {
-            Letter::Vocal(vocal) => vocal.position_data(word_radius, number_of_letters, index),
-            Letter::Consonant(consonant) | Letter::ConsonantWithVocal { consonant, .. } => {
-                consonant.position_data(word_radius, number_of_letters, index)
-            }
-        }
-    }
-}
-
-impl TryFrom<&str> for Letter {
-    type Error = String;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        Vocal::try_from(value)
-            .map(Self::Vocal)
-            .or_else(|_| Consonant::try_from(value).map(Self::Consonant))
-            .map_err(|_| {
-                format!(
-                    "Cannot assign letter to '{}' as it is not a valid letter!",
-                    value
-                )
-            })
-    }
-}
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Component, Deref, DerefMut)]
+pub struct Letter(pub alphabet::Letter);
 
 #[derive(Debug, Copy, Clone, Default, Deref, DerefMut, Component)]
 pub struct NestedLetter(pub Option<Entity>);
 
+/// Replaces the [`crate::plugins::text_converter::DecorationLayoutSettings`] for the dots and
+/// lines of a single letter.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Deref, DerefMut, Component)]
+pub struct DecorationLayoutOverride(pub Option<DecorationLayout>);
+
 #[derive(Bundle)]
 pub struct LetterBundle {
     pub letter: Letter,
@@ -103,24 +29,34 @@ pub struct LetterBundle {
     pub dots: CircleChildren,
     pub line_slots: LineSlotChildren,
     pub nested_letter: NestedLetter,
+    pub decoration_layout: DecorationLayoutOverride,
 }
 
 impl LetterBundle {
     pub fn new(
         text: String,
-        letter: Letter,
+        letter: alphabet::Letter,
         word_radius: f32,
         number_of_letters: usize,
         index: usize,
+        alphabet: &Alphabet,
+        reading_direction: ReadingDirection,
     ) -> Self {
         Self {
-            letter,
+            letter: Letter(letter),
             text: Text(text),
             radius: Radius(letter.radius(word_radius, number_of_letters)),
-            position_data: letter.position_data(word_radius, number_of_letters, index),
+            position_data: PositionData(letter.position_data(
+                alphabet,
+                reading_direction,
+                word_radius,
+                number_of_letters,
+                index,
+            )),
             dots: Default::default(),
             line_slots: Default::default(),
             nested_letter: NestedLetter::default(),
+            decoration_layout: DecorationLayoutOverride::default(),
         }
     }
 }
@@ -132,8 +68,8 @@ pub enum NestingSettings {
     Custom(HashSet<(Consonant, Vocal)>),
 }
 
-impl NestingSettings {
-    pub fn can_nest(&self, consonant: Consonant, vocal: Vocal) -> bool {
+impl Nesting for NestingSettings {
+    fn can_nest(&self, consonant: Consonant, vocal: Vocal) -> bool {
         match self {
             NestingSettings::None => false,
             NestingSettings::All => true,
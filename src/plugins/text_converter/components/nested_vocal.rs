@@ -0,0 +1,70 @@
+use super::{
+    ConsonantPlacement, DecorationLayoutOverride, Letter, LetterBundle, NestedLetter, PositionData,
+    Radius, Text, Vocal,
+};
+use bevy::prelude::*;
+use gallifreyan_core::alphabet::Alphabet;
+use gallifreyan_core::{alphabet, model};
+
+#[derive(Debug, Copy, Clone, Default, Component)]
+pub struct NestedVocal;
+
+#[derive(Copy, Clone, Default, Component)]
+pub struct NestedVocalPositionCorrection;
+
+#[derive(Bundle)]
+pub struct NestedVocalPositionCorrectionBundle {
+    pub nested_vocal_position_correction: NestedVocalPositionCorrection,
+    pub spatial_bundle: SpatialBundle,
+    pub position_data: PositionData,
+}
+
+impl NestedVocalPositionCorrectionBundle {
+    pub fn new(consonant_distance: f32) -> Self {
+        Self {
+            nested_vocal_position_correction: NestedVocalPositionCorrection,
+            spatial_bundle: SpatialBundle::INHERITED_IDENTITY,
+            position_data: PositionData(model::NestedVocal::position_correction(
+                consonant_distance,
+            )),
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct NestedVocalBundle {
+    pub letter_bundle: LetterBundle,
+    pub nested_vocal: NestedVocal,
+}
+
+impl NestedVocalBundle {
+    pub fn new(
+        text: String,
+        vocal: Vocal,
+        consonant_placement: ConsonantPlacement,
+        consonant_radius: f32,
+        consonant_distance: f32,
+        word_radius: f32,
+        alphabet: &Alphabet,
+    ) -> Self {
+        Self {
+            letter_bundle: LetterBundle {
+                text: Text(text),
+                letter: Letter(alphabet::Letter::Vocal(vocal)),
+                radius: Radius(vocal.nested_radius(consonant_radius)),
+                position_data: PositionData(vocal.nested_position_data(
+                    alphabet,
+                    consonant_placement,
+                    consonant_radius,
+                    consonant_distance,
+                    word_radius,
+                )),
+                dots: Default::default(),
+                line_slots: Default::default(),
+                nested_letter: NestedLetter::default(),
+                decoration_layout: DecorationLayoutOverride::default(),
+            },
+            nested_vocal: NestedVocal,
+        }
+    }
+}
@@ -1,27 +1,20 @@
-use super::{
-    AnglePlacement, CircleChildren, LineSlotChildren, PositionData, Radius, Text, SVG_SIZE,
-};
-use crate::math::angle::Degree;
+use super::{CircleChildren, LineSlotChildren, PositionData, Radius, Text};
 use bevy::prelude::*;
-
-pub const OUTER_CIRCLE_SIZE: f32 = 10.0;
+use gallifreyan_core::alphabet::SENTENCE_SEPARATOR;
+use gallifreyan_core::model;
 
 #[derive(Debug, Copy, Clone, Default, Component)]
 pub struct Sentence;
 
-impl Sentence {
-    pub fn radius() -> f32 {
-        SVG_SIZE * 0.9 / 2.0
-    }
+/// Order of a root sentence in a text with multiple sentences.
+#[derive(
+    Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Component, Deref, DerefMut,
+)]
+pub struct SentenceIndex(pub usize);
 
-    pub fn position_data() -> PositionData {
-        PositionData {
-            angle: Degree::new(0.0),
-            distance: 0.0,
-            angle_placement: AnglePlacement::Absolute,
-        }
-    }
-}
+/// The circle around all sentences of [`gallifreyan_core::layout::SentenceArrangement::Paragraph`].
+#[derive(Debug, Copy, Clone, Default, Component)]
+pub struct Paragraph;
 
 #[derive(Bundle)]
 pub struct SentenceBundle {
@@ -38,10 +31,30 @@ impl SentenceBundle {
         Self {
             sentence: Sentence,
             text: Text(sentence),
-            radius: Radius(Sentence::radius()),
-            position_data: Sentence::position_data(),
+            radius: Radius(model::Sentence::radius()),
+            position_data: PositionData(model::Sentence::position_data()),
             words: CircleChildren::default(),
             line_slots: LineSlotChildren::default(),
         }
     }
 }
+
+/// Sorts the root sentences by their [`SentenceIndex`], sentences without one come last.
+pub fn sort_sentences<T>(sentences: &mut [(Option<&SentenceIndex>, T)]) {
+    sentences.sort_by_key(|(index, _)| index.map_or(usize::MAX, |it| **it));
+}
+
+/// The text of all root sentences joined with [`SENTENCE_SEPARATOR`], like the text of
+/// [`crate::plugins::text_converter::SetText`].
+pub fn join_sentence_texts<'a>(
+    sentences: impl IntoIterator<Item = (Option<&'a SentenceIndex>, &'a Text)>,
+) -> String {
+    let mut sentences: Vec<_> = sentences.into_iter().collect();
+    sort_sentences(&mut sentences);
+
+    sentences
+        .into_iter()
+        .map(|(_, text)| text.as_str())
+        .collect::<Vec<_>>()
+        .join(&SENTENCE_SEPARATOR.to_string())
+}
@@ -1,32 +1,11 @@
-use super::{AnglePlacement, CircleChildren, LineSlotChildren, PositionData, Radius, Text};
-use crate::math::angle::Degree;
+use super::{CircleChildren, LineSlotChildren, PositionData, Radius, Text};
 use bevy::prelude::*;
+use gallifreyan_core::layout::ReadingDirection;
+use gallifreyan_core::model;
 
 #[derive(Debug, Copy, Clone, Default, Component)]
 pub struct Word;
 
-impl Word {
-    pub fn radius(sentence_radius: f32, number_of_words: usize) -> f32 {
-        (sentence_radius * 0.75) / (1.0 + number_of_words as f32 / 2.0)
-    }
-
-    pub fn position_data(
-        sentence_radius: f32,
-        number_of_words: usize,
-        index: usize,
-    ) -> PositionData {
-        PositionData {
-            distance: if number_of_words > 1 {
-                sentence_radius - Self::radius(sentence_radius, number_of_words) * 1.5
-            } else {
-                0.0
-            },
-            angle: Degree::new(index as f32 * (360.0 / number_of_words as f32)),
-            angle_placement: AnglePlacement::Absolute,
-        }
-    }
-}
-
 #[derive(Bundle)]
 pub struct WordBundle {
     pub word: Word,
@@ -38,12 +17,23 @@ pub struct WordBundle {
 }
 
 impl WordBundle {
-    pub fn new(word: String, sentence_radius: f32, number_of_words: usize, index: usize) -> Self {
+    pub fn new(
+        word: String,
+        reading_direction: ReadingDirection,
+        sentence_radius: f32,
+        number_of_words: usize,
+        index: usize,
+    ) -> Self {
         Self {
             word: Word,
             text: Text(word),
-            radius: Radius(Word::radius(sentence_radius, number_of_words)),
-            position_data: Word::position_data(sentence_radius, number_of_words, index),
+            radius: Radius(model::Word::radius(sentence_radius, number_of_words)),
+            position_data: PositionData(model::Word::position_data(
+                reading_direction,
+                sentence_radius,
+                number_of_words,
+                index,
+            )),
             letters: CircleChildren::default(),
             line_slots: LineSlotChildren::default(),
         }
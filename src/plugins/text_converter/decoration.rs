@@ -0,0 +1,370 @@
+use super::components::{
+    CircleChildren, CustomDecoration, DecorationLayoutOverride, Dot, DotBundle, Letter,
+    LineSlotBundle, LineSlotChildren, PositionData, Radius,
+};
+use super::{DecorationLayoutSettings, TextConverterBaseSet};
+use bevy::prelude::*;
+use gallifreyan_core::layout::{self, DecorationSpace};
+
+pub struct DecorationPlugin;
+
+impl Plugin for DecorationPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<CustomDecoration>()
+            .add_event::<AddDecoration>()
+            .add_event::<RemoveDecoration>()
+            .add_systems(
+                (
+                    handle_add_decoration_events,
+                    handle_remove_decoration_events,
+                    update_custom_decorations,
+                )
+                    .in_base_set(TextConverterBaseSet::PostTextConverter),
+            );
+    }
+}
+
+/// Adds a [`CustomDecoration`] to a letter. Without a position it's placed like a single
+/// decoration of the letter, otherwise at the position relative to the letter. Lines are always
+/// placed on the letter line.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct AddDecoration {
+    pub letter: Entity,
+    pub decoration: CustomDecoration,
+    pub position: Option<Vec2>,
+}
+
+/// Removes a [`CustomDecoration`], the decorations of the letter itself can't be removed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct RemoveDecoration(pub Entity);
+
+/// Circles are drawn as outline and are larger than dots.
+const CIRCLE_SIZE: f32 = 2.0;
+
+fn handle_add_decoration_events(
+    mut commands: Commands,
+    mut events: EventReader<AddDecoration>,
+    decoration_layout_settings: Res<DecorationLayoutSettings>,
+    mut letter_query: Query<
+        (
+            &Radius,
+            &DecorationLayoutOverride,
+            &mut CircleChildren,
+            &mut LineSlotChildren,
+        ),
+        With<Letter>,
+    >,
+) {
+    for AddDecoration {
+        letter,
+        decoration,
+        position,
+    } in events.iter()
+    {
+        let Ok((radius, layout_override, mut dots, mut line_slots)) = letter_query.get_mut(*letter)
+        else {
+            warn!("Can't add decoration to {:?}, it's not a letter", letter);
+            continue;
+        };
+
+        let layout = layout_override.unwrap_or(**decoration_layout_settings);
+        let space = DecorationSpace::default();
+
+        debug!("Add {:?} to letter {:?}", decoration, letter);
+
+        match decoration {
+            CustomDecoration::Dot | CustomDecoration::Circle => {
+                let size = if *decoration == CustomDecoration::Circle {
+                    CIRCLE_SIZE
+                } else {
+                    1.0
+                };
+
+                let position_data = position
+                    .map(layout::PositionData::from_translation)
+                    .unwrap_or_else(|| layout.dot_position_data(**radius, 1, 0, &space));
+
+                let dot_entity = commands
+                    .spawn((
+                        DotBundle {
+                            dot: Dot,
+                            radius: Radius(layout.dot_radius(**radius) * size),
+                            position_data: PositionData(position_data),
+                        },
+                        *decoration,
+                    ))
+                    .id();
+
+                commands.entity(*letter).add_child(dot_entity);
+                dots.push(dot_entity);
+            }
+            CustomDecoration::Line => {
+                let position_data = match *position {
+                    Some(translation) => layout::PositionData {
+                        distance: **radius,
+                        ..layout::PositionData::from_translation(translation)
+                    },
+                    None => layout.line_slot_position_data(**radius, 1, 0, false, &space),
+                };
+
+                let line_slot_entity = commands
+                    .spawn((
+                        LineSlotBundle {
+                            line_slot: Default::default(),
+                            position_data: PositionData(position_data),
+                        },
+                        *decoration,
+                    ))
+                    .id();
+
+                commands.entity(*letter).add_child(line_slot_entity);
+                line_slots.push(line_slot_entity);
+            }
+        }
+    }
+}
+
+fn handle_remove_decoration_events(
+    mut commands: Commands,
+    mut events: EventReader<RemoveDecoration>,
+    decoration_query: Query<&Parent, With<CustomDecoration>>,
+    mut letter_query: Query<(&mut CircleChildren, &mut LineSlotChildren), With<Letter>>,
+) {
+    for RemoveDecoration(entity) in events.iter() {
+        let Ok(parent) = decoration_query.get(*entity) else {
+            warn!("Can't remove {:?}, it's not a custom decoration", entity);
+            continue;
+        };
+
+        debug!("Remove custom decoration {:?}", entity);
+
+        if let Ok((mut dots, mut line_slots)) = letter_query.get_mut(parent.get()) {
+            dots.retain(|dot| dot != entity);
+            line_slots.retain(|line_slot| line_slot != entity);
+        }
+
+        commands.entity(*entity).despawn_recursive();
+    }
+}
+
+/// Keeps the size of custom dots and circles and the distance of custom lines in line with the
+/// radius of their letter.
+#[allow(clippy::type_complexity)]
+fn update_custom_decorations(
+    decoration_layout_settings: Res<DecorationLayoutSettings>,
+    letter_query: Query<
+        (
+            &Radius,
+            &DecorationLayoutOverride,
+            &CircleChildren,
+            &LineSlotChildren,
+        ),
+        (With<Letter>, Changed<Radius>),
+    >,
+    mut decoration_query: Query<
+        (&CustomDecoration, Option<&mut Radius>, &mut PositionData),
+        Without<Letter>,
+    >,
+) {
+    for (radius, layout_override, dots, line_slots) in letter_query.iter() {
+        let layout = layout_override.unwrap_or(**decoration_layout_settings);
+
+        let mut decorations = decoration_query.iter_many_mut(dots.iter().chain(line_slots.iter()));
+
+        while let Some((decoration, decoration_radius, mut position_data)) =
+            decorations.fetch_next()
+        {
+            match (decoration, decoration_radius) {
+                (CustomDecoration::Line, _) => {
+                    if position_data.distance != **radius {
+                        position_data.distance = **radius;
+                    }
+                }
+                (_, Some(mut decoration_radius)) => {
+                    let size = if *decoration == CustomDecoration::Circle {
+                        CIRCLE_SIZE
+                    } else {
+                        1.0
+                    };
+                    let new_radius = layout.dot_radius(**radius) * size;
+
+                    if **decoration_radius != new_radius {
+                        debug!("Update custom decoration radius: {}", new_radius);
+                        **decoration_radius = new_radius;
+                    }
+                }
+                (_, None) => {}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::angle::Angle;
+    use crate::plugins::text_converter::components::LineSlot;
+    use crate::plugins::text_converter::{SetText, TextConverterPlugin};
+
+    fn set_text(app: &mut App, text: &str) {
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText(text.to_string()));
+
+        app.update();
+    }
+
+    fn letter(app: &mut App) -> Entity {
+        app.world
+            .query_filtered::<Entity, With<Letter>>()
+            .single(&app.world)
+    }
+
+    #[test]
+    fn should_keep_custom_decorations_when_text_changes() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        set_text(&mut app, "b");
+
+        let letter = letter(&mut app);
+
+        for decoration in [CustomDecoration::Dot, CustomDecoration::Line] {
+            app.world
+                .resource_mut::<Events<AddDecoration>>()
+                .send(AddDecoration {
+                    letter,
+                    decoration,
+                    position: None,
+                });
+        }
+
+        app.update();
+
+        set_text(&mut app, "d");
+
+        let dots = app.world.get::<CircleChildren>(letter).unwrap().to_vec();
+        let line_slots = app.world.get::<LineSlotChildren>(letter).unwrap().to_vec();
+
+        assert_eq!(dots.len(), 4);
+        assert_eq!(line_slots.len(), 1);
+        assert!(app.world.get::<CustomDecoration>(dots[3]).is_some());
+        assert!(app.world.get::<LineSlot>(line_slots[0]).is_some());
+
+        set_text(&mut app, "b");
+
+        let dots = app.world.get::<CircleChildren>(letter).unwrap().to_vec();
+
+        assert_eq!(dots.len(), 1);
+        assert_eq!(
+            app.world.get::<CustomDecoration>(dots[0]),
+            Some(&CustomDecoration::Dot)
+        );
+    }
+
+    #[test]
+    fn should_place_custom_decoration_at_position() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        set_text(&mut app, "b");
+
+        let letter = letter(&mut app);
+
+        app.world
+            .resource_mut::<Events<AddDecoration>>()
+            .send(AddDecoration {
+                letter,
+                decoration: CustomDecoration::Circle,
+                position: Some(Vec2::new(10.0, 0.0)),
+            });
+
+        app.update();
+
+        let dot = app.world.get::<CircleChildren>(letter).unwrap()[0];
+        let position_data = app.world.get::<PositionData>(dot).unwrap();
+        let letter_radius = **app.world.get::<Radius>(letter).unwrap();
+
+        assert_eq!(position_data.distance, 10.0);
+        assert_eq!(position_data.angle.inner(), 90.0);
+        assert_eq!(
+            **app.world.get::<Radius>(dot).unwrap(),
+            letter_radius * 0.1 * CIRCLE_SIZE
+        );
+    }
+
+    #[test]
+    fn should_only_remove_custom_decorations() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        set_text(&mut app, "d");
+
+        let letter = letter(&mut app);
+
+        app.world
+            .resource_mut::<Events<AddDecoration>>()
+            .send(AddDecoration {
+                letter,
+                decoration: CustomDecoration::Dot,
+                position: None,
+            });
+
+        app.update();
+
+        let dots = app.world.get::<CircleChildren>(letter).unwrap().to_vec();
+
+        for dot in [dots[0], dots[3]] {
+            app.world
+                .resource_mut::<Events<RemoveDecoration>>()
+                .send(RemoveDecoration(dot));
+        }
+
+        app.update();
+
+        assert_eq!(
+            app.world.get::<CircleChildren>(letter).unwrap().to_vec(),
+            dots[..3]
+        );
+        assert!(app.world.get_entity(dots[3]).is_none());
+    }
+
+    #[test]
+    fn should_resize_custom_decorations_with_letter() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        set_text(&mut app, "b");
+
+        let letter = letter(&mut app);
+
+        for decoration in [CustomDecoration::Circle, CustomDecoration::Line] {
+            app.world
+                .resource_mut::<Events<AddDecoration>>()
+                .send(AddDecoration {
+                    letter,
+                    decoration,
+                    position: None,
+                });
+        }
+
+        app.update();
+
+        **app.world.get_mut::<Radius>(letter).unwrap() *= 0.5;
+
+        app.update();
+
+        let letter_radius = **app.world.get::<Radius>(letter).unwrap();
+        let dot = app.world.get::<CircleChildren>(letter).unwrap()[0];
+        let line_slot = app.world.get::<LineSlotChildren>(letter).unwrap()[0];
+
+        assert_eq!(
+            **app.world.get::<Radius>(dot).unwrap(),
+            letter_radius * 0.1 * CIRCLE_SIZE
+        );
+        assert_eq!(
+            app.world.get::<PositionData>(line_slot).unwrap().distance,
+            letter_radius
+        );
+    }
+}
@@ -0,0 +1,210 @@
+use super::script::ScriptSystemSettings;
+use super::{ReadingDirection, ReadingDirectionSettings, SentenceFrame, SentenceFrameSettings};
+use crate::math::angle::{Angle, Degree};
+use bevy::prelude::*;
+use gallifreyan_core::script::SHERMAN_ID;
+
+pub struct DocumentPlugin;
+
+impl Plugin for DocumentPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<DocumentOptions>()
+            .add_system(apply_document_options)
+            .add_system(update_document_options.after(apply_document_options));
+    }
+}
+
+/// The settings which belong to the document instead of the editor, saved on their own entity
+/// together with the sentences. Documents without it use the defaults.
+#[derive(Debug, Clone, PartialEq, Component, Reflect)]
+#[reflect(Component)]
+pub struct DocumentOptions {
+    /// See [`ReadingDirection::start_angle`].
+    pub start_angle: f32,
+    pub clockwise: bool,
+    /// Id of the script system the sentences are written in.
+    pub script_system: String,
+    /// See [`SentenceFrame::as_str`].
+    pub sentence_frame: String,
+}
+
+impl Default for DocumentOptions {
+    fn default() -> Self {
+        Self::new(
+            ReadingDirection::default(),
+            SHERMAN_ID,
+            SentenceFrame::default(),
+        )
+    }
+}
+
+impl DocumentOptions {
+    pub fn new(
+        reading_direction: ReadingDirection,
+        script_system: &str,
+        sentence_frame: SentenceFrame,
+    ) -> Self {
+        Self {
+            start_angle: reading_direction.start_angle.inner(),
+            clockwise: reading_direction.clockwise,
+            script_system: script_system.to_string(),
+            sentence_frame: sentence_frame.as_str().to_string(),
+        }
+    }
+
+    pub fn reading_direction(&self) -> ReadingDirection {
+        ReadingDirection {
+            start_angle: Degree::new(self.start_angle),
+            clockwise: self.clockwise,
+        }
+    }
+}
+
+/// Applies the options of a new or loaded document to the settings.
+fn apply_document_options(
+    document_query: Query<&DocumentOptions, Added<DocumentOptions>>,
+    mut reading_direction_settings: ResMut<ReadingDirectionSettings>,
+    mut script_system_settings: ResMut<ScriptSystemSettings>,
+    mut sentence_frame_settings: ResMut<SentenceFrameSettings>,
+) {
+    let Some(options) = document_query.iter().last() else {
+        return;
+    };
+
+    let reading_direction = options.reading_direction();
+
+    if reading_direction_settings.reading_direction != reading_direction {
+        debug!("Apply document reading direction: {:?}", reading_direction);
+        reading_direction_settings.reading_direction = reading_direction;
+    }
+
+    match script_system_settings
+        .script_systems
+        .iter()
+        .position(|system| system.id() == options.script_system)
+    {
+        Some(selected) if selected != script_system_settings.selected => {
+            debug!("Apply document script system: {}", options.script_system);
+            script_system_settings.selected = selected;
+        }
+        Some(_) => {}
+        None => warn!("'{}' is not a known script system!", options.script_system),
+    }
+
+    match SentenceFrame::try_from(options.sentence_frame.as_str()) {
+        Ok(sentence_frame) if sentence_frame != **sentence_frame_settings => {
+            debug!("Apply document sentence frame: {:?}", sentence_frame);
+            sentence_frame_settings.0 = sentence_frame;
+        }
+        Ok(_) => {}
+        Err(error) => warn!("{}", error),
+    }
+}
+
+/// Writes changed settings into the document, which is spawned with the first change.
+fn update_document_options(
+    mut commands: Commands,
+    reading_direction_settings: Res<ReadingDirectionSettings>,
+    script_system_settings: Res<ScriptSystemSettings>,
+    sentence_frame_settings: Res<SentenceFrameSettings>,
+    mut document_query: Query<&mut DocumentOptions>,
+) {
+    let is_changed = (reading_direction_settings.is_changed()
+        && !reading_direction_settings.is_added())
+        || (script_system_settings.is_changed() && !script_system_settings.is_added())
+        || (sentence_frame_settings.is_changed() && !sentence_frame_settings.is_added());
+
+    if !is_changed {
+        return;
+    }
+
+    let new_options = DocumentOptions::new(
+        reading_direction_settings.reading_direction,
+        script_system_settings.current().id(),
+        **sentence_frame_settings,
+    );
+
+    match document_query.get_single_mut() {
+        Ok(mut options) => {
+            if *options != new_options {
+                debug!("Update document options: {:?}", new_options);
+                *options = new_options;
+            }
+        }
+        Err(_) => {
+            debug!("Spawn document options: {:?}", new_options);
+            commands.spawn(new_options);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::plugins::text_converter::TextConverterPlugin;
+
+    fn document_options(app: &mut App) -> Vec<DocumentOptions> {
+        app.world
+            .query::<&DocumentOptions>()
+            .iter(&app.world)
+            .cloned()
+            .collect()
+    }
+
+    #[test]
+    fn should_keep_settings_with_document() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        app.update();
+
+        assert!(document_options(&mut app).is_empty());
+
+        let clockwise_from_top = ReadingDirection {
+            start_angle: Degree::new(180.0),
+            clockwise: true,
+        };
+        app.world
+            .resource_mut::<ReadingDirectionSettings>()
+            .reading_direction = clockwise_from_top;
+        app.world.resource_mut::<ScriptSystemSettings>().selected = 1;
+        app.world.resource_mut::<SentenceFrameSettings>().0 = SentenceFrame::None;
+
+        app.update();
+
+        assert_eq!(
+            document_options(&mut app),
+            [DocumentOptions::new(
+                clockwise_from_top,
+                "clockwork",
+                SentenceFrame::None
+            )]
+        );
+
+        let loaded = app.world.spawn(DocumentOptions::default()).id();
+        let existing = app
+            .world
+            .query_filtered::<Entity, With<DocumentOptions>>()
+            .iter(&app.world)
+            .find(|entity| *entity != loaded)
+            .unwrap();
+        app.world.despawn(existing);
+
+        app.update();
+
+        assert_eq!(
+            app.world
+                .resource::<ReadingDirectionSettings>()
+                .reading_direction,
+            ReadingDirection::default()
+        );
+        assert_eq!(
+            app.world.resource::<ScriptSystemSettings>().current().id(),
+            SHERMAN_ID
+        );
+        assert_eq!(
+            **app.world.resource::<SentenceFrameSettings>(),
+            SentenceFrame::default()
+        );
+    }
+}
@@ -0,0 +1,245 @@
+use super::components::{
+    CircleChildren, ElementId, LineSlotChildren, NestedLetter, PositionData, Radius, Sentence, Text,
+};
+use super::TextConverterBaseSet;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+pub struct ChangeEventsPlugin;
+
+impl Plugin for ChangeEventsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<TextConverted>()
+            .add_event::<GeometryChanged>()
+            .add_system(send_text_converted.in_base_set(TextConverterBaseSet::PostTextConverter))
+            .add_system(send_geometry_changed.in_base_set(CoreSet::PostUpdate));
+    }
+}
+
+/// Sent in [`TextConverterBaseSet::PostTextConverter`] once all entities of the new text of a
+/// sentence exist, so systems in [`CoreSet::Update`] see it in the same frame.
+///
+/// The tree is `None` when the sentence got removed by an empty text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextConverted {
+    pub text: String,
+    pub tree: Option<EntityTree>,
+}
+
+/// Entities of the sentence in the order of the text: words, letters, nested vocals, dots and
+/// line slots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EntityTree {
+    pub entity: Entity,
+    pub id: Option<ElementId>,
+    pub children: Vec<EntityTree>,
+}
+
+/// Sent in [`CoreSet::PostUpdate`] for every entity whose radius or position data differs from
+/// the last frame. This includes changes of the text converter and of dragged or edited entities,
+/// but not newly spawned ones.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct GeometryChanged {
+    pub entity: Entity,
+    pub old: Geometry,
+    pub new: Geometry,
+}
+
+/// Line slots and position corrections don't have a radius.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Geometry {
+    pub radius: Option<Radius>,
+    pub position_data: PositionData,
+}
+
+#[derive(SystemParam)]
+struct EntityTreeSystemParams<'w, 's> {
+    circle_children_query: Query<'w, 's, &'static CircleChildren>,
+    line_slot_children_query: Query<'w, 's, &'static LineSlotChildren>,
+    nested_letter_query: Query<'w, 's, &'static NestedLetter>,
+    element_id_query: Query<'w, 's, &'static ElementId>,
+}
+
+impl EntityTreeSystemParams<'_, '_> {
+    fn create_tree(&self, entity: Entity) -> EntityTree {
+        let nested = self
+            .nested_letter_query
+            .get(entity)
+            .ok()
+            .and_then(|nested_letter| **nested_letter);
+        let circles = self
+            .circle_children_query
+            .get(entity)
+            .into_iter()
+            .flat_map(|children| children.iter().copied());
+        let line_slots = self
+            .line_slot_children_query
+            .get(entity)
+            .into_iter()
+            .flat_map(|children| children.iter().copied());
+
+        EntityTree {
+            entity,
+            id: self.element_id_query.get(entity).ok().copied(),
+            children: nested
+                .into_iter()
+                .chain(circles)
+                .chain(line_slots)
+                .map(|child| self.create_tree(child))
+                .collect(),
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn send_text_converted(
+    mut events: EventWriter<TextConverted>,
+    sentence_query: Query<(Entity, &Text), (With<Sentence>, Changed<Text>)>,
+    mut removed_sentences: RemovedComponents<Sentence>,
+    entity_tree: EntityTreeSystemParams,
+) {
+    for _ in &mut removed_sentences {
+        debug!("Text converted: removed sentence");
+        events.send(TextConverted {
+            text: String::new(),
+            tree: None,
+        });
+    }
+
+    for (sentence_entity, text) in sentence_query.iter() {
+        debug!("Text converted: {}", **text);
+        events.send(TextConverted {
+            text: text.to_string(),
+            tree: Some(entity_tree.create_tree(sentence_entity)),
+        });
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn send_geometry_changed(
+    mut events: EventWriter<GeometryChanged>,
+    geometry_query: Query<
+        (Entity, Option<&Radius>, &PositionData),
+        Or<(Changed<Radius>, Changed<PositionData>)>,
+    >,
+    mut removed: RemovedComponents<PositionData>,
+    mut last_geometry: Local<HashMap<Entity, Geometry>>,
+) {
+    for entity in &mut removed {
+        last_geometry.remove(&entity);
+    }
+
+    for (entity, radius, position_data) in geometry_query.iter() {
+        let new = Geometry {
+            radius: radius.copied(),
+            position_data: *position_data,
+        };
+
+        match last_geometry.insert(entity, new) {
+            Some(old) if old != new => {
+                events.send(GeometryChanged { entity, old, new });
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::plugins::text_converter::components::{Dot, Letter, LineSlot, Word};
+    use crate::plugins::text_converter::{SetText, TextConverterPlugin};
+
+    fn set_text(app: &mut App, text: &str) -> Vec<TextConverted> {
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText(text.to_string()));
+
+        app.update();
+
+        app.world
+            .resource_mut::<Events<TextConverted>>()
+            .drain()
+            .collect()
+    }
+
+    fn count(tree: &EntityTree) -> usize {
+        1 + tree.children.iter().map(count).sum::<usize>()
+    }
+
+    #[test]
+    fn should_send_text_converted() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        let events = set_text(&mut app, "ba d");
+
+        let entities = app
+            .world
+            .query_filtered::<(), Or<(With<Sentence>, With<Word>, With<Dot>, With<LineSlot>)>>()
+            .iter(&app.world)
+            .count()
+            + app.world.query::<&Letter>().iter(&app.world).count();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].text, "ba d");
+
+        let tree = events[0].tree.as_ref().unwrap();
+        assert_eq!(tree.children.len(), 2);
+        assert!(tree.id.is_some());
+        assert_eq!(count(tree), entities);
+
+        let events = set_text(&mut app, "");
+
+        assert_eq!(
+            events,
+            vec![TextConverted {
+                text: String::new(),
+                tree: None
+            }]
+        );
+    }
+
+    #[test]
+    fn should_send_geometry_changed() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        set_text(&mut app, "ab");
+
+        let new_events = |app: &mut App| -> Vec<GeometryChanged> {
+            app.world
+                .resource_mut::<Events<GeometryChanged>>()
+                .drain()
+                .collect()
+        };
+
+        assert!(new_events(&mut app).is_empty());
+
+        set_text(&mut app, "abc");
+
+        let events = new_events(&mut app);
+        let word = app
+            .world
+            .query_filtered::<Entity, With<Word>>()
+            .single(&app.world);
+
+        assert!(!events.is_empty());
+        assert!(events.iter().all(|event| event.old != event.new));
+        assert!(!events.iter().any(|event| event.entity == word));
+
+        let mut position_data = app.world.get_mut::<PositionData>(word).unwrap();
+        let old = *position_data;
+        position_data.distance = 10.0;
+
+        app.update();
+
+        let events = new_events(&mut app);
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].entity, word);
+        assert_eq!(events[0].old.position_data, old);
+        assert_eq!(events[0].new.position_data.distance, 10.0);
+    }
+}
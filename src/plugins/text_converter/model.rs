@@ -0,0 +1,348 @@
+use super::components::{
+    sort_sentences, CircleChildren, Dot, Letter, LineSlot, LineSlotChildren, NestedLetter,
+    NestedVocalPositionCorrection, PositionData, Radius, Sentence, SentenceIndex, Text, Word,
+};
+use super::systems::sentence::arrange_sentences;
+use super::{SetText, TextConverterBaseSet};
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use gallifreyan_core::alphabet::SENTENCE_SEPARATOR;
+use gallifreyan_core::{layout, model};
+
+pub struct ModelPlugin;
+
+impl Plugin for ModelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SetSentenceModels>()
+            .init_resource::<PendingSentenceModels>()
+            .add_system(handle_set_sentence_models_event)
+            .add_system(
+                apply_pending_sentence_models
+                    .in_base_set(TextConverterBaseSet::PostTextConverter)
+                    .after(arrange_sentences),
+            );
+    }
+}
+
+/// Recreates the entities of all root sentences from their models in reading order including
+/// their radii and positions.
+pub struct SetSentenceModels(pub Vec<model::Sentence>);
+
+#[derive(Default, Resource)]
+struct PendingSentenceModels(Option<Vec<model::Sentence>>);
+
+type TextCircleQuery<'w, 's, F> = Query<
+    'w,
+    's,
+    (
+        &'static Text,
+        &'static Radius,
+        &'static PositionData,
+        &'static CircleChildren,
+    ),
+    F,
+>;
+
+type RootSentenceQuery<'w, 's> = Query<
+    'w,
+    's,
+    (
+        Option<&'static SentenceIndex>,
+        &'static Text,
+        &'static Radius,
+        &'static PositionData,
+        &'static CircleChildren,
+    ),
+    (With<Sentence>, Without<Parent>),
+>;
+
+#[derive(SystemParam)]
+pub struct SentenceModelSystemParams<'w, 's> {
+    sentence_query: RootSentenceQuery<'w, 's>,
+    word_query: TextCircleQuery<'w, 's, With<Word>>,
+    letter_query: Query<
+        'w,
+        's,
+        (
+            &'static Text,
+            &'static Letter,
+            &'static Radius,
+            &'static PositionData,
+            &'static NestedLetter,
+            &'static CircleChildren,
+            &'static LineSlotChildren,
+        ),
+    >,
+    dot_query: Query<'w, 's, (&'static Radius, &'static PositionData), With<Dot>>,
+    line_slot_query: Query<'w, 's, &'static PositionData, With<LineSlot>>,
+    position_correction_query:
+        Query<'w, 's, &'static PositionData, With<NestedVocalPositionCorrection>>,
+    parent_query: Query<'w, 's, &'static Parent>,
+}
+
+impl SentenceModelSystemParams<'_, '_> {
+    /// Creates the models of all root sentences in reading order with all changes made to their
+    /// entities.
+    pub fn create_models(&self) -> Vec<model::Sentence> {
+        let mut sentences: Vec<_> = self
+            .sentence_query
+            .iter()
+            .map(|(index, text, radius, position_data, words)| {
+                (index, (text, radius, position_data, words))
+            })
+            .collect();
+        sort_sentences(&mut sentences);
+
+        sentences
+            .into_iter()
+            .map(|(_, (text, radius, position_data, words))| {
+                self.create_sentence(text, radius, position_data, words)
+            })
+            .collect()
+    }
+
+    fn create_sentence(
+        &self,
+        text: &Text,
+        radius: &Radius,
+        position_data: &PositionData,
+        words: &CircleChildren,
+    ) -> model::Sentence {
+        model::Sentence {
+            text: text.to_string(),
+            radius: **radius,
+            position_data: **position_data,
+            words: self
+                .word_query
+                .iter_many(words.iter())
+                .map(|(text, radius, position_data, letters)| model::Word {
+                    text: text.to_string(),
+                    radius: **radius,
+                    position_data: **position_data,
+                    letters: letters
+                        .iter()
+                        .filter_map(|letter| self.create_letter(*letter))
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    fn create_letter(&self, entity: Entity) -> Option<model::Letter> {
+        let (text, letter, radius, position_data, nested_letter, dots, line_slots) =
+            self.letter_query.get(entity).ok()?;
+
+        let nested = (**nested_letter).and_then(|nested_entity| {
+            let vocal = self.create_letter(nested_entity)?;
+            let position_correction = self
+                .parent_query
+                .get(nested_entity)
+                .ok()
+                .and_then(|parent| self.position_correction_query.get(parent.get()).ok())
+                .map(|position_correction| **position_correction);
+
+            Some(Box::new(model::NestedVocal {
+                position_correction,
+                vocal,
+            }))
+        });
+
+        Some(model::Letter {
+            text: text.to_string(),
+            letter: **letter,
+            radius: **radius,
+            position_data: **position_data,
+            nested,
+            dots: self
+                .dot_query
+                .iter_many(dots.iter())
+                .map(|(radius, position_data)| model::Dot {
+                    radius: **radius,
+                    position_data: **position_data,
+                })
+                .collect(),
+            line_slots: self
+                .line_slot_query
+                .iter_many(line_slots.iter())
+                .map(|position_data| model::LineSlot {
+                    position_data: **position_data,
+                })
+                .collect(),
+        })
+    }
+}
+
+fn handle_set_sentence_models_event(
+    mut events: EventReader<SetSentenceModels>,
+    mut set_text_events: EventWriter<SetText>,
+    mut pending_sentence_models: ResMut<PendingSentenceModels>,
+) {
+    if let Some(SetSentenceModels(sentences)) = events.iter().last() {
+        let text = sentences
+            .iter()
+            .map(|sentence| sentence.text.as_str())
+            .collect::<Vec<_>>()
+            .join(&SENTENCE_SEPARATOR.to_string());
+
+        info!("Set sentence models: {:?}", text);
+        set_text_events.send(SetText(text));
+        pending_sentence_models.0 = Some(sentences.clone());
+    }
+}
+
+type ModelQuery<'w, 's> = Query<'w, 's, (Option<&'static mut Radius>, &'static mut PositionData)>;
+
+#[derive(SystemParam)]
+struct ModelChildrenSystemParams<'w, 's> {
+    circle_children_query: Query<'w, 's, &'static CircleChildren>,
+    line_slot_children_query: Query<'w, 's, &'static LineSlotChildren>,
+    nested_letter_query: Query<'w, 's, &'static NestedLetter>,
+    position_correction_query: Query<'w, 's, Entity, With<NestedVocalPositionCorrection>>,
+    letter_query: Query<'w, 's, Entity, With<Letter>>,
+    dot_query: Query<'w, 's, Entity, With<Dot>>,
+    parent_query: Query<'w, 's, &'static Parent>,
+}
+
+impl ModelChildrenSystemParams<'_, '_> {
+    /// Dots and letters share the circle children, so they are filtered by their kind.
+    fn circle_children<F: bevy::ecs::query::ReadOnlyWorldQuery>(
+        &self,
+        entity: Entity,
+        filter: &Query<Entity, F>,
+    ) -> Vec<Entity> {
+        self.circle_children_query
+            .get(entity)
+            .map(|children| filter.iter_many(children.iter()).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Runs after the text converter created the entities of the new sentences.
+#[allow(clippy::type_complexity)]
+fn apply_pending_sentence_models(
+    mut pending_sentence_models: ResMut<PendingSentenceModels>,
+    sentence_query: Query<(Entity, Option<&SentenceIndex>), (With<Sentence>, Without<Parent>)>,
+    children: ModelChildrenSystemParams,
+    mut model_query: ModelQuery,
+) {
+    let Some(sentences) = pending_sentence_models.0.take() else {
+        return;
+    };
+
+    let mut sentence_entities: Vec<_> = sentence_query
+        .iter()
+        .map(|(entity, index)| (index, entity))
+        .collect();
+    sort_sentences(&mut sentence_entities);
+
+    if sentence_entities.len() != sentences.len() {
+        error!(
+            "Failed to apply sentence models: {} models for {} sentences",
+            sentences.len(),
+            sentence_entities.len()
+        );
+        return;
+    }
+
+    for (sentence, (_, sentence_entity)) in sentences.iter().zip(sentence_entities) {
+        apply(
+            &mut model_query,
+            sentence_entity,
+            Some(sentence.radius),
+            sentence.position_data,
+        );
+
+        let words = children
+            .circle_children_query
+            .get(sentence_entity)
+            .map(|words| words.to_vec())
+            .unwrap_or_default();
+
+        for (word, word_entity) in sentence.words.iter().zip(words) {
+            apply(
+                &mut model_query,
+                word_entity,
+                Some(word.radius),
+                word.position_data,
+            );
+
+            let letters = children.circle_children(word_entity, &children.letter_query);
+
+            for (letter, letter_entity) in word.letters.iter().zip(letters) {
+                apply_letter(letter, letter_entity, &children, &mut model_query);
+            }
+        }
+    }
+}
+
+fn apply_letter(
+    letter: &model::Letter,
+    entity: Entity,
+    children: &ModelChildrenSystemParams,
+    model_query: &mut ModelQuery,
+) {
+    apply(
+        model_query,
+        entity,
+        Some(letter.radius),
+        letter.position_data,
+    );
+
+    let dots = children.circle_children(entity, &children.dot_query);
+
+    for (dot, dot_entity) in letter.dots.iter().zip(dots) {
+        apply(model_query, dot_entity, Some(dot.radius), dot.position_data);
+    }
+
+    let line_slots = children
+        .line_slot_children_query
+        .get(entity)
+        .map(|line_slots| line_slots.to_vec())
+        .unwrap_or_default();
+
+    for (line_slot, line_slot_entity) in letter.line_slots.iter().zip(line_slots) {
+        apply(model_query, line_slot_entity, None, line_slot.position_data);
+    }
+
+    let nested_entity = children
+        .nested_letter_query
+        .get(entity)
+        .ok()
+        .and_then(|nested_letter| **nested_letter);
+
+    if let (Some(nested), Some(nested_entity)) = (&letter.nested, nested_entity) {
+        apply_letter(&nested.vocal, nested_entity, children, model_query);
+
+        let position_correction_entity = children
+            .parent_query
+            .get(nested_entity)
+            .ok()
+            .and_then(|parent| children.position_correction_query.get(parent.get()).ok());
+
+        if let (Some(position_correction), Some(position_correction_entity)) =
+            (nested.position_correction, position_correction_entity)
+        {
+            apply(
+                model_query,
+                position_correction_entity,
+                None,
+                position_correction,
+            );
+        }
+    }
+}
+
+fn apply(
+    model_query: &mut ModelQuery,
+    entity: Entity,
+    new_radius: Option<f32>,
+    new_position_data: layout::PositionData,
+) {
+    if let Ok((radius, mut position_data)) = model_query.get_mut(entity) {
+        if let (Some(mut radius), Some(new_radius)) = (radius, new_radius) {
+            **radius = new_radius;
+        }
+
+        **position_data = new_position_data;
+    }
+}
@@ -0,0 +1,478 @@
+use super::components::{
+    join_sentence_texts, sort_sentences, CircleChildren, LineSlotChildren, PositionData, Radius,
+    Sentence, SentenceIndex, Text,
+};
+use super::systems::element_id::assign_element_ids;
+use super::{ReadingDirectionSettings, SetText, TextConverterBaseSet};
+use crate::plugins::svg::{DrawStyle, SVGShape, StyleOverride};
+use bevy::ecs::schedule::common_conditions::not;
+use bevy::prelude::*;
+use gallifreyan_core::alphabet::SENTENCE_SEPARATOR;
+use gallifreyan_core::export::FILL_CLASS;
+use gallifreyan_core::model;
+use gallifreyan_core::script::{script_systems, ScriptElement, ScriptSystem, SHERMAN_ID};
+use std::sync::Arc;
+
+pub struct ScriptPlugin;
+
+impl Plugin for ScriptPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScriptSystemSettings>()
+            .register_type::<ScriptElementKind>()
+            .configure_set(ScriptSystemSet::Sherman.run_if(is_sherman_selected))
+            .configure_set(ScriptSystemSet::Other.run_if(not(is_sherman_selected)))
+            .add_systems(
+                (
+                    convert_script_sentence,
+                    apply_system_buffers,
+                    assign_element_ids,
+                    apply_system_buffers,
+                )
+                    .chain()
+                    .in_base_set(TextConverterBaseSet::TextConverter)
+                    .in_set(ScriptSystemSet::Other),
+            )
+            .add_systems(
+                (apply_script_system_settings, apply_system_buffers)
+                    .chain()
+                    .in_base_set(TextConverterBaseSet::TextConverter)
+                    .before(ScriptSystemSet::Sherman)
+                    .before(ScriptSystemSet::Other),
+            );
+    }
+}
+
+/// Sherman's sentences are made of editable entities, all other script systems are converted
+/// into a static tree of [`ScriptElementKind`] entities.
+#[derive(SystemSet, Debug, Eq, PartialEq, Copy, Clone, Hash)]
+pub enum ScriptSystemSet {
+    Sherman,
+    Other,
+}
+
+#[derive(Resource)]
+pub struct ScriptSystemSettings {
+    pub script_systems: Vec<Arc<dyn ScriptSystem>>,
+    pub selected: usize,
+}
+
+impl Default for ScriptSystemSettings {
+    fn default() -> Self {
+        Self {
+            script_systems: script_systems(),
+            selected: 0,
+        }
+    }
+}
+
+impl ScriptSystemSettings {
+    pub fn current(&self) -> &Arc<dyn ScriptSystem> {
+        &self.script_systems[self.selected]
+    }
+}
+
+/// The kind of the [`ScriptElement`] the entity was spawned for.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Component, Reflect, Deref)]
+#[reflect(Component)]
+pub struct ScriptElementKind(pub String);
+
+type RootSentenceQuery<'w, 's, 'a> = Query<
+    'w,
+    's,
+    (
+        Entity,
+        Option<&'a SentenceIndex>,
+        &'a Text,
+        &'a mut PositionData,
+    ),
+    (With<Sentence>, Without<Parent>),
+>;
+
+type ScriptElementQuery<'w, 's, 'a> = Query<'w, 's, (&'a ScriptElementKind, Option<&'a Children>)>;
+
+fn is_sherman_selected(script_system_settings: Res<ScriptSystemSettings>) -> bool {
+    script_system_settings.current().id() == SHERMAN_ID
+}
+
+/// Lays out the sentences of the text, or the existing ones again when the reading direction
+/// changed or they were converted by another script system. Existing elements are updated in
+/// place so that they keep their [`ElementId`](super::components::ElementId) and style.
+#[allow(clippy::type_complexity)]
+fn convert_script_sentence(
+    mut commands: Commands,
+    mut events: EventReader<SetText>,
+    script_system_settings: Res<ScriptSystemSettings>,
+    reading_direction_settings: Res<ReadingDirectionSettings>,
+    mut sentence_query: RootSentenceQuery,
+    unconverted_sentence_query: Query<
+        (),
+        (With<Sentence>, Without<Parent>, Without<ScriptElementKind>),
+    >,
+    element_query: ScriptElementQuery,
+) {
+    let is_reading_direction_changed =
+        reading_direction_settings.is_changed() && !reading_direction_settings.is_added();
+
+    let text = match events.iter().last() {
+        Some(SetText(text)) => text.clone(),
+        None if is_reading_direction_changed || !unconverted_sentence_query.is_empty() => {
+            join_sentence_texts(
+                sentence_query
+                    .iter()
+                    .map(|(_, index, text, _)| (index, text)),
+            )
+        }
+        None => return,
+    };
+
+    let script_system = script_system_settings.current();
+    let new_texts: Vec<&str> = text
+        .split(SENTENCE_SEPARATOR)
+        .filter(|it| !it.is_empty())
+        .collect();
+
+    let mut sentences: Vec<_> = sentence_query
+        .iter_mut()
+        .map(|(entity, index, _, position_data)| (index, (entity, position_data)))
+        .collect();
+    sort_sentences(&mut sentences);
+
+    let mut existing_sentences = sentences.into_iter();
+
+    for index in 0..new_texts.len().max(existing_sentences.len()) {
+        let existing_sentence = existing_sentences.next();
+        let sentence = match new_texts.get(index) {
+            Some(text) => {
+                match script_system.layout(text, reading_direction_settings.reading_direction) {
+                    Ok(sentence) => Some(sentence),
+                    Err(error) => {
+                        error!("{}", error);
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+
+        match (existing_sentence, sentence) {
+            (Some((sentence_index, (sentence_entity, mut position_data))), Some(sentence)) => {
+                debug!(
+                    "Update {} sentence: {}",
+                    script_system.name(),
+                    sentence.text
+                );
+
+                if !element_query.contains(sentence_entity) {
+                    // the shape of the sentence gets replaced which also resets its transform
+                    position_data.set_changed();
+                }
+
+                update_script_element(&mut commands, sentence_entity, &sentence, &element_query);
+
+                if sentence_index != Some(&SentenceIndex(index)) {
+                    commands
+                        .entity(sentence_entity)
+                        .insert(SentenceIndex(index));
+                }
+            }
+            (Some((_, (sentence_entity, _))), None) => {
+                debug!("Despawn {} sentence", script_system.name());
+                commands.entity(sentence_entity).despawn_recursive();
+            }
+            (None, Some(sentence)) => {
+                debug!("Spawn {} sentence: {}", script_system.name(), sentence.text);
+                let sentence_entity = spawn_script_element(&mut commands, &sentence);
+                commands
+                    .entity(sentence_entity)
+                    .insert((Sentence, SentenceIndex(index)));
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+fn draw_style(element: &ScriptElement) -> DrawStyle {
+    if element.class == FILL_CLASS {
+        DrawStyle::Fill
+    } else {
+        DrawStyle::Stroke
+    }
+}
+
+fn spawn_script_element(commands: &mut Commands, element: &ScriptElement) -> Entity {
+    let mut entity_commands = commands.spawn((
+        ScriptElementKind(element.kind.to_string()),
+        Text(element.text.clone()),
+        PositionData(element.position_data),
+        TransformBundle::from_transform(Transform::from_xyz(0.0, 0.0, 0.1)),
+    ));
+
+    if let Some(shape) = &element.shape {
+        entity_commands.insert((
+            SVGShape(shape.clone()),
+            draw_style(element),
+            StyleOverride::default(),
+        ));
+    }
+
+    let entity = entity_commands.id();
+
+    for child in &element.children {
+        let child_entity = spawn_script_element(commands, child);
+        commands.entity(entity).add_child(child_entity);
+    }
+
+    entity
+}
+
+/// Updates the entity of the element and its children, children of another kind are spawned
+/// again. The position of the entity itself is kept as sentences are arranged separately.
+fn update_script_element(
+    commands: &mut Commands,
+    entity: Entity,
+    element: &ScriptElement,
+    element_query: &ScriptElementQuery,
+) {
+    let mut entity_commands = commands.entity(entity);
+    entity_commands.insert((
+        ScriptElementKind(element.kind.to_string()),
+        Text(element.text.clone()),
+    ));
+
+    if let Some(shape) = &element.shape {
+        entity_commands.insert((SVGShape(shape.clone()), draw_style(element)));
+    }
+
+    let existing_children: Vec<Entity> = element_query
+        .get(entity)
+        .ok()
+        .and_then(|(_, children)| children)
+        .map(|children| {
+            children
+                .iter()
+                .copied()
+                .filter(|child| element_query.contains(*child))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    for index in 0..element.children.len().max(existing_children.len()) {
+        match (existing_children.get(index), element.children.get(index)) {
+            (Some(child_entity), Some(child))
+                if matches!(
+                    element_query.get(*child_entity),
+                    Ok((kind, _)) if **kind == child.kind
+                ) =>
+            {
+                update_script_element(commands, *child_entity, child, element_query);
+                commands
+                    .entity(*child_entity)
+                    .insert(PositionData(child.position_data));
+            }
+            (Some(child_entity), Some(child)) => {
+                commands.entity(*child_entity).despawn_recursive();
+                let new_child_entity = spawn_script_element(commands, child);
+                commands
+                    .entity(entity)
+                    .insert_children(index, &[new_child_entity]);
+            }
+            (Some(child_entity), None) => {
+                commands.entity(*child_entity).despawn_recursive();
+            }
+            (None, Some(child)) => {
+                let child_entity = spawn_script_element(commands, child);
+                commands.entity(entity).add_child(child_entity);
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+/// Converts the existing sentences with the new script system. The sentences themselves are
+/// kept with their id, position and style, only the elements inside of them are replaced.
+#[allow(clippy::type_complexity)]
+fn apply_script_system_settings(
+    mut commands: Commands,
+    script_system_settings: Res<ScriptSystemSettings>,
+    mut sentence_query: Query<(Entity, &mut Text), (With<Sentence>, Without<Parent>)>,
+    mut last_selected: Local<Option<&'static str>>,
+) {
+    if !script_system_settings.is_changed() {
+        return;
+    }
+
+    let id = script_system_settings.current().id();
+
+    if !matches!(last_selected.replace(id), Some(last) if last != id) {
+        return;
+    }
+
+    debug!("Set script system: {}", id);
+
+    for (sentence_entity, mut text) in sentence_query.iter_mut() {
+        let mut entity_commands = commands.entity(sentence_entity);
+        entity_commands.despawn_descendants();
+
+        if id == SHERMAN_ID {
+            entity_commands.remove::<ScriptElementKind>().insert((
+                Radius(model::Sentence::radius()),
+                CircleChildren::default(),
+                LineSlotChildren::default(),
+            ));
+            // converts the words again
+            text.set_changed();
+        } else {
+            // converted by convert_script_sentence as they have no ScriptElementKind
+            entity_commands.remove::<(Radius, CircleChildren, LineSlotChildren)>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::plugins::svg::export::SVGExportSystemParams;
+    use crate::plugins::text_converter::components::{ElementId, Word};
+    use crate::plugins::text_converter::TextConverterPlugin;
+    use std::sync::mpsc::sync_channel;
+
+    fn set_text(app: &mut App, text: &str) {
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText(text.to_string()));
+
+        app.update();
+    }
+
+    #[test]
+    fn should_convert_text_with_selected_script_system() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        set_text(&mut app, "an");
+
+        assert_eq!(app.world.query::<&Word>().iter(&app.world).count(), 1);
+
+        let mut script_system_settings = app.world.resource_mut::<ScriptSystemSettings>();
+        script_system_settings.selected = script_system_settings
+            .script_systems
+            .iter()
+            .position(|it| it.id() == "clockwork")
+            .unwrap();
+
+        // replaces the words of the sentence and converts it again
+        app.update();
+        app.update();
+
+        let kinds: Vec<String> = app
+            .world
+            .query::<&ScriptElementKind>()
+            .iter(&app.world)
+            .map(|kind| kind.to_string())
+            .collect();
+
+        // sentence, word, two letters with hands and the dot of n
+        assert_eq!(kinds.len(), 7);
+        assert_eq!(app.world.query::<&Word>().iter(&app.world).count(), 0);
+
+        set_text(&mut app, "");
+
+        assert_eq!(
+            app.world
+                .query::<&ScriptElementKind>()
+                .iter(&app.world)
+                .count(),
+            0
+        );
+    }
+
+    fn select_script_system(app: &mut App, id: &str) {
+        let mut script_system_settings = app.world.resource_mut::<ScriptSystemSettings>();
+        script_system_settings.selected = script_system_settings
+            .script_systems
+            .iter()
+            .position(|it| it.id() == id)
+            .unwrap();
+
+        app.update();
+        app.update();
+    }
+
+    fn script_element_ids(app: &mut App) -> Vec<(Entity, ElementId)> {
+        let mut ids: Vec<_> = app
+            .world
+            .query_filtered::<(Entity, &ElementId), With<ScriptElementKind>>()
+            .iter(&app.world)
+            .map(|(entity, id)| (entity, *id))
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn should_keep_elements_when_script_system_or_text_changes() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        set_text(&mut app, "an");
+
+        let mut sentence_query = app
+            .world
+            .query_filtered::<(Entity, &ElementId), (With<Sentence>, Without<Parent>)>();
+        let sentence = sentence_query.single(&app.world);
+        let sentence = (sentence.0, *sentence.1);
+
+        select_script_system(&mut app, "clockwork");
+
+        let ids = script_element_ids(&mut app);
+
+        assert_eq!(ids.len(), 7);
+        assert!(ids.contains(&sentence));
+
+        set_text(&mut app, "ao");
+
+        assert_eq!(script_element_ids(&mut app), ids);
+
+        select_script_system(&mut app, SHERMAN_ID);
+
+        let new_sentence = sentence_query.single(&app.world);
+
+        assert_eq!((new_sentence.0, *new_sentence.1), sentence);
+        assert_eq!(app.world.query::<&Word>().iter(&app.world).count(), 1);
+        assert_eq!(
+            app.world
+                .query::<&ScriptElementKind>()
+                .iter(&app.world)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn should_export_script_sentence() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin)
+            .insert_resource(ScriptSystemSettings {
+                script_systems: script_systems(),
+                selected: 1,
+            });
+
+        set_text(&mut app, "clock");
+
+        let (sender, receiver) = sync_channel::<String>(1);
+
+        app.add_system(move |svg_export: SVGExportSystemParams| {
+            let result = svg_export.create_svg().unwrap().to_string();
+            sender.send(result).unwrap();
+        });
+
+        app.update();
+
+        let result = receiver.recv().unwrap();
+
+        assert!(result.contains("<title>clock</title>"));
+        assert_eq!(result.matches("<line").count(), 10);
+        // only o is in the second half of the alphabet
+        assert_eq!(result.matches("class=\"fill\"").count(), 1);
+    }
+}
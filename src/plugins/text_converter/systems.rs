@@ -1,7 +1,61 @@
 pub mod dot;
+pub mod element_id;
 pub mod letter;
 pub mod line_slot;
 pub mod sentence;
 pub mod word;
 
 use super::components;
+use super::DecorationLayoutSettings;
+use bevy::ecs::query::QueryItem;
+use bevy::prelude::*;
+use components::{DecorationLayoutOverride, Dot, Letter, PositionData, Radius, Text, Word};
+use gallifreyan_core::alphabet::Alphabet;
+use gallifreyan_core::layout::{DecorationLayout, DecorationSpace};
+
+type LetterDecorationQuery = (
+    Entity,
+    &'static Letter,
+    Ref<'static, Text>,
+    Ref<'static, DecorationLayoutOverride>,
+    Ref<'static, Radius>,
+    Ref<'static, PositionData>,
+    Option<&'static Parent>,
+);
+
+type WordRadiusQuery<'w, 's> =
+    Query<'w, 's, &'static Radius, (With<Word>, Without<Letter>, Without<Dot>)>;
+
+/// The layout for the dots and lines of a letter or `None` if they don't need to be updated.
+fn letter_decoration_layout(
+    (_, letter, text, layout_override, radius, position_data, parent): &QueryItem<
+        LetterDecorationQuery,
+    >,
+    settings: &Res<DecorationLayoutSettings>,
+    word_query: &WordRadiusQuery,
+    alphabet: &Alphabet,
+) -> Option<(DecorationLayout, DecorationSpace)> {
+    let layout = layout_override.unwrap_or(***settings);
+
+    let is_changed = text.is_changed() || layout_override.is_changed() || settings.is_changed();
+    let is_moved = layout.auto_fit && (radius.is_changed() || position_data.is_changed());
+
+    if !is_changed && !is_moved {
+        return None;
+    }
+
+    let word_radius = parent.and_then(|parent| word_query.get(parent.get()).ok());
+
+    let space = match word_radius {
+        Some(word_radius) if layout.auto_fit => layout.space(
+            alphabet,
+            ***letter,
+            ***radius,
+            position_data.distance,
+            **word_radius,
+        ),
+        _ => DecorationSpace::default(),
+    };
+
+    Some((layout, space))
+}
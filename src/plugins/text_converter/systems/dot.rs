@@ -1,16 +1,44 @@
-use super::components::{Text, *};
+use super::components::*;
+use super::{letter_decoration_layout, LetterDecorationQuery, WordRadiusQuery};
+use crate::plugins::text_converter::alphabet::AlphabetSettings;
+use crate::plugins::text_converter::DecorationLayoutSettings;
 use crate::utils::update_if_changed::update_if_changed;
 use bevy::prelude::*;
 
+#[allow(clippy::type_complexity)]
 pub fn convert_dots(
     mut commands: Commands,
-    mut letter_query: Query<(Entity, &Letter, &Radius, &mut CircleChildren), Changed<Text>>,
-    mut dot_query: Query<(Entity, &mut Radius, &mut PositionData), (With<Dot>, Without<Letter>)>,
+    decoration_layout_settings: Res<DecorationLayoutSettings>,
+    alphabet_settings: Res<AlphabetSettings>,
+    mut letter_query: Query<(LetterDecorationQuery, &mut CircleChildren)>,
+    word_query: WordRadiusQuery,
+    mut dot_query: Query<
+        (Entity, &mut Radius, &mut PositionData),
+        (With<Dot>, Without<Letter>, Without<CustomDecoration>),
+    >,
+    custom_decoration_query: Query<Entity, With<CustomDecoration>>,
 ) {
-    for (letter_entity, letter, Radius(letter_radius), mut children) in letter_query.iter_mut() {
+    let alphabet = alphabet_settings.current();
+
+    for (letter_decoration, mut children) in letter_query.iter_mut() {
+        let Some((layout, space)) = letter_decoration_layout(
+            &letter_decoration,
+            &decoration_layout_settings,
+            &word_query,
+            alphabet,
+        ) else {
+            continue;
+        };
+
+        let (letter_entity, letter, _, _, letter_radius, ..) = letter_decoration;
+        let letter_radius = &**letter_radius;
+
+        let custom_decorations: Vec<Entity> =
+            custom_decoration_query.iter_many(children.iter()).collect();
+
         let mut existing_dots = dot_query.iter_many_mut(children.iter());
 
-        let number_of_dots = letter.dots();
+        let number_of_dots = letter.dots(alphabet);
         let mut new_dots_iter = 0..number_of_dots;
 
         let mut new_children: Vec<Entity> = Vec::with_capacity(number_of_dots);
@@ -22,14 +50,18 @@ pub fn convert_dots(
             match (next_existing_dot, next_new_dot) {
                 // update dot
                 (Some((dot_entity, mut radius, mut position_data)), Some(_)) => {
-                    let new_radius = Dot::radius(*letter_radius);
-                    let new_position_data =
-                        Dot::position_data(*letter_radius, number_of_dots, new_children.len());
+                    let new_radius = layout.dot_radius(*letter_radius);
+                    let new_position_data = layout.dot_position_data(
+                        *letter_radius,
+                        number_of_dots,
+                        new_children.len(),
+                        &space,
+                    );
 
                     update_if_changed!(**radius, new_radius, "Update dot radius: {} -> {}");
 
                     update_if_changed!(
-                        *position_data,
+                        **position_data,
                         new_position_data,
                         "Update dot position_data: {:?} -> {:?}"
                     );
@@ -45,8 +77,13 @@ pub fn convert_dots(
                 (None, Some(_)) => {
                     debug!("Spawn dot");
 
-                    let dot_bundle =
-                        DotBundle::new(*letter_radius, number_of_dots, new_children.len());
+                    let dot_bundle = DotBundle::new(
+                        *letter_radius,
+                        number_of_dots,
+                        new_children.len(),
+                        &layout,
+                        &space,
+                    );
 
                     let dot_entity = commands.spawn(dot_bundle).id();
                     commands.entity(letter_entity).add_child(dot_entity);
@@ -58,6 +95,71 @@ pub fn convert_dots(
             }
         }
 
+        new_children.extend(custom_decorations);
+
         **children = new_children;
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::math::angle::{Angle, Degree};
+    use crate::plugins::text_converter::{DecorationLayout, SetText, TextConverterPlugin};
+
+    fn dot_angles(app: &mut App) -> Vec<f32> {
+        app.world
+            .query_filtered::<&PositionData, With<Dot>>()
+            .iter(&app.world)
+            .map(|position_data| position_data.angle.inner())
+            .collect()
+    }
+
+    #[test]
+    fn should_apply_decoration_layout_override() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText("d".to_string()));
+
+        app.update();
+
+        assert_eq!(dot_angles(&mut app), vec![135.0, 180.0, 225.0]);
+
+        let mut query = app
+            .world
+            .query_filtered::<&mut DecorationLayoutOverride, With<Letter>>();
+        for mut decoration_layout in query.iter_mut(&mut app.world) {
+            **decoration_layout = Some(DecorationLayout {
+                spacing: Degree::new(30.0),
+                ..Default::default()
+            });
+        }
+
+        app.update();
+
+        assert_eq!(dot_angles(&mut app), vec![150.0, 180.0, 210.0]);
+    }
+
+    #[test]
+    fn should_apply_decoration_layout_settings() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText("d".to_string()));
+
+        app.update();
+
+        app.world
+            .resource_mut::<DecorationLayoutSettings>()
+            .center_angle = Degree::new(90.0);
+
+        app.update();
+
+        assert_eq!(dot_angles(&mut app), vec![45.0, 90.0, 135.0]);
+    }
+}
@@ -0,0 +1,136 @@
+use super::components::*;
+use crate::plugins::text_converter::script::ScriptElementKind;
+use bevy::prelude::*;
+
+#[allow(clippy::type_complexity)]
+pub fn assign_element_ids(
+    mut commands: Commands,
+    query: Query<
+        Entity,
+        (
+            Or<(
+                With<Sentence>,
+                With<Word>,
+                With<Letter>,
+                With<NestedVocalPositionCorrection>,
+                With<Dot>,
+                With<LineSlot>,
+                With<ScriptElementKind>,
+            )>,
+            Without<ElementId>,
+        ),
+    >,
+    mut element_id_generator: ResMut<ElementIdGenerator>,
+) {
+    for entity in query.iter() {
+        let element_id = element_id_generator.generate();
+        debug!("Assign element id: {:?} -> {}", entity, element_id);
+        commands.entity(entity).insert(element_id);
+    }
+}
+
+/// Makes sure that the ids of loaded elements won't be generated again.
+pub fn reserve_element_ids(
+    query: Query<&ElementId, Added<ElementId>>,
+    mut element_id_generator: ResMut<ElementIdGenerator>,
+) {
+    for element_id in query.iter() {
+        element_id_generator.reserve(*element_id);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::plugins::text_converter::{SetText, TextConverterPlugin};
+    use bevy::ecs::entity::EntityMap;
+    use bevy::utils::HashSet;
+
+    fn set_text(app: &mut App, text: &str) {
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText(text.to_string()));
+
+        app.update();
+    }
+
+    fn letter_ids(app: &mut App) -> Vec<(String, ElementId)> {
+        let mut letters: Vec<(String, ElementId)> = app
+            .world
+            .query_filtered::<(&Text, &ElementId), With<Letter>>()
+            .iter(&app.world)
+            .map(|(text, id)| (text.to_string(), *id))
+            .collect();
+        letters.sort_by_key(|(_, id)| *id);
+        letters
+    }
+
+    #[test]
+    fn should_assign_unique_element_ids() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        set_text(&mut app, "bad ti");
+
+        let elements = app
+            .world
+            .query::<(Entity, Option<&ElementId>)>()
+            .iter(&app.world)
+            .count();
+        let ids: HashSet<ElementId> = app
+            .world
+            .query::<&ElementId>()
+            .iter(&app.world)
+            .copied()
+            .collect();
+
+        assert_eq!(ids.len(), elements);
+    }
+
+    #[test]
+    fn should_keep_element_ids_of_updated_elements() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        set_text(&mut app, "ab");
+        let before = letter_ids(&mut app);
+
+        set_text(&mut app, "abc");
+        let after = letter_ids(&mut app);
+
+        assert_eq!(before[..], after[..2]);
+        assert!(after[2].1 > before[1].1);
+    }
+
+    #[test]
+    fn should_not_reuse_loaded_element_ids() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        let mut saved_world = World::new();
+        saved_world.spawn(ElementId(10));
+        let scene = DynamicScene::from_world(&saved_world, app.world.resource::<AppTypeRegistry>());
+        scene
+            .write_to_world(&mut app.world, &mut EntityMap::default())
+            .unwrap();
+
+        set_text(&mut app, "ab");
+
+        let ids: Vec<ElementId> = app
+            .world
+            .query::<&ElementId>()
+            .iter(&app.world)
+            .copied()
+            .collect();
+
+        assert_eq!(ids.iter().filter(|id| **id == ElementId(10)).count(), 1);
+        assert!(ids.iter().all(|id| id.0 >= 10));
+    }
+
+    #[test]
+    fn should_parse_element_id() {
+        assert_eq!("e12".parse::<ElementId>(), Ok(ElementId(12)));
+        assert_eq!(ElementId(12).to_string(), "e12");
+        assert!("12".parse::<ElementId>().is_err());
+    }
+}
@@ -1,53 +1,12 @@
-use super::super::split_word_to_chars;
 use super::components::{Text, *};
+use crate::plugins::text_converter::alphabet::AlphabetSettings;
+use crate::plugins::text_converter::ReadingDirectionSettings;
 use crate::utils::update_if_changed::update_if_changed;
 use bevy::prelude::*;
+use gallifreyan_core::alphabet;
+use gallifreyan_core::alphabet::{create_letters_from_word, NESTED_LETTER_TEXT_DELIMITER};
 
-const NESTED_LETTER_TEXT_DELIMITER: &str = "~";
-
-fn create_letters_from_word(
-    word_text: &str,
-    nesting_settings: &NestingSettings,
-) -> Vec<(String, Letter)> {
-    let letters = split_word_to_chars(word_text).map(|it| {
-        let letter = Letter::try_from(it).unwrap();
-        (it.to_string(), letter)
-    });
-
-    match nesting_settings {
-        NestingSettings::None => letters.collect(),
-        nesting_settings => letters.fold(Vec::new(), |mut acc, (text, letter)| {
-            match letter {
-                Letter::Vocal(vocal) => {
-                    if let Some((previous_text, previous_letter)) = acc.pop() {
-                        match previous_letter {
-                            Letter::Consonant(consonant)
-                                if nesting_settings.can_nest(consonant, vocal) =>
-                            {
-                                acc.push((
-                                    previous_text + NESTED_LETTER_TEXT_DELIMITER + &text,
-                                    Letter::ConsonantWithVocal { consonant, vocal },
-                                ));
-                            }
-                            _ => {
-                                acc.push((previous_text, previous_letter));
-                                acc.push((text, letter));
-                            }
-                        }
-                    } else {
-                        acc.push((text, letter));
-                    }
-                }
-                Letter::Consonant(_) | Letter::ConsonantWithVocal { .. } => {
-                    acc.push((text, letter));
-                }
-            }
-
-            acc
-        }),
-    }
-}
-
+#[allow(clippy::type_complexity)]
 pub fn convert_letters(
     mut commands: Commands,
     mut word_query: Query<
@@ -65,11 +24,16 @@ pub fn convert_letters(
         (Without<Word>, Without<NestedVocal>),
     >,
     nesting_settings: Res<NestingSettings>,
+    alphabet_settings: Res<AlphabetSettings>,
+    reading_direction_settings: Res<ReadingDirectionSettings>,
 ) {
+    let alphabet = alphabet_settings.current();
+    let reading_direction = reading_direction_settings.reading_direction;
+
     for (word_entity, word_text, Radius(word_radius), mut children) in word_query.iter_mut() {
         let mut existing_letters = letter_query.iter_many_mut(children.iter());
 
-        let new_letters = create_letters_from_word(word_text, &nesting_settings);
+        let new_letters = create_letters_from_word(word_text, &*nesting_settings, alphabet);
 
         let number_of_letters = new_letters.len();
         let mut new_letters_iter = new_letters.into_iter();
@@ -88,6 +52,8 @@ pub fn convert_letters(
                 ) => {
                     let new_radius = new_letter.radius(*word_radius, number_of_letters);
                     let new_position_data = new_letter.position_data(
+                        alphabet,
+                        reading_direction,
                         *word_radius,
                         number_of_letters,
                         new_children.len(),
@@ -96,12 +62,12 @@ pub fn convert_letters(
                     debug!("Update letter: {:?} -> {:?}", *letter, new_letter);
 
                     **text = new_text;
-                    *letter = new_letter;
+                    **letter = new_letter;
 
                     update_if_changed!(**radius, new_radius, "Update letter radius: {} -> {}");
 
                     update_if_changed!(
-                        *position_data,
+                        **position_data,
                         new_position_data,
                         "Update letter position_data: {:?} -> {:?}"
                     );
@@ -123,6 +89,8 @@ pub fn convert_letters(
                         *word_radius,
                         number_of_letters,
                         new_children.len(),
+                        alphabet,
+                        reading_direction,
                     );
 
                     let letter_entity = commands.spawn(letter_bundle).id();
@@ -139,6 +107,7 @@ pub fn convert_letters(
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub fn convert_nested_letters(
     mut commands: Commands,
     word_query: Query<&Radius, (With<Word>, Without<NestedVocal>)>,
@@ -165,7 +134,10 @@ pub fn convert_nested_letters(
         ),
         With<NestedVocal>,
     >,
+    alphabet_settings: Res<AlphabetSettings>,
 ) {
+    let alphabet = alphabet_settings.current();
+
     for (
         letter_entity,
         letter_parent,
@@ -176,8 +148,8 @@ pub fn convert_nested_letters(
         mut nested,
     ) in letter_query.iter_mut()
     {
-        match letter {
-            Letter::ConsonantWithVocal { vocal, consonant } => {
+        match **letter {
+            alphabet::Letter::ConsonantWithVocal { vocal, consonant } => {
                 let word_radius = **word_query.get(letter_parent.get()).unwrap();
                 let new_nested_text = letter_text
                     .split_once(NESTED_LETTER_TEXT_DELIMITER)
@@ -197,19 +169,15 @@ pub fn convert_nested_letters(
                     {
                         debug!("Update nested letter: {:?} -> {:?}", *nested_letter, vocal);
 
-                        let old_placement = match *nested_letter {
-                            Letter::Vocal(vocal) => VocalPlacement::from(vocal),
-                            _ => unreachable!(),
-                        };
-
-                        let new_placement = VocalPlacement::from(*vocal);
+                        // the placement of the old vocal might have changed with the alphabet
+                        let has_position_correction =
+                            position_correction_query.contains(nested_parent.get());
+                        let needs_position_correction =
+                            alphabet.vocal_placement(vocal) == VocalPlacement::Outside;
 
-                        match (old_placement, new_placement) {
+                        match (has_position_correction, needs_position_correction) {
                             // add position correction
-                            (
-                                VocalPlacement::OnLine | VocalPlacement::Inside,
-                                VocalPlacement::Outside,
-                            ) => {
+                            (false, true) => {
                                 debug!("Add position correction");
 
                                 commands
@@ -224,10 +192,7 @@ pub fn convert_nested_letters(
                                     });
                             }
                             // remove position correction
-                            (
-                                VocalPlacement::Outside,
-                                VocalPlacement::OnLine | VocalPlacement::Inside,
-                            ) => {
+                            (true, false) => {
                                 debug!("Remove position correction");
 
                                 commands
@@ -240,11 +205,12 @@ pub fn convert_nested_letters(
                         }
 
                         **nested_text = new_nested_text;
-                        *nested_letter = Letter::Vocal(*vocal);
+                        **nested_letter = alphabet::Letter::Vocal(vocal);
 
                         let new_nested_radius = vocal.nested_radius(**letter_radius);
                         let new_nested_position_data = vocal.nested_position_data(
-                            ConsonantPlacement::from(*consonant),
+                            alphabet,
+                            alphabet.consonant_placement(consonant),
                             **letter_radius,
                             letter_position_data.distance,
                             word_radius,
@@ -257,7 +223,7 @@ pub fn convert_nested_letters(
                         );
 
                         update_if_changed!(
-                            *nested_position_data,
+                            **nested_position_data,
                             new_nested_position_data,
                             "Update nested letter position_data: {:?} -> {:?}"
                         );
@@ -269,14 +235,15 @@ pub fn convert_nested_letters(
 
                     let vocal_bundle = NestedVocalBundle::new(
                         new_nested_text,
-                        *vocal,
-                        ConsonantPlacement::from(*consonant),
+                        vocal,
+                        alphabet.consonant_placement(consonant),
                         **letter_radius,
                         letter_position_data.distance,
                         word_radius,
+                        alphabet,
                     );
 
-                    let vocal_id = if VocalPlacement::Outside == VocalPlacement::from(*vocal) {
+                    let vocal_id = if VocalPlacement::Outside == alphabet.vocal_placement(vocal) {
                         debug!("Spawn nested letter with position correction: {:?}", letter);
 
                         let position_correction_id = commands
@@ -298,7 +265,7 @@ pub fn convert_nested_letters(
                     **nested = Some(vocal_id);
                 }
             }
-            Letter::Consonant(_) | Letter::Vocal(_) => {
+            alphabet::Letter::Consonant(_) | alphabet::Letter::Vocal(_) => {
                 // remove nested
                 if let Some(nested_entity) = nested.take() {
                     let position_correction_entity = nested_vocal_query
@@ -392,6 +359,26 @@ mod test {
         test_count_letter_entities("th", 1, 0, 0);
     }
 
+    #[test]
+    fn should_spawn_t_and_h_with_separator() {
+        test_count_letter_entities("t|h", 2, 0, 2);
+    }
+
+    #[test]
+    fn should_split_th_on_update() {
+        test_component_update::<Text, Letter>(
+            "hothouse",
+            "hot|house",
+            NestingSettings::None,
+            |before, after| {
+                assert_eq!(before.len(), 7);
+                assert_eq!(after.len(), 8);
+                assert_eq!(*after[2], "t");
+                assert_eq!(*after[3], "h");
+            },
+        );
+    }
+
     #[test]
     fn should_spawn_ph() {
         test_count_letter_entities("ph", 1, 1, 0);
@@ -686,105 +673,6 @@ mod test {
         assert_occurrences(&mut app, 0, 0, 1);
     }
 
-    #[test]
-    fn should_nest_all_vocals() {
-        let result = create_letters_from_word("bbabibubebo", &NestingSettings::All);
-        let expected = [
-            ("b".to_string(), Letter::Consonant(Consonant::B)),
-            (
-                "b~a".to_string(),
-                Letter::ConsonantWithVocal {
-                    consonant: Consonant::B,
-                    vocal: Vocal::A,
-                },
-            ),
-            (
-                "b~i".to_string(),
-                Letter::ConsonantWithVocal {
-                    consonant: Consonant::B,
-                    vocal: Vocal::I,
-                },
-            ),
-            (
-                "b~u".to_string(),
-                Letter::ConsonantWithVocal {
-                    consonant: Consonant::B,
-                    vocal: Vocal::U,
-                },
-            ),
-            (
-                "b~e".to_string(),
-                Letter::ConsonantWithVocal {
-                    consonant: Consonant::B,
-                    vocal: Vocal::E,
-                },
-            ),
-            (
-                "b~o".to_string(),
-                Letter::ConsonantWithVocal {
-                    consonant: Consonant::B,
-                    vocal: Vocal::O,
-                },
-            ),
-        ];
-
-        assert_eq!(result, expected);
-    }
-
-    #[test]
-    fn should_nest_no_vocals() {
-        let result = create_letters_from_word("bbabibubebo", &NestingSettings::None);
-        let expected = [
-            ("b".to_string(), Letter::Consonant(Consonant::B)),
-            ("b".to_string(), Letter::Consonant(Consonant::B)),
-            ("a".to_string(), Letter::Vocal(Vocal::A)),
-            ("b".to_string(), Letter::Consonant(Consonant::B)),
-            ("i".to_string(), Letter::Vocal(Vocal::I)),
-            ("b".to_string(), Letter::Consonant(Consonant::B)),
-            ("u".to_string(), Letter::Vocal(Vocal::U)),
-            ("b".to_string(), Letter::Consonant(Consonant::B)),
-            ("e".to_string(), Letter::Vocal(Vocal::E)),
-            ("b".to_string(), Letter::Consonant(Consonant::B)),
-            ("o".to_string(), Letter::Vocal(Vocal::O)),
-        ];
-
-        assert_eq!(result, expected);
-    }
-
-    #[test]
-    fn should_nest_custom_vocals() {
-        let rules = [(Consonant::B, Vocal::A), (Consonant::B, Vocal::O)]
-            .into_iter()
-            .collect();
-
-        let result = create_letters_from_word("bbabibubebo", &NestingSettings::Custom(rules));
-        let expected = [
-            ("b".to_string(), Letter::Consonant(Consonant::B)),
-            (
-                "b~a".to_string(),
-                Letter::ConsonantWithVocal {
-                    consonant: Consonant::B,
-                    vocal: Vocal::A,
-                },
-            ),
-            ("b".to_string(), Letter::Consonant(Consonant::B)),
-            ("i".to_string(), Letter::Vocal(Vocal::I)),
-            ("b".to_string(), Letter::Consonant(Consonant::B)),
-            ("u".to_string(), Letter::Vocal(Vocal::U)),
-            ("b".to_string(), Letter::Consonant(Consonant::B)),
-            ("e".to_string(), Letter::Vocal(Vocal::E)),
-            (
-                "b~o".to_string(),
-                Letter::ConsonantWithVocal {
-                    consonant: Consonant::B,
-                    vocal: Vocal::O,
-                },
-            ),
-        ];
-
-        assert_eq!(result, expected);
-    }
-
     fn assert_spawn_nested(consonant: &str, vocal: &str) {
         let mut app = App::new();
         app.add_plugin(TextConverterPlugin);
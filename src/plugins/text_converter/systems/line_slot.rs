@@ -1,20 +1,45 @@
-use super::components::{Text, *};
+use super::components::*;
+use super::{letter_decoration_layout, LetterDecorationQuery, WordRadiusQuery};
+use crate::plugins::text_converter::alphabet::AlphabetSettings;
+use crate::plugins::text_converter::DecorationLayoutSettings;
 use crate::utils::update_if_changed::update_if_changed;
 use bevy::prelude::*;
 
+#[allow(clippy::type_complexity)]
 pub fn convert_line_slots(
     mut commands: Commands,
-    mut letter_query: Query<(Entity, &Letter, &Radius, &mut LineSlotChildren), Changed<Text>>,
-    mut line_slot_query: Query<(Entity, &mut PositionData), With<LineSlot>>,
+    decoration_layout_settings: Res<DecorationLayoutSettings>,
+    alphabet_settings: Res<AlphabetSettings>,
+    mut letter_query: Query<(LetterDecorationQuery, &mut LineSlotChildren)>,
+    word_query: WordRadiusQuery,
+    mut line_slot_query: Query<
+        (Entity, &mut PositionData),
+        (With<LineSlot>, Without<Letter>, Without<CustomDecoration>),
+    >,
+    custom_decoration_query: Query<Entity, With<CustomDecoration>>,
 ) {
-    for (letter_entity, letter, Radius(letter_radius), mut children) in letter_query.iter_mut() {
-        let mut existing_line_slots = line_slot_query.iter_many_mut(children.iter());
+    let alphabet = alphabet_settings.current();
 
-        let number_of_lines = letter.lines();
-        let line_points_outside = match letter {
-            Letter::Vocal(vocal) => VocalDecoration::from(*vocal) == VocalDecoration::LineOutside,
-            Letter::Consonant(_) | Letter::ConsonantWithVocal { .. } => false,
+    for (letter_decoration, mut children) in letter_query.iter_mut() {
+        let Some((layout, space)) = letter_decoration_layout(
+            &letter_decoration,
+            &decoration_layout_settings,
+            &word_query,
+            alphabet,
+        ) else {
+            continue;
         };
+
+        let (letter_entity, letter, _, _, letter_radius, ..) = letter_decoration;
+        let letter_radius = &**letter_radius;
+
+        let custom_decorations: Vec<Entity> =
+            custom_decoration_query.iter_many(children.iter()).collect();
+
+        let mut existing_line_slots = line_slot_query.iter_many_mut(children.iter());
+
+        let number_of_lines = letter.lines(alphabet);
+        let line_points_outside = letter.line_points_outside(alphabet);
         let mut new_line_slots_iter = 0..number_of_lines;
 
         let mut new_children: Vec<Entity> = Vec::with_capacity(number_of_lines);
@@ -26,15 +51,16 @@ pub fn convert_line_slots(
             match (next_existing_line_slot, next_new_line_slot) {
                 // update line slot
                 (Some((line_slot_entity, mut position_data)), Some(_)) => {
-                    let new_position_data = LineSlot::position_data(
+                    let new_position_data = layout.line_slot_position_data(
                         *letter_radius,
                         number_of_lines,
                         new_children.len(),
                         line_points_outside,
+                        &space,
                     );
 
                     update_if_changed!(
-                        *position_data,
+                        **position_data,
                         new_position_data,
                         "Update line_slot position_data: {:?} -> {:?}"
                     );
@@ -55,6 +81,8 @@ pub fn convert_line_slots(
                         number_of_lines,
                         new_children.len(),
                         line_points_outside,
+                        &layout,
+                        &space,
                     );
 
                     let line_slot_entity = commands.spawn(line_slot_bundle).id();
@@ -67,6 +95,8 @@ pub fn convert_line_slots(
             }
         }
 
+        new_children.extend(custom_decorations);
+
         **children = new_children;
     }
 }
@@ -1,41 +1,255 @@
-use super::super::SetText;
+use super::super::{
+    ReadingDirectionSettings, SentenceArrangementSettings, SentenceFrameSettings, SetText,
+};
 use super::components::{Text, *};
-use bevy::ecs::query::QuerySingleError;
+use crate::plugins::svg::{Circle, DrawStyle, SVGShape, StyleOverride};
+use crate::plugins::text_converter::alphabet::AlphabetSettings;
+use crate::utils::update_if_changed::update_if_changed;
 use bevy::prelude::*;
+use gallifreyan_core::alphabet::SENTENCE_SEPARATOR;
+use gallifreyan_core::layout::{ReadingDirection, SentenceArrangement};
+use gallifreyan_core::model;
 
+/// Every line of the text is a root sentence, existing sentences are updated in their order.
+#[allow(clippy::type_complexity)]
 pub fn convert_sentence(
     mut commands: Commands,
     mut events: EventReader<SetText>,
-    mut sentence_query: Query<(Entity, &mut Text), With<Sentence>>,
+    mut sentence_query: Query<
+        (Entity, Option<&SentenceIndex>, &mut Text),
+        (With<Sentence>, Without<Parent>),
+    >,
 ) {
     if let Some(SetText(text)) = events.iter().last() {
-        match sentence_query.get_single_mut() {
-            Ok((sentence_entity, mut sentence_text)) => {
-                if text.is_empty() {
+        let new_texts: Vec<&str> = text
+            .split(SENTENCE_SEPARATOR)
+            .filter(|it| !it.is_empty())
+            .collect();
+
+        let mut sentences: Vec<_> = sentence_query
+            .iter_mut()
+            .map(|(entity, index, text)| (index, (entity, text)))
+            .collect();
+        sort_sentences(&mut sentences);
+
+        let mut existing_sentences = sentences.into_iter();
+
+        for index in 0..new_texts.len().max(existing_sentences.len()) {
+            match (existing_sentences.next(), new_texts.get(index)) {
+                (Some((sentence_index, (sentence_entity, mut sentence_text))), Some(new_text)) => {
+                    if **sentence_text != *new_text {
+                        debug!("Update sentence: {} -> {}", **sentence_text, new_text);
+                        **sentence_text = new_text.to_string();
+                    }
+
+                    if sentence_index != Some(&SentenceIndex(index)) {
+                        commands
+                            .entity(sentence_entity)
+                            .insert(SentenceIndex(index));
+                    }
+                }
+                (Some((_, (sentence_entity, sentence_text))), None) => {
                     debug!("Despawn sentence: {}", **sentence_text);
                     commands.entity(sentence_entity).despawn_recursive();
-                } else {
-                    debug!("Update sentence: {} -> {}", **sentence_text, text);
-                    **sentence_text = text.clone();
                 }
+                (None, Some(new_text)) => {
+                    debug!("Spawn sentence: {}", new_text);
+                    let sentence_bundle = SentenceBundle::new(new_text.to_string());
+                    commands.spawn((sentence_bundle, SentenceIndex(index)));
+                }
+                (None, None) => {}
             }
-            Err(QuerySingleError::NoEntities(_)) => {
-                debug!("Spawn sentence: {}", text);
-                let sentence_bundle = SentenceBundle::new(text.to_string());
-                commands.spawn(sentence_bundle);
+        }
+    }
+}
+
+/// Converts the words of all sentences again as the size of a single word depends on the frame.
+pub fn apply_sentence_frame_settings(
+    sentence_frame: Res<SentenceFrameSettings>,
+    mut sentence_query: Query<&mut Text, With<Sentence>>,
+) {
+    if !sentence_frame.is_changed() || sentence_frame.is_added() {
+        return;
+    }
+
+    debug!("Set sentence frame: {:?}", **sentence_frame);
+
+    for mut text in sentence_query.iter_mut() {
+        text.set_changed();
+    }
+}
+
+/// Places the existing words and letters again as their positions depend on the reading
+/// direction. Only words and letters which are still at their position for the last reading
+/// direction are moved, so moved words and edited letters stay in place. The first run only
+/// records it.
+#[allow(clippy::type_complexity)]
+pub fn apply_reading_direction_settings(
+    reading_direction_settings: Res<ReadingDirectionSettings>,
+    alphabet_settings: Res<AlphabetSettings>,
+    sentence_query: Query<(&Radius, &CircleChildren), With<Sentence>>,
+    mut word_query: Query<
+        (&Radius, &CircleChildren, &mut PositionData),
+        (With<Word>, Without<Sentence>),
+    >,
+    mut letter_query: Query<
+        (&Letter, &mut PositionData),
+        (Without<Word>, Without<Sentence>, Without<NestedVocal>),
+    >,
+    mut last_reading_direction: Local<Option<ReadingDirection>>,
+) {
+    let reading_direction = reading_direction_settings.reading_direction;
+    let last = last_reading_direction.replace(reading_direction);
+
+    let Some(last) = last.filter(|last| *last != reading_direction) else {
+        return;
+    };
+
+    debug!("Set reading direction: {:?}", reading_direction);
+
+    let alphabet = alphabet_settings.current();
+
+    for (Radius(sentence_radius), words) in sentence_query.iter() {
+        let number_of_words = words.len();
+        let mut word_iter = word_query.iter_many_mut(words.iter());
+        let mut word_index = 0;
+
+        while let Some((Radius(word_radius), letters, mut position_data)) = word_iter.fetch_next() {
+            let position_data_for = |reading_direction| {
+                model::Word::position_data(
+                    reading_direction,
+                    *sentence_radius,
+                    number_of_words,
+                    word_index,
+                )
+            };
+
+            if **position_data == position_data_for(last) {
+                update_if_changed!(
+                    **position_data,
+                    position_data_for(reading_direction),
+                    "Update word position_data: {:?} -> {:?}"
+                );
             }
-            Err(QuerySingleError::MultipleEntities(_)) => {
-                error!("Multiple sentences");
+
+            let number_of_letters = letters.len();
+            let mut letter_iter = letter_query.iter_many_mut(letters.iter());
+            let mut letter_index = 0;
+
+            while let Some((letter, mut position_data)) = letter_iter.fetch_next() {
+                let position_data_for = |reading_direction| {
+                    letter.position_data(
+                        alphabet,
+                        reading_direction,
+                        *word_radius,
+                        number_of_letters,
+                        letter_index,
+                    )
+                };
+
+                if **position_data == position_data_for(last) {
+                    update_if_changed!(
+                        **position_data,
+                        position_data_for(reading_direction),
+                        "Update letter position_data: {:?} -> {:?}"
+                    );
+                }
+
+                letter_index += 1;
             }
+
+            word_index += 1;
         }
     }
 }
 
+/// Places the root sentences with the [`SentenceArrangementSettings`] whenever sentences were
+/// added or removed or the arrangement changed.
+#[allow(clippy::type_complexity)]
+pub fn arrange_sentences(
+    mut commands: Commands,
+    sentence_arrangement: Res<SentenceArrangementSettings>,
+    mut sentence_query: Query<
+        (Entity, Option<&SentenceIndex>, &mut PositionData),
+        (With<Sentence>, Without<Parent>),
+    >,
+    added_query: Query<(), (Added<Sentence>, Without<Parent>)>,
+    changed_index_query: Query<(), Changed<SentenceIndex>>,
+    mut removed_sentences: RemovedComponents<Sentence>,
+    mut paragraph_query: Query<(Entity, &mut SVGShape), With<Paragraph>>,
+) {
+    let is_removed = removed_sentences.iter().count() > 0;
+
+    if !sentence_arrangement.is_changed()
+        && !is_removed
+        && added_query.is_empty()
+        && changed_index_query.is_empty()
+    {
+        return;
+    }
+
+    let arrangement = **sentence_arrangement;
+    let radius = model::Sentence::radius();
+
+    let mut sentences: Vec<_> = sentence_query
+        .iter_mut()
+        .map(|(entity, index, position_data)| (index, (entity, position_data)))
+        .collect();
+    sort_sentences(&mut sentences);
+
+    let number_of_sentences = sentences.len();
+
+    for (index, (_, (sentence_entity, mut position_data))) in sentences.into_iter().enumerate() {
+        if arrangement == SentenceArrangement::Free && !added_query.contains(sentence_entity) {
+            continue;
+        }
+
+        let new_position_data = arrangement.position_data(radius, number_of_sentences, index);
+
+        if **position_data != new_position_data {
+            debug!("Arrange sentence {}: {:?}", index, new_position_data);
+            **position_data = new_position_data;
+        }
+    }
+
+    let is_paragraph = arrangement == SentenceArrangement::Paragraph && number_of_sentences > 1;
+    let paragraph_shape = || {
+        Circle::new(SentenceArrangement::paragraph_radius(
+            radius,
+            number_of_sentences,
+        ))
+        .into()
+    };
+
+    match (paragraph_query.get_single_mut(), is_paragraph) {
+        (Ok((_, mut shape)), true) => {
+            **shape = paragraph_shape();
+        }
+        (Ok((paragraph_entity, _)), false) => {
+            debug!("Despawn paragraph");
+            commands.entity(paragraph_entity).despawn_recursive();
+        }
+        (Err(_), true) => {
+            debug!("Spawn paragraph");
+            commands.spawn((
+                Paragraph,
+                SVGShape(paragraph_shape()),
+                DrawStyle::Stroke,
+                StyleOverride::default(),
+                TransformBundle::from_transform(Transform::from_xyz(0.0, 0.0, 0.1)),
+            ));
+        }
+        (Err(_), false) => {}
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
+    use crate::math::angle::Angle;
     use crate::plugins::text_converter::test::test_component_update;
     use crate::plugins::text_converter::{SetText, TextConverterPlugin};
+    use gallifreyan_core::layout;
 
     #[test]
     fn should_spawn_sentence() {
@@ -120,4 +334,145 @@ mod test {
             },
         );
     }
+
+    #[test]
+    fn should_spawn_sentence_per_line() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText("first\nsecond".to_string()));
+
+        app.update();
+
+        let mut query = app
+            .world
+            .query_filtered::<(&SentenceIndex, &Text, &PositionData), With<Sentence>>();
+        let mut sentences: Vec<_> = query.iter(&app.world).collect();
+        sentences.sort_by_key(|(index, ..)| **index);
+
+        assert_eq!(sentences.len(), 2);
+        assert_eq!(**sentences[0].1, "first");
+        assert_eq!(**sentences[1].1, "second");
+        assert_ne!(sentences[0].2, sentences[1].2);
+    }
+
+    #[test]
+    fn should_keep_element_ids_when_reading_direction_changes() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText("ba dot".to_string()));
+
+        app.update();
+
+        let mut query = app
+            .world
+            .query_filtered::<(Entity, &ElementId, &PositionData), Or<(With<Word>, With<Letter>)>>(
+            );
+        let before: Vec<_> = query
+            .iter(&app.world)
+            .map(|(entity, id, position_data)| (entity, *id, *position_data))
+            .collect();
+
+        app.world
+            .resource_mut::<ReadingDirectionSettings>()
+            .reading_direction = ReadingDirection {
+            start_angle: Default::default(),
+            clockwise: true,
+        };
+
+        app.update();
+
+        let after: Vec<_> = query
+            .iter(&app.world)
+            .map(|(entity, id, position_data)| (entity, *id, *position_data))
+            .collect();
+
+        assert_eq!(before.len(), after.len());
+
+        for ((entity, id, position_data), (new_entity, new_id, new_position_data)) in
+            before.iter().zip(&after)
+        {
+            assert_eq!(entity, new_entity);
+            assert_eq!(id, new_id);
+
+            let mirrored_angle = new_position_data.angle.inner() + position_data.angle.inner();
+            assert_eq!(mirrored_angle.rem_euclid(360.0), 0.0);
+        }
+    }
+
+    #[test]
+    fn should_keep_moved_elements_when_reading_direction_changes() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin)
+            .insert_resource(NestingSettings::None);
+
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText("ba dot".to_string()));
+
+        app.update();
+
+        let mut word_query = app.world.query_filtered::<Entity, With<Word>>();
+        let words: Vec<Entity> = word_query.iter(&app.world).collect();
+        let letters = app.world.get::<CircleChildren>(words[0]).unwrap().to_vec();
+
+        let moved = PositionData(layout::PositionData {
+            distance: 12.0,
+            ..Default::default()
+        });
+        *app.world.get_mut::<PositionData>(words[0]).unwrap() = moved;
+        *app.world.get_mut::<PositionData>(letters[0]).unwrap() = moved;
+
+        let other_letter_before = *app.world.get::<PositionData>(letters[1]).unwrap();
+        let other_word_before = *app.world.get::<PositionData>(words[1]).unwrap();
+
+        app.world
+            .resource_mut::<ReadingDirectionSettings>()
+            .reading_direction = ReadingDirection {
+            start_angle: Default::default(),
+            clockwise: true,
+        };
+
+        app.update();
+
+        assert_eq!(*app.world.get::<PositionData>(words[0]).unwrap(), moved);
+        assert_eq!(*app.world.get::<PositionData>(letters[0]).unwrap(), moved);
+        assert_ne!(
+            *app.world.get::<PositionData>(letters[1]).unwrap(),
+            other_letter_before
+        );
+        assert_ne!(
+            *app.world.get::<PositionData>(words[1]).unwrap(),
+            other_word_before
+        );
+    }
+
+    #[test]
+    fn should_spawn_paragraph() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin)
+            .insert_resource(SentenceArrangementSettings(SentenceArrangement::Paragraph));
+
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText("first\nsecond\nthird".to_string()));
+
+        app.update();
+
+        let mut query = app.world.query_filtered::<(), With<Paragraph>>();
+        assert_eq!(query.iter(&app.world).count(), 1);
+
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText("first".to_string()));
+
+        app.update();
+
+        assert_eq!(query.iter(&app.world).count(), 0);
+    }
 }
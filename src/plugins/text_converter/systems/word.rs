@@ -1,9 +1,14 @@
+use super::super::{ReadingDirectionSettings, SentenceFrameSettings};
 use super::components::{Text, *};
 use crate::utils::update_if_changed::update_if_changed;
 use bevy::prelude::*;
+use gallifreyan_core::model;
 
+#[allow(clippy::type_complexity)]
 pub fn convert_words(
     mut commands: Commands,
+    sentence_frame: Res<SentenceFrameSettings>,
+    reading_direction_settings: Res<ReadingDirectionSettings>,
     mut sentence_query: Query<
         (Entity, &Text, &Radius, &mut CircleChildren),
         (With<Sentence>, Changed<Text>),
@@ -23,6 +28,8 @@ pub fn convert_words(
             .map(|it| it.to_string())
             .collect();
         let number_of_words = new_words.len();
+        // words which were moved freely stay in place while the number of words stays the same
+        let is_number_of_words_changed = number_of_words != children.len();
         let mut new_words_iter = new_words.into_iter();
 
         let mut new_children: Vec<Entity> = Vec::with_capacity(number_of_words);
@@ -39,10 +46,11 @@ pub fn convert_words(
                 ) => {
                     debug!("Update word: {} -> {}", **word_text, new_word);
 
-                    let new_radius = Word::radius(*sentence_radius, number_of_words);
-
-                    let new_position_data =
-                        Word::position_data(*sentence_radius, number_of_words, new_children.len());
+                    let new_radius = model::Word::framed_radius(
+                        **sentence_frame,
+                        *sentence_radius,
+                        number_of_words,
+                    );
 
                     // TODO text change
                     //if **word_text != new_word {
@@ -51,11 +59,20 @@ pub fn convert_words(
 
                     update_if_changed!(**radius, new_radius, "Update word radius: {} -> {}");
 
-                    update_if_changed!(
-                        *position_data,
-                        new_position_data,
-                        "Update word position_data: {:?} -> {:?}"
-                    );
+                    if is_number_of_words_changed {
+                        let new_position_data = model::Word::position_data(
+                            reading_direction_settings.reading_direction,
+                            *sentence_radius,
+                            number_of_words,
+                            new_children.len(),
+                        );
+
+                        update_if_changed!(
+                            **position_data,
+                            new_position_data,
+                            "Update word position_data: {:?} -> {:?}"
+                        );
+                    }
 
                     new_children.push(word_entity);
                 }
@@ -68,12 +85,18 @@ pub fn convert_words(
                 (None, Some(new_word)) => {
                     debug!("Spawn word: {}", new_word);
 
-                    let word_bundle = WordBundle::new(
+                    let mut word_bundle = WordBundle::new(
                         new_word,
+                        reading_direction_settings.reading_direction,
                         *sentence_radius,
                         number_of_words,
                         new_children.len(),
                     );
+                    word_bundle.radius = Radius(model::Word::framed_radius(
+                        **sentence_frame,
+                        *sentence_radius,
+                        number_of_words,
+                    ));
 
                     let word_entity = commands.spawn(word_bundle).id();
                     commands.entity(sentence_entity).add_child(word_entity);
@@ -300,4 +323,31 @@ mod test {
             },
         );
     }
+
+    #[test]
+    fn should_keep_moved_word_position() {
+        let mut app = App::new();
+        app.add_plugin(TextConverterPlugin);
+
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText("my word".to_string()));
+
+        app.update();
+
+        let mut query = app.world.query_filtered::<&mut PositionData, With<Word>>();
+        for mut position_data in query.iter_mut(&mut app.world) {
+            position_data.distance = 1000.0;
+        }
+
+        app.world
+            .resource_mut::<Events<SetText>>()
+            .send(SetText("me words".to_string()));
+
+        app.update();
+
+        assert!(query
+            .iter(&app.world)
+            .all(|position_data| position_data.distance == 1000.0));
+    }
 }
@@ -1,11 +1,13 @@
-use crate::plugins::file::{FileActions, FileHandleAction};
+use crate::plugins::file::{ExportSettings, FileActions, FileHandleAction};
 use crate::utils::event_set::SendEvent;
 use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
 use bevy_egui::egui;
 
 #[derive(SystemParam)]
 pub struct FileSystemParams<'w> {
     file_actions: FileActions<'w>,
+    export_settings: ResMut<'w, ExportSettings>,
     //file_handles: os::FileHandlesResource<'w>,
 }
 
@@ -30,9 +32,28 @@ pub fn ui(ui: &mut egui::Ui, mut params: FileSystemParams) {
             params.file_actions.dispatch(FileHandleAction::Save);
         }*/
 
+        if ui.button("Import SVG...").clicked() {
+            ui.close_menu();
+            params.file_actions.dispatch(FileHandleAction::Import);
+        }
+
+        ui.separator();
+
         if ui.button("Export as SVG...").clicked() {
             ui.close_menu();
             params.file_actions.dispatch(FileHandleAction::Export);
         }
+
+        if ui.button("Export as animated SVG...").clicked() {
+            ui.close_menu();
+            params
+                .file_actions
+                .dispatch(FileHandleAction::AnimatedExport);
+        }
+
+        ui.checkbox(
+            &mut params.export_settings.embed_metadata,
+            "Embed metadata for import",
+        );
     });
 }
@@ -1,7 +1,17 @@
+pub mod animation;
+pub mod decorations;
+pub mod reading_direction;
+pub mod reference_image;
 pub mod vocal_nesting;
 
 use super::{UiBaseSet, UiSet};
 use crate::plugins::color_theme::{ColorTheme, Theme};
+use crate::plugins::text_converter::alphabet::AlphabetSettings;
+use crate::plugins::text_converter::script::ScriptSystemSettings;
+use crate::plugins::text_converter::{
+    NormalizationPolicy, NormalizationSettings, SentenceArrangement, SentenceArrangementSettings,
+    SentenceFrame, SentenceFrameSettings,
+};
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_egui::egui;
@@ -10,8 +20,14 @@ pub struct SettingsPlugin;
 
 impl Plugin for SettingsPlugin {
     fn build(&self, app: &mut App) {
-        app.init_resource::<OpenedSettingWindows>().add_system(
-            vocal_nesting::ui
+        app.init_resource::<OpenedSettingWindows>().add_systems(
+            (
+                vocal_nesting::ui,
+                animation::ui,
+                reading_direction::ui,
+                decorations::ui,
+                reference_image::ui,
+            )
                 .in_base_set(UiBaseSet)
                 .in_set(UiSet::Window),
         );
@@ -21,12 +37,21 @@ impl Plugin for SettingsPlugin {
 #[derive(Default, Resource)]
 pub struct OpenedSettingWindows {
     vocal_nesting: bool,
+    animation: bool,
+    reading_direction: bool,
+    decorations: bool,
+    reference_image: bool,
 }
 
 #[derive(SystemParam)]
 pub struct SettingsSystemParams<'w> {
     color_theme: ResMut<'w, ColorTheme>,
     opened_setting_windows: ResMut<'w, OpenedSettingWindows>,
+    alphabet_settings: ResMut<'w, AlphabetSettings>,
+    script_system_settings: ResMut<'w, ScriptSystemSettings>,
+    normalization_settings: ResMut<'w, NormalizationSettings>,
+    sentence_arrangement_settings: ResMut<'w, SentenceArrangementSettings>,
+    sentence_frame_settings: ResMut<'w, SentenceFrameSettings>,
 }
 
 pub fn ui(ui: &mut egui::Ui, mut params: SettingsSystemParams) {
@@ -43,9 +68,115 @@ pub fn ui(ui: &mut egui::Ui, mut params: SettingsSystemParams) {
             params.color_theme.set_theme(new_theme, ui.ctx());
         }
 
+        ui.menu_button("Script", |ui| {
+            let selected = params.script_system_settings.selected;
+            let names: Vec<&str> = params
+                .script_system_settings
+                .script_systems
+                .iter()
+                .map(|script_system| script_system.name())
+                .collect();
+
+            for (index, name) in names.into_iter().enumerate() {
+                if ui.radio(index == selected, name).clicked() && index != selected {
+                    params.script_system_settings.selected = index;
+                    ui.close_menu();
+                }
+            }
+        });
+
+        ui.menu_button("Alphabet", |ui| {
+            let selected = params.alphabet_settings.selected;
+            let names: Vec<String> = params
+                .alphabet_settings
+                .alphabets
+                .iter()
+                .map(|alphabet| alphabet.name().to_string())
+                .collect();
+
+            for (index, name) in names.into_iter().enumerate() {
+                if ui.radio(index == selected, name).clicked() && index != selected {
+                    params.alphabet_settings.selected = index;
+                    ui.close_menu();
+                }
+            }
+        });
+
+        ui.menu_button("Unknown Characters", |ui| {
+            let policies = [
+                (NormalizationPolicy::Drop, "Drop"),
+                (NormalizationPolicy::Transliterate, "Transliterate (ä → a)"),
+                (
+                    NormalizationPolicy::ExpandUmlauts,
+                    "Expand umlauts (ä → ae)",
+                ),
+            ];
+
+            for (policy, name) in policies {
+                let selected = **params.normalization_settings == policy;
+                if ui.radio(selected, name).clicked() && !selected {
+                    **params.normalization_settings = policy;
+                    ui.close_menu();
+                }
+            }
+        });
+
+        ui.menu_button("Sentence Arrangement", |ui| {
+            let arrangements = [
+                (SentenceArrangement::Row, "Row"),
+                (SentenceArrangement::Grid, "Grid"),
+                (SentenceArrangement::Paragraph, "Paragraph"),
+                (SentenceArrangement::Free, "Free"),
+            ];
+
+            for (arrangement, name) in arrangements {
+                let selected = **params.sentence_arrangement_settings == arrangement;
+                if ui.radio(selected, name).clicked() && !selected {
+                    **params.sentence_arrangement_settings = arrangement;
+                    ui.close_menu();
+                }
+            }
+        });
+
+        ui.menu_button("Sentence Circle", |ui| {
+            let frames = [
+                (SentenceFrame::Double, "Double"),
+                (SentenceFrame::Single, "Single"),
+                (SentenceFrame::None, "None (single word as root)"),
+            ];
+
+            for (frame, name) in frames {
+                let selected = **params.sentence_frame_settings == frame;
+                if ui.radio(selected, name).clicked() && !selected {
+                    **params.sentence_frame_settings = frame;
+                    ui.close_menu();
+                }
+            }
+        });
+
         if ui.button("Vocal Nesting...").clicked() {
             params.opened_setting_windows.vocal_nesting = true;
             ui.close_menu();
         }
+
+        if ui.button("Animation...").clicked() {
+            params.opened_setting_windows.animation = true;
+            ui.close_menu();
+        }
+
+        if ui.button("Reading Direction...").clicked() {
+            params.opened_setting_windows.reading_direction = true;
+            ui.close_menu();
+        }
+
+        if ui.button("Decorations...").clicked() {
+            params.opened_setting_windows.decorations = true;
+            ui.close_menu();
+        }
+
+        if ui.button("Reference Image...").clicked() {
+            params.opened_setting_windows.reference_image = true;
+            ui.close_menu();
+        }
     });
 }
@@ -0,0 +1,47 @@
+use crate::plugins::svg::animation::DrawAnimation;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+pub fn ui(
+    mut egui_contexts: EguiContexts,
+    mut opened_setting_windows: ResMut<super::OpenedSettingWindows>,
+    mut draw_animation: ResMut<DrawAnimation>,
+) {
+    egui::Window::new("Animation")
+        .open(&mut opened_setting_windows.animation)
+        .show(egui_contexts.ctx_mut(), |ui| {
+            ui.label("Used for the animated svg export.");
+
+            egui::Grid::new("animation_settings").show(ui, |ui| {
+                let mut duration = draw_animation.duration;
+                ui.label("Duration per element");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut duration)
+                            .speed(0.05)
+                            .clamp_range(0.05..=10.0)
+                            .suffix("s"),
+                    )
+                    .changed()
+                {
+                    draw_animation.duration = duration;
+                }
+                ui.end_row();
+
+                let mut stagger = draw_animation.stagger;
+                ui.label("Delay between elements");
+                if ui
+                    .add(
+                        egui::DragValue::new(&mut stagger)
+                            .speed(0.05)
+                            .clamp_range(0.0..=10.0)
+                            .suffix("s"),
+                    )
+                    .changed()
+                {
+                    draw_animation.stagger = stagger;
+                }
+                ui.end_row();
+            });
+        });
+}
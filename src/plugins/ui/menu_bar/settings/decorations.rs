@@ -0,0 +1,31 @@
+use crate::plugins::text_converter::{DecorationLayout, DecorationLayoutSettings};
+use crate::plugins::ui::widgets::decoration_layout::ui_decoration_layout;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+pub fn ui(
+    mut egui_contexts: EguiContexts,
+    mut opened_setting_windows: ResMut<super::OpenedSettingWindows>,
+    mut decoration_layout_settings: ResMut<DecorationLayoutSettings>,
+) {
+    egui::Window::new("Decorations")
+        .open(&mut opened_setting_windows.decorations)
+        .show(egui_contexts.ctx_mut(), |ui| {
+            ui.label("How dots and lines are placed, 180° faces the center of the word.");
+            ui.label("Auto-fit spreads them around nested vocals and the word circle.");
+
+            let mut new_layout = ui_decoration_layout(
+                ui,
+                "decoration_layout_settings",
+                &decoration_layout_settings,
+            );
+
+            if ui.button("Reset").clicked() {
+                new_layout = DecorationLayout::default();
+            }
+
+            if new_layout != **decoration_layout_settings {
+                **decoration_layout_settings = new_layout;
+            }
+        });
+}
@@ -0,0 +1,55 @@
+use crate::math::angle::{Angle, Degree};
+use crate::plugins::text_converter::ReadingDirectionSettings;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+pub fn ui(
+    mut egui_contexts: EguiContexts,
+    mut opened_setting_windows: ResMut<super::OpenedSettingWindows>,
+    mut reading_direction_settings: ResMut<ReadingDirectionSettings>,
+) {
+    egui::Window::new("Reading Direction")
+        .open(&mut opened_setting_windows.reading_direction)
+        .show(egui_contexts.ctx_mut(), |ui| {
+            ui.label("Where the first word and letter are placed, 0° is at 6 o'clock.");
+
+            let mut new_settings = *reading_direction_settings;
+
+            egui::Grid::new("reading_direction_settings").show(ui, |ui| {
+                let mut start_angle = new_settings.reading_direction.start_angle.inner();
+                ui.label("Start angle");
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::DragValue::new(&mut start_angle)
+                            .speed(1.0)
+                            .clamp_range(0.0..=359.0)
+                            .suffix("°"),
+                    );
+
+                    for (angle, name) in [(0.0, "6 o'clock"), (180.0, "12 o'clock")] {
+                        if ui.button(name).clicked() {
+                            start_angle = angle;
+                        }
+                    }
+                });
+                new_settings.reading_direction.start_angle = Degree::new(start_angle);
+                ui.end_row();
+
+                let clockwise = &mut new_settings.reading_direction.clockwise;
+                ui.label("Direction");
+                ui.horizontal(|ui| {
+                    ui.radio_value(clockwise, false, "Counter-clockwise");
+                    ui.radio_value(clockwise, true, "Clockwise");
+                });
+                ui.end_row();
+
+                ui.label("Start marker");
+                ui.checkbox(&mut new_settings.show_start_marker, "");
+                ui.end_row();
+            });
+
+            if new_settings != *reading_direction_settings {
+                *reading_direction_settings = new_settings;
+            }
+        });
+}
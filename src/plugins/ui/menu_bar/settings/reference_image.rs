@@ -0,0 +1,73 @@
+use crate::plugins::file::{FileActions, FileHandleAction};
+use crate::plugins::reference_image::ReferenceImage;
+use crate::utils::event_set::SendEvent;
+use bevy::prelude::*;
+use bevy_egui::{egui, EguiContexts};
+
+pub fn ui(
+    mut commands: Commands,
+    mut egui_contexts: EguiContexts,
+    mut opened_setting_windows: ResMut<super::OpenedSettingWindows>,
+    mut file_actions: FileActions,
+    mut reference_image_query: Query<(Entity, &mut ReferenceImage)>,
+) {
+    egui::Window::new("Reference Image")
+        .open(&mut opened_setting_windows.reference_image)
+        .show(egui_contexts.ctx_mut(), |ui| {
+            ui.horizontal(|ui| {
+                if ui.button("Load image...").clicked() {
+                    file_actions.dispatch(FileHandleAction::OpenReferenceImage);
+                }
+
+                if let Ok((entity, _)) = reference_image_query.get_single() {
+                    if ui.button("Remove").clicked() {
+                        commands.entity(entity).despawn();
+                    }
+                }
+            });
+
+            if let Ok((_, mut reference_image)) = reference_image_query.get_single_mut() {
+                let mut new_reference_image = reference_image.clone();
+
+                egui::Grid::new("reference_image").show(ui, |ui| {
+                    ui.label("Opacity");
+                    ui.add(egui::Slider::new(
+                        &mut new_reference_image.opacity,
+                        0.0..=1.0,
+                    ));
+                    ui.end_row();
+
+                    ui.label("Scale");
+                    ui.add(
+                        egui::DragValue::new(&mut new_reference_image.scale)
+                            .speed(0.01)
+                            .clamp_range(0.01..=100.0),
+                    );
+                    ui.end_row();
+
+                    ui.label("Offset");
+                    ui.horizontal(|ui| {
+                        ui.add(
+                            egui::DragValue::new(&mut new_reference_image.offset.x).prefix("x: "),
+                        );
+                        ui.add(
+                            egui::DragValue::new(&mut new_reference_image.offset.y).prefix("y: "),
+                        );
+                    });
+                    ui.end_row();
+
+                    ui.label("Rotation");
+                    ui.add(
+                        egui::DragValue::new(&mut new_reference_image.rotation)
+                            .suffix("°")
+                            .clamp_range(-360.0..=360.0),
+                    );
+                    ui.end_row();
+                });
+
+                if *reference_image != new_reference_image {
+                    *reference_image = new_reference_image;
+                }
+            }
+        });
+}
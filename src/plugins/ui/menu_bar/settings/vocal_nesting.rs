@@ -1,8 +1,9 @@
+use crate::plugins::text_converter::alphabet::AlphabetSettings;
 use crate::plugins::text_converter::components::{Consonant, NestingSettings, Vocal};
 use bevy::prelude::*;
 use bevy::utils::hashbrown::HashSet;
 use bevy_egui::{egui, EguiContexts};
-use strum::IntoEnumIterator;
+use gallifreyan_core::alphabet::parse_rules_string;
 
 #[derive(Default)]
 pub struct Rules {
@@ -15,6 +16,7 @@ pub fn ui(
     mut egui_contexts: EguiContexts,
     mut opened_setting_windows: ResMut<super::OpenedSettingWindows>,
     mut nesting_settings: ResMut<NestingSettings>,
+    alphabet_settings: Res<AlphabetSettings>,
     mut rules: Local<Rules>,
 ) {
     egui::Window::new("Vocal Nesting")
@@ -57,9 +59,10 @@ pub fn ui(
                 ui.label("You can use '*' as a wildcard.");
 
                 if ui.text_edit_singleline(&mut rules.text).changed() {
-                    match parse_rules_string(&rules.text) {
+                    match parse_rules_string(&rules.text, alphabet_settings.current()) {
                         Ok(new_rules) => {
-                            *nesting_settings = NestingSettings::Custom(new_rules);
+                            *nesting_settings =
+                                NestingSettings::Custom(new_rules.into_iter().collect());
                             rules.parse_error = None;
                         }
                         Err(error) => {
@@ -74,67 +77,3 @@ pub fn ui(
             });
         });
 }
-
-fn parse_rules_string(rules: &str) -> Result<HashSet<(Consonant, Vocal)>, String> {
-    let mut rules_map = HashSet::new();
-
-    for rule in rules.split(',') {
-        match rule.len() {
-            0 => {
-                return Err("Rule can't be empty!".to_string());
-            }
-            1 => {
-                return if rule == "*" {
-                    Err("Missing a consonant or vocal!".to_string())
-                } else if Consonant::try_from(rule).is_ok() {
-                    Err(format!("'{}' is missing a vocal!", rule))
-                } else if Vocal::try_from(rule).is_ok() {
-                    Err(format!("'{}' is missing a consonant!", rule))
-                } else {
-                    Err(format!("'{}' is not a valid letter!", rule))
-                };
-            }
-            2 => {
-                if Consonant::try_from(rule).is_ok() {
-                    return Err(format!("'{}' is missing a vocal!", rule));
-                }
-
-                let (consonant, vocal) = rule.split_at(1);
-
-                if consonant == "*" {
-                    let vocal = Vocal::try_from(vocal)?;
-                    for consonant in Consonant::iter() {
-                        rules_map.insert((consonant, vocal));
-                    }
-                } else if vocal == "*" {
-                    let consonant = Consonant::try_from(consonant)?;
-                    for vocal in Vocal::iter() {
-                        rules_map.insert((consonant, vocal));
-                    }
-                } else {
-                    let consonant = Consonant::try_from(consonant)?;
-                    let vocal = Vocal::try_from(vocal)?;
-                    rules_map.insert((consonant, vocal));
-                }
-            }
-            3 => {
-                let (consonant, vocal) = rule.split_at(2);
-                let consonant = Consonant::try_from(consonant)?;
-
-                if vocal == "*" {
-                    for vocal in Vocal::iter() {
-                        rules_map.insert((consonant, vocal));
-                    }
-                } else {
-                    let vocal = Vocal::try_from(vocal)?;
-                    rules_map.insert((consonant, vocal));
-                }
-            }
-            _ => {
-                return Err(format!("Too many letters in '{}'!", rule));
-            }
-        }
-    }
-
-    Ok(rules_map)
-}
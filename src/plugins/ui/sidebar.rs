@@ -1,12 +1,13 @@
 mod selection;
 mod text_input;
+mod text_preview;
 mod tree;
 
 use super::{UiBaseSet, UiSet};
 use bevy::prelude::*;
 use bevy_egui::{egui, EguiContexts};
 use selection::{ui_selection, SelectionSystemParams};
-use text_input::{ui_text_input, TextInputSystemParams, TextState};
+use text_input::{sync_text_input, ui_text_input, TextInputSystemParams, TextState};
 use tree::{add_is_open_component, ui_tree, TreeSystemParams};
 
 pub struct SideBarPlugin;
@@ -15,7 +16,8 @@ impl Plugin for SideBarPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<TextState>()
             .add_system(ui.in_base_set(UiBaseSet).in_set(UiSet::SideBar))
-            .add_system(add_is_open_component);
+            .add_system(add_is_open_component)
+            .add_system(sync_text_input);
     }
 }
 
@@ -1,39 +1,66 @@
 use crate::math::angle::{Angle, Degree, Radian};
 use crate::plugins::selection::Selected;
+use crate::plugins::svg::StyleOverride;
+use crate::plugins::text_converter::alphabet::AlphabetSettings;
 use crate::plugins::text_converter::components::{
-    ConsonantPlacement, Letter, LineSlot, PositionData, Radius, VocalPlacement,
+    ConsonantPlacement, CustomDecoration, DecorationLayoutOverride, ElementId, Letter, LineSlot,
+    PositionData, Radius, VocalPlacement,
 };
+use crate::plugins::text_converter::decoration::{AddDecoration, RemoveDecoration};
+use crate::plugins::text_converter::DecorationLayoutSettings;
 use crate::plugins::ui::widgets::angle_slider::AngleSlider;
+use crate::plugins::ui::widgets::decoration_layout::ui_decoration_layout;
 use crate::utils::update_if_changed::update_if_changed;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_egui::egui;
+use gallifreyan_core::alphabet;
 
 #[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
 pub struct SelectionSystemParams<'w, 's> {
     selection_query: Query<
         'w,
         's,
         (
+            Entity,
+            Option<&'static ElementId>,
             Option<&'static Parent>,
             Option<&'static mut Radius>,
             &'static mut PositionData,
             Option<&'static Letter>,
             Option<&'static LineSlot>,
+            Option<&'static mut StyleOverride>,
+            Option<&'static mut DecorationLayoutOverride>,
+            Option<&'static CustomDecoration>,
         ),
         With<Selected>,
     >,
     global_transform_query: Query<'w, 's, &'static GlobalTransform>,
+    decoration_layout_settings: Res<'w, DecorationLayoutSettings>,
+    alphabet_settings: Res<'w, AlphabetSettings>,
+    add_decoration_events: EventWriter<'w, AddDecoration>,
+    remove_decoration_events: EventWriter<'w, RemoveDecoration>,
 }
 
 pub fn ui_selection(ui: &mut egui::Ui, mut params: SelectionSystemParams) {
-    let (parent, mut radius, mut position_data, letter, line_slot) =
-        match params.selection_query.get_single_mut() {
-            Ok(it) => it,
-            Err(_) => {
-                return;
-            }
-        };
+    let (
+        entity,
+        element_id,
+        parent,
+        mut radius,
+        mut position_data,
+        letter,
+        line_slot,
+        mut style_override,
+        mut decoration_layout,
+        custom_decoration,
+    ) = match params.selection_query.get_single_mut() {
+        Ok(it) => it,
+        Err(_) => {
+            return;
+        }
+    };
 
     egui::TopBottomPanel::bottom("selection")
         .frame(egui::Frame::none())
@@ -42,20 +69,26 @@ pub fn ui_selection(ui: &mut egui::Ui, mut params: SelectionSystemParams) {
                 let original_slider_width = ui.spacing().slider_width;
                 ui.spacing_mut().slider_width = ui.available_width();
 
+                if let Some(element_id) = element_id {
+                    ui.label(format!("Id: {}", element_id));
+                }
+
                 if let Some(radius) = &mut radius {
                     let new_radius = ui_radius(ui, ***radius);
 
                     update_if_changed!(***radius, new_radius, "Update radius: {} -> {}");
                 }
 
+                let alphabet = params.alphabet_settings.current();
+
                 let can_change_distance = letter
-                    .map(|letter| match letter {
-                        Letter::Vocal(vocal) => {
-                            VocalPlacement::from(*vocal) != VocalPlacement::OnLine
+                    .map(|letter| match **letter {
+                        alphabet::Letter::Vocal(vocal) => {
+                            alphabet.vocal_placement(vocal) != VocalPlacement::OnLine
                         }
-                        Letter::Consonant(consonant)
-                        | Letter::ConsonantWithVocal { consonant, .. } => {
-                            ConsonantPlacement::from(*consonant) != ConsonantPlacement::OnLine
+                        alphabet::Letter::Consonant(consonant)
+                        | alphabet::Letter::ConsonantWithVocal { consonant, .. } => {
+                            alphabet.consonant_placement(consonant) != ConsonantPlacement::OnLine
                         }
                     })
                     .unwrap_or_else(|| line_slot.is_none());
@@ -82,6 +115,56 @@ pub fn ui_selection(ui: &mut egui::Ui, mut params: SelectionSystemParams) {
                 update_if_changed!(position_data.angle, new_angle, "Update angle: {:?} -> {:?}");
 
                 ui.spacing_mut().slider_width = original_slider_width;
+
+                if let Some(style_override) = &mut style_override {
+                    let new_style_override = ui_style_override(ui, style_override);
+
+                    update_if_changed!(
+                        **style_override,
+                        new_style_override,
+                        "Update style override: {:?} -> {:?}"
+                    );
+                }
+
+                let has_decorations = letter
+                    .is_some_and(|letter| letter.dots(alphabet) > 0 || letter.lines(alphabet) > 0);
+
+                if has_decorations {
+                    if let Some(decoration_layout) = &mut decoration_layout {
+                        let new_decoration_layout = ui_decoration_layout_override(
+                            ui,
+                            decoration_layout,
+                            &params.decoration_layout_settings,
+                        );
+
+                        update_if_changed!(
+                            **decoration_layout,
+                            new_decoration_layout,
+                            "Update decoration layout: {:?} -> {:?}"
+                        );
+                    }
+                }
+
+                if letter.is_some() {
+                    if let Some(decoration) = ui_add_decoration(ui) {
+                        params.add_decoration_events.send(AddDecoration {
+                            letter: entity,
+                            decoration,
+                            position: None,
+                        });
+                    }
+                }
+
+                if custom_decoration.is_some()
+                    && ui
+                        .button("Remove decoration")
+                        .on_hover_text("Or press Delete")
+                        .clicked()
+                {
+                    params
+                        .remove_decoration_events
+                        .send(RemoveDecoration(entity));
+                }
             });
         });
 }
@@ -150,3 +233,132 @@ fn ui_angle(
 
     new_angle
 }
+
+fn ui_style_override(ui: &mut egui::Ui, style_override: &StyleOverride) -> StyleOverride {
+    ui.label("Style");
+
+    let mut new_style_override = style_override.clone();
+
+    egui::Grid::new("style_override")
+        .num_columns(2)
+        .show(ui, |ui| {
+            let mut has_color = new_style_override.color.is_some();
+            ui.checkbox(&mut has_color, "Color");
+
+            if has_color {
+                let [r, g, b, _a] = new_style_override
+                    .color
+                    .unwrap_or(Color::WHITE)
+                    .as_rgba_f32();
+                let mut rgb = [r, g, b];
+                ui.color_edit_button_rgb(&mut rgb);
+                new_style_override.color = Some(Color::rgb(rgb[0], rgb[1], rgb[2]));
+            } else {
+                new_style_override.color = None;
+            }
+
+            ui.end_row();
+
+            let mut has_width = new_style_override.width.is_some();
+            ui.checkbox(&mut has_width, "Width");
+
+            if has_width {
+                let mut width = new_style_override.stroke_width();
+                ui.add(
+                    egui::DragValue::new(&mut width)
+                        .clamp_range(0.1..=50.0)
+                        .speed(0.1),
+                );
+                new_style_override.width = Some(width);
+            } else {
+                new_style_override.width = None;
+            }
+
+            ui.end_row();
+
+            let mut is_dashed = !new_style_override.dash_pattern.is_empty();
+            ui.checkbox(&mut is_dashed, "Dashed");
+
+            if is_dashed {
+                if new_style_override.dash_pattern.len() < 2 {
+                    new_style_override.dash_pattern = vec![5.0, 5.0];
+                }
+
+                ui.horizontal(|ui| {
+                    for value in new_style_override.dash_pattern.iter_mut() {
+                        ui.add(
+                            egui::DragValue::new(value)
+                                .clamp_range(0.0..=100.0)
+                                .speed(0.1),
+                        );
+                    }
+                });
+            } else {
+                new_style_override.dash_pattern.clear();
+            }
+
+            ui.end_row();
+
+            let mut has_opacity = new_style_override.opacity.is_some();
+            ui.checkbox(&mut has_opacity, "Opacity");
+
+            if has_opacity {
+                let mut opacity = new_style_override.opacity.unwrap_or(1.0);
+                ui.add(egui::Slider::new(&mut opacity, 0.0..=1.0));
+                new_style_override.opacity = Some(opacity);
+            } else {
+                new_style_override.opacity = None;
+            }
+
+            ui.end_row();
+        });
+
+    new_style_override
+}
+
+fn ui_decoration_layout_override(
+    ui: &mut egui::Ui,
+    decoration_layout: &DecorationLayoutOverride,
+    decoration_layout_settings: &DecorationLayoutSettings,
+) -> DecorationLayoutOverride {
+    let mut is_custom = decoration_layout.is_some();
+    ui.checkbox(&mut is_custom, "Custom decorations");
+
+    if !is_custom {
+        return DecorationLayoutOverride(None);
+    }
+
+    let layout = decoration_layout.unwrap_or(**decoration_layout_settings);
+
+    DecorationLayoutOverride(Some(ui_decoration_layout(
+        ui,
+        "decoration_layout_override",
+        &layout,
+    )))
+}
+
+fn ui_add_decoration(ui: &mut egui::Ui) -> Option<CustomDecoration> {
+    ui.label("Add decoration");
+
+    let mut decoration = None;
+
+    ui.horizontal(|ui| {
+        let decorations = [
+            (CustomDecoration::Dot, "Dot", "D"),
+            (CustomDecoration::Line, "Line", "L"),
+            (CustomDecoration::Circle, "Circle", "C"),
+        ];
+
+        for (new_decoration, name, key) in decorations {
+            if ui
+                .button(name)
+                .on_hover_text(format!("Or press {} to add it at the cursor", key))
+                .clicked()
+            {
+                decoration = Some(new_decoration);
+            }
+        }
+    });
+
+    decoration
+}
@@ -1,18 +1,51 @@
-use crate::plugins::text_converter::{sanitize_text_input, SetText};
+use super::text_preview::{ui_text_preview, TextPreviewSystemParams};
+use crate::plugins::text_converter::alphabet::AlphabetSettings;
+use crate::plugins::text_converter::components::{
+    join_sentence_texts, Sentence, SentenceIndex, Text,
+};
+use crate::plugins::text_converter::{normalize_sentences, NormalizationSettings, SetText};
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_egui::egui;
+use gallifreyan_core::alphabet::SENTENCE_SEPARATOR;
+use gallifreyan_core::normalization::{NormalizationPolicy, TextChange, TextChangeKind};
+use gallifreyan_core::phonetic::PhoneticRules;
 
 #[derive(Resource, Default)]
 pub struct TextState {
     text: String,
     sanitized_text: String,
+    /// Converts the text with [`PhoneticRules::english`] into an editable phonetic spelling which
+    /// gets converted instead of the text.
+    phonetic: bool,
+    phonetic_text: String,
+    /// What happened to the characters of the converted text which aren't part of the alphabet.
+    changes: Vec<TextChange>,
+}
+
+impl TextState {
+    fn converted_text(&self) -> &str {
+        if self.phonetic {
+            &self.phonetic_text
+        } else {
+            &self.text
+        }
+    }
+
+    /// Transliterates the text with the policy first, so the phonetic rules see whole words.
+    fn update_phonetic_text(&mut self, policy: NormalizationPolicy) {
+        self.phonetic_text =
+            PhoneticRules::english().transliterate(&policy.transliterate_text(&self.text));
+    }
 }
 
 #[derive(SystemParam)]
-pub struct TextInputSystemParams<'w> {
+pub struct TextInputSystemParams<'w, 's> {
     ui_state: ResMut<'w, TextState>,
+    normalization_settings: Res<'w, NormalizationSettings>,
+    alphabet_settings: Res<'w, AlphabetSettings>,
     set_text_event: EventWriter<'w, SetText>,
+    preview: TextPreviewSystemParams<'w, 's>,
 }
 
 pub fn ui_text_input(ui: &mut egui::Ui, mut params: TextInputSystemParams) {
@@ -22,16 +55,96 @@ pub fn ui_text_input(ui: &mut egui::Ui, mut params: TextInputSystemParams) {
             let original_text_edit_width = ui.spacing().text_edit_width;
             ui.spacing_mut().text_edit_width = ui.available_width();
 
-            if ui.text_edit_singleline(&mut params.ui_state.text).changed() {
-                let new_sanitized_text = sanitize_text_input(&params.ui_state.text);
-                if params.ui_state.sanitized_text != new_sanitized_text {
-                    params.ui_state.sanitized_text = new_sanitized_text;
+            let ui_state = &mut *params.ui_state;
+
+            let text_changed = ui.text_edit_singleline(&mut ui_state.text).changed();
+            let phonetic_toggled = ui
+                .checkbox(&mut ui_state.phonetic, "Phonetic spelling")
+                .changed();
+
+            if ui_state.phonetic
+                && (text_changed || phonetic_toggled || params.normalization_settings.is_changed())
+            {
+                ui_state.update_phonetic_text(**params.normalization_settings);
+            }
+
+            let phonetic_text_changed = ui_state.phonetic
+                && ui
+                    .text_edit_singleline(&mut ui_state.phonetic_text)
+                    .changed();
+
+            if text_changed
+                || phonetic_toggled
+                || phonetic_text_changed
+                || params.normalization_settings.is_changed()
+                || params.alphabet_settings.is_changed()
+            {
+                let normalized = normalize_sentences(
+                    ui_state.converted_text(),
+                    **params.normalization_settings,
+                    params.alphabet_settings.current(),
+                );
+                ui_state.changes = normalized.changes;
+
+                if ui_state.sanitized_text != normalized.text {
+                    ui_state.sanitized_text = normalized.text;
                     params
                         .set_text_event
-                        .send(SetText(params.ui_state.sanitized_text.clone()));
+                        .send(SetText(ui_state.sanitized_text.clone()));
                 }
             }
 
+            ui_text_preview(
+                ui,
+                &ui_state.sanitized_text,
+                &ui_state.changes,
+                &mut params.preview,
+            );
+
+            if !ui_state.changes.is_empty() {
+                let title = format!("{} characters changed", ui_state.changes.len());
+                egui::CollapsingHeader::new(title)
+                    .id_source("text_changes")
+                    .show(ui, |ui| {
+                        for change in &ui_state.changes {
+                            ui.label(change_label(change));
+                        }
+                    });
+            }
+
             ui.spacing_mut().text_edit_width = original_text_edit_width;
         });
 }
+
+fn change_label(change: &TextChange) -> String {
+    match &change.kind {
+        TextChangeKind::Transformed(text) => {
+            format!("{} → {} at {}", change.character, text, change.position)
+        }
+        TextChangeKind::Dropped => format!("{} dropped at {}", change.character, change.position),
+    }
+}
+
+/// Keeps the input in sync if the sentences were changed from somewhere else, e.g. by an import.
+/// The phonetic spelling is derived from the new text again.
+#[allow(clippy::type_complexity)]
+pub fn sync_text_input(
+    mut ui_state: ResMut<TextState>,
+    normalization_settings: Res<NormalizationSettings>,
+    changed_query: Query<(), (With<Sentence>, Without<Parent>, Changed<Text>)>,
+    sentence_query: Query<(Option<&SentenceIndex>, &Text), (With<Sentence>, Without<Parent>)>,
+) {
+    if changed_query.is_empty() {
+        return;
+    }
+
+    let text = join_sentence_texts(sentence_query.iter());
+
+    if ui_state.sanitized_text != text {
+        let input = text.replace(SENTENCE_SEPARATOR, ". ");
+        ui_state.text = input;
+        ui_state.update_phonetic_text(**normalization_settings);
+        ui_state.sanitized_text = text;
+        ui_state.changes.clear();
+    }
+}
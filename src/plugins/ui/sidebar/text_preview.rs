@@ -0,0 +1,305 @@
+use crate::plugins::selection::Highlight;
+use crate::plugins::text_converter::alphabet::AlphabetSettings;
+use crate::plugins::text_converter::components::{
+    CircleChildren, NestedLetter, NestingSettings, Sentence, SentenceIndex, Word,
+};
+use crate::plugins::text_converter::script::ScriptSystemSettings;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_egui::egui;
+use gallifreyan_core::alphabet::{
+    create_letters_from_word, Alphabet, Letter, Nesting, NESTED_LETTER_TEXT_DELIMITER,
+    SENTENCE_SEPARATOR,
+};
+use gallifreyan_core::normalization::{TextChange, TextChangeKind};
+use gallifreyan_core::script::{ScriptSystem, SHERMAN_ID};
+
+const CONSONANT_COLOR: egui::Color32 = egui::Color32::from_rgba_premultiplied(40, 80, 146, 60);
+const VOCAL_COLOR: egui::Color32 = egui::Color32::from_rgba_premultiplied(31, 101, 50, 60);
+
+/// A letter of the preview.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chip {
+    pub text: String,
+    /// `None` for script systems without Sherman's letters.
+    pub letter: Option<Letter>,
+}
+
+impl Chip {
+    fn tooltip(&self, alphabet: &Alphabet) -> String {
+        match self.letter {
+            Some(Letter::Vocal(vocal)) => {
+                format!("{:?}, {:?}", vocal, alphabet.vocal_placement(vocal))
+            }
+            Some(Letter::Consonant(consonant)) => {
+                format!(
+                    "{:?}, {:?}",
+                    consonant,
+                    alphabet.consonant_placement(consonant)
+                )
+            }
+            Some(Letter::ConsonantWithVocal { consonant, vocal }) => {
+                format!("{:?} with nested {:?}", consonant, vocal)
+            }
+            None => self.text.clone(),
+        }
+    }
+}
+
+/// Splits the sanitized text into the letters which get converted, with nested pairs for
+/// Sherman's alphabet.
+pub fn preview_words(
+    text: &str,
+    script_system: &dyn ScriptSystem,
+    nesting: &impl Nesting,
+    alphabet: &Alphabet,
+) -> Vec<Vec<Chip>> {
+    text.split_whitespace()
+        .map(|word| match script_system.id() {
+            SHERMAN_ID => create_letters_from_word(word, nesting, alphabet)
+                .into_iter()
+                .map(|(text, letter)| Chip {
+                    text,
+                    letter: Some(letter),
+                })
+                .collect(),
+            _ => script_system
+                .split_word(word)
+                .into_iter()
+                .map(|text| Chip {
+                    text: text.to_string(),
+                    letter: None,
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub struct TextPreviewSystemParams<'w, 's> {
+    nesting_settings: Res<'w, NestingSettings>,
+    script_system_settings: Res<'w, ScriptSystemSettings>,
+    alphabet_settings: Res<'w, AlphabetSettings>,
+    sentence_query: Query<
+        'w,
+        's,
+        (Option<&'static SentenceIndex>, &'static CircleChildren),
+        (With<Sentence>, Without<Parent>),
+    >,
+    word_query: Query<'w, 's, &'static CircleChildren, With<Word>>,
+    nested_letter_query: Query<'w, 's, &'static NestedLetter>,
+    highlight_event: EventWriter<'w, Highlight>,
+    hovered: Local<'s, Option<Entity>>,
+}
+
+impl<'w, 's> TextPreviewSystemParams<'w, 's> {
+    fn letter_entity(
+        &self,
+        sentence_index: usize,
+        word_index: usize,
+        letter_index: usize,
+    ) -> Option<Entity> {
+        let (_, words) = self
+            .sentence_query
+            .iter()
+            .find(|(index, _)| index.map_or(0, |it| **it) == sentence_index)?;
+        let letters = self.word_query.get(*words.get(word_index)?).ok()?;
+        letters.get(letter_index).copied()
+    }
+}
+
+pub fn ui_text_preview(
+    ui: &mut egui::Ui,
+    text: &str,
+    changes: &[TextChange],
+    params: &mut TextPreviewSystemParams,
+) {
+    let script_system = params.script_system_settings.current();
+    let alphabet = params.alphabet_settings.current();
+    let is_sherman = script_system.id() == SHERMAN_ID;
+    let sentences: Vec<_> = text
+        .split(SENTENCE_SEPARATOR)
+        .map(|sentence| {
+            preview_words(
+                sentence,
+                &**script_system,
+                &*params.nesting_settings,
+                alphabet,
+            )
+        })
+        .collect();
+
+    let mut hovered = None;
+
+    ui.horizontal_wrapped(|ui| {
+        ui.spacing_mut().item_spacing = egui::vec2(2.0, 4.0);
+
+        for (sentence_index, words) in sentences.iter().enumerate() {
+            if sentence_index > 0 {
+                ui.end_row();
+            }
+
+            for (word_index, word) in words.iter().enumerate() {
+                if word_index > 0 {
+                    ui.add_space(8.0);
+                }
+
+                for (letter_index, chip) in word.iter().enumerate() {
+                    let letter_entity = if is_sherman {
+                        params.letter_entity(sentence_index, word_index, letter_index)
+                    } else {
+                        None
+                    };
+
+                    let response = match chip.letter {
+                        Some(Letter::ConsonantWithVocal { .. }) => {
+                            let (consonant, vocal) = chip
+                                .text
+                                .split_once(NESTED_LETTER_TEXT_DELIMITER)
+                                .unwrap_or((&chip.text, ""));
+
+                            let inner = egui::Frame::none()
+                                .stroke(ui.visuals().widgets.noninteractive.bg_stroke)
+                                .rounding(4.0)
+                                .inner_margin(egui::style::Margin::same(1.0))
+                                .show(ui, |ui| {
+                                    ui.spacing_mut().item_spacing.x = 1.0;
+                                    let consonant_response =
+                                        ui_chip(ui, consonant, CONSONANT_COLOR);
+                                    let vocal_response = ui_chip(ui, vocal, VOCAL_COLOR);
+                                    (consonant_response, vocal_response)
+                                });
+
+                            let (consonant_response, vocal_response) = inner.inner;
+
+                            if vocal_response.hovered() {
+                                hovered = letter_entity
+                                    .and_then(|it| params.nested_letter_query.get(it).ok())
+                                    .and_then(|it| **it);
+                            } else if consonant_response.hovered() {
+                                hovered = letter_entity;
+                            }
+
+                            inner.response
+                        }
+                        Some(Letter::Vocal(_)) => {
+                            let response = ui_chip(ui, &chip.text, VOCAL_COLOR);
+                            if response.hovered() {
+                                hovered = letter_entity;
+                            }
+                            response
+                        }
+                        Some(Letter::Consonant(_)) | None => {
+                            let response = ui_chip(ui, &chip.text, CONSONANT_COLOR);
+                            if response.hovered() {
+                                hovered = letter_entity;
+                            }
+                            response
+                        }
+                    };
+
+                    response.on_hover_text(chip.tooltip(alphabet));
+                }
+            }
+        }
+
+        let dropped = changes
+            .iter()
+            .filter(|change| change.kind == TextChangeKind::Dropped);
+
+        for (index, change) in dropped.enumerate() {
+            if index == 0 {
+                ui.add_space(8.0);
+            }
+
+            let error_color = ui.visuals().error_fg_color;
+
+            egui::Frame::none()
+                .stroke(egui::Stroke::new(1.0, error_color))
+                .rounding(4.0)
+                .inner_margin(egui::style::Margin::symmetric(4.0, 1.0))
+                .show(ui, |ui| {
+                    ui.colored_label(error_color, change.character.to_string());
+                })
+                .response
+                .on_hover_text(format!(
+                    "'{}' at {} isn't part of the alphabet and gets dropped",
+                    change.character, change.position
+                ));
+        }
+    });
+
+    if *params.hovered != hovered {
+        *params.hovered = hovered;
+        params.highlight_event.send(Highlight(hovered));
+    }
+}
+
+fn ui_chip(ui: &mut egui::Ui, text: &str, fill: egui::Color32) -> egui::Response {
+    egui::Frame::none()
+        .fill(fill)
+        .rounding(4.0)
+        .inner_margin(egui::style::Margin::symmetric(4.0, 1.0))
+        .show(ui, |ui| ui.label(text))
+        .response
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use gallifreyan_core::alphabet::{Consonant, Vocal};
+    use gallifreyan_core::script::{find_script_system, Sherman};
+
+    #[test]
+    fn should_group_nested_letters() {
+        let words = preview_words(
+            "that ngo",
+            &Sherman::default(),
+            &NestingSettings::All,
+            &Alphabet::sherman(),
+        );
+
+        let texts: Vec<Vec<&str>> = words
+            .iter()
+            .map(|word| word.iter().map(|chip| chip.text.as_str()).collect())
+            .collect();
+
+        assert_eq!(texts, [vec!["th~a", "t"], vec!["ng~o"]]);
+        assert_eq!(
+            words[1][0].letter,
+            Some(Letter::ConsonantWithVocal {
+                consonant: Consonant::NG,
+                vocal: Vocal::O
+            })
+        );
+    }
+
+    #[test]
+    fn should_not_nest_without_nesting_settings() {
+        let words = preview_words(
+            "ba",
+            &Sherman::default(),
+            &NestingSettings::None,
+            &Alphabet::sherman(),
+        );
+
+        assert_eq!(words[0].len(), 2);
+        assert_eq!(words[0][1].letter, Some(Letter::Vocal(Vocal::A)));
+    }
+
+    #[test]
+    fn should_split_with_other_script_systems() {
+        let clockwork = find_script_system("clockwork").unwrap();
+
+        let words = preview_words(
+            "that",
+            &*clockwork,
+            &NestingSettings::All,
+            &Alphabet::sherman(),
+        );
+
+        assert_eq!(words[0].len(), 4);
+        assert_eq!(words[0][0].letter, None);
+    }
+}
@@ -1,12 +1,16 @@
 use crate::plugins::selection::{Select, Selected};
+use crate::plugins::text_converter::alphabet::AlphabetSettings;
 use crate::plugins::text_converter::components::{
-    CircleChildren, Letter, LineSlotChildren, NestedLetter, NestedVocal, Sentence, Text, Word,
+    join_sentence_texts, CircleChildren, Letter, LineSlotChildren, NestedLetter, NestedVocal,
+    Sentence, SentenceIndex, Text, Word,
 };
+use crate::plugins::text_converter::SetText;
 use crate::plugins::ui::widgets::tree::CollapsingTreeItem;
 use crate::utils::contains::Contains;
 use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_egui::egui;
+use gallifreyan_core::alphabet::{Alphabet, NESTED_LETTER_TEXT_DELIMITER};
 
 type WorldQuery = (
     Entity,
@@ -19,6 +23,13 @@ type WorldQuery = (
 type SentenceQuery<'w, 's> =
     Query<'w, 's, WorldQuery, (With<Sentence>, Without<Word>, Without<Letter>)>;
 
+type RootSentenceQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, Option<&'static SentenceIndex>, &'static Text),
+    (With<Sentence>, Without<Parent>),
+>;
+
 type WordQuery<'w, 's> =
     Query<'w, 's, WorldQuery, (With<Word>, Without<Sentence>, Without<Letter>)>;
 
@@ -61,15 +72,20 @@ type NestedLetterQuery<'w, 's> = Query<
 #[derive(SystemParam)]
 pub struct TreeSystemParams<'w, 's> {
     sentence_query: SentenceQuery<'w, 's>,
+    root_sentence_query: RootSentenceQuery<'w, 's>,
     word_query: WordQuery<'w, 's>,
     letter_query: LetterQuery<'w, 's>,
     nested_letter_query: NestedLetterQuery<'w, 's>,
     select_event: EventWriter<'w, Select>,
+    set_text_event: EventWriter<'w, SetText>,
     selected_query: Query<'w, 's, Entity, With<Selected>>,
+    alphabet_settings: Res<'w, AlphabetSettings>,
 }
 
 pub fn ui_tree(ui: &mut egui::Ui, mut params: TreeSystemParams) {
     let selection = params.selected_query.get_single().ok();
+    let alphabet = params.alphabet_settings.current();
+    let mut edited_sentence = None;
 
     egui::CentralPanel::default().show_inside(ui, |ui| {
         egui::ScrollArea::vertical()
@@ -85,7 +101,7 @@ pub fn ui_tree(ui: &mut egui::Ui, mut params: TreeSystemParams) {
                         selection.contains(&sentence_entity),
                     )
                     .show(ui, |ui| {
-                        ui_words(
+                        let new_sentence_text = ui_words(
                             ui,
                             words,
                             &mut params.word_query,
@@ -93,7 +109,13 @@ pub fn ui_tree(ui: &mut egui::Ui, mut params: TreeSystemParams) {
                             &mut params.nested_letter_query,
                             &mut params.select_event,
                             &selection,
+                            alphabet,
                         );
+
+                        if let Some(new_sentence_text) = new_sentence_text {
+                            edited_sentence = Some((sentence_entity, Text(new_sentence_text)));
+                        }
+
                         ui_line_slots(
                             ui,
                             sentence_line_slots,
@@ -108,6 +130,19 @@ pub fn ui_tree(ui: &mut egui::Ui, mut params: TreeSystemParams) {
                 }
             });
 
+        if let Some((edited_entity, new_sentence_text)) = &edited_sentence {
+            let text = join_sentence_texts(params.root_sentence_query.iter().map(
+                |(entity, index, text)| {
+                    if entity == *edited_entity {
+                        (index, new_sentence_text)
+                    } else {
+                        (index, text)
+                    }
+                },
+            ));
+            params.set_text_event.send(SetText(text));
+        }
+
         let free_space_response = ui.allocate_response(ui.available_size(), egui::Sense::click());
         if free_space_response.clicked() {
             params.select_event.send(Select(None));
@@ -115,6 +150,8 @@ pub fn ui_tree(ui: &mut egui::Ui, mut params: TreeSystemParams) {
     });
 }
 
+/// Returns the new text of the sentence if the graphemes of a word were split or merged.
+#[allow(clippy::too_many_arguments)]
 fn ui_words(
     ui: &mut egui::Ui,
     words: &[Entity],
@@ -123,12 +160,17 @@ fn ui_words(
     nested_letter_query: &mut NestedLetterQuery,
     select_event: &mut EventWriter<Select>,
     selection: &Option<Entity>,
-) {
+    alphabet: &Alphabet,
+) -> Option<String> {
     let mut iter = word_query.iter_many_mut(words.iter());
+    let mut word_texts = Vec::with_capacity(words.len());
+    let mut is_word_changed = false;
 
     while let Some((word_entity, word_text, letters, word_line_slots, mut is_open)) =
         iter.fetch_next()
     {
+        let mut new_word_text = None;
+
         let (header_response, _) = CollapsingTreeItem::new(
             word_text,
             word_entity,
@@ -136,13 +178,15 @@ fn ui_words(
             selection.contains(&word_entity),
         )
         .show(ui, |ui| {
-            ui_letters(
+            new_word_text = ui_letters(
                 ui,
+                word_text,
                 letters,
                 letter_query,
                 nested_letter_query,
                 select_event,
                 selection,
+                alphabet,
             );
             ui_line_slots(ui, word_line_slots, select_event, selection);
         });
@@ -150,18 +194,29 @@ fn ui_words(
         if header_response.clicked() {
             select_event.send(Select(Some(word_entity)));
         }
+
+        is_word_changed |= new_word_text.is_some();
+        word_texts.push(new_word_text.unwrap_or_else(|| word_text.to_string()));
     }
+
+    is_word_changed.then(|| word_texts.join(" "))
 }
 
+/// Returns the new text of the word if a digraph was split or merged.
+#[allow(clippy::too_many_arguments)]
 fn ui_letters(
     ui: &mut egui::Ui,
+    word_text: &str,
     letters: &[Entity],
     letter_query: &mut LetterQuery,
     nested_letter_query: &mut NestedLetterQuery,
     select_event: &mut EventWriter<Select>,
     selection: &Option<Entity>,
-) {
+    alphabet: &Alphabet,
+) -> Option<String> {
     let mut iter = letter_query.iter_many_mut(letters.iter());
+    let mut grapheme_index = 0;
+    let mut new_word_text = None;
 
     while let Some((letter_entity, letter_text, dots, letter_line_slots, nested, mut is_open)) =
         iter.fetch_next()
@@ -182,10 +237,35 @@ fn ui_letters(
             header_response
         };
 
+        let header_response = header_response.context_menu(|ui| {
+            let split = alphabet.split_grapheme(word_text, grapheme_index);
+            let merge = alphabet.merge_graphemes(word_text, grapheme_index);
+
+            if ui
+                .add_enabled(split.is_some(), egui::Button::new("Split digraph"))
+                .clicked()
+            {
+                new_word_text = split;
+                ui.close_menu();
+            }
+
+            if ui
+                .add_enabled(merge.is_some(), egui::Button::new("Merge into digraph"))
+                .clicked()
+            {
+                new_word_text = merge;
+                ui.close_menu();
+            }
+        });
+
         if header_response.clicked() {
             select_event.send(Select(Some(letter_entity)));
         }
+
+        grapheme_index += letter_text.split(NESTED_LETTER_TEXT_DELIMITER).count();
     }
+
+    new_word_text
 }
 
 fn ui_nested_letters(
@@ -1,2 +1,3 @@
 pub mod angle_slider;
+pub mod decoration_layout;
 pub mod tree;
@@ -0,0 +1,60 @@
+use crate::math::angle::{Angle, Degree};
+use crate::plugins::text_converter::DecorationLayout;
+use bevy_egui::egui;
+
+/// Grid with the fields of a [`DecorationLayout`], returns the edited layout.
+pub fn ui_decoration_layout(
+    ui: &mut egui::Ui,
+    id_source: &str,
+    layout: &DecorationLayout,
+) -> DecorationLayout {
+    let mut new_layout = *layout;
+
+    egui::Grid::new(id_source).num_columns(2).show(ui, |ui| {
+        let mut spacing = new_layout.spacing.inner();
+        ui.label("Spacing");
+        ui.add(
+            egui::DragValue::new(&mut spacing)
+                .speed(1.0)
+                .clamp_range(0.0..=180.0)
+                .suffix("°"),
+        );
+        new_layout.spacing = Degree::new(spacing);
+        ui.end_row();
+
+        let mut center_angle = new_layout.center_angle.inner();
+        ui.label("Center angle");
+        ui.add(
+            egui::DragValue::new(&mut center_angle)
+                .speed(1.0)
+                .clamp_range(0.0..=359.0)
+                .suffix("°"),
+        );
+        new_layout.center_angle = Degree::new(center_angle);
+        ui.end_row();
+
+        ui.label("Dot size");
+        ui.add(
+            egui::DragValue::new(&mut new_layout.dot_size)
+                .speed(0.01)
+                .clamp_range(0.01..=0.5),
+        );
+        ui.end_row();
+
+        let max_inset = new_layout.max_inset().min(5.0);
+        new_layout.inset = new_layout.inset.min(max_inset);
+        ui.label("Inset");
+        ui.add(
+            egui::DragValue::new(&mut new_layout.inset)
+                .speed(0.1)
+                .clamp_range(0.0..=max_inset),
+        );
+        ui.end_row();
+
+        ui.label("Auto-fit");
+        ui.checkbox(&mut new_layout.auto_fit, "");
+        ui.end_row();
+    });
+
+    new_layout
+}
@@ -0,0 +1,476 @@
+use crate::plugins::svg::raster::{rasterize_svg, RasterSettings};
+use crate::plugins::svg::{Rgb, DEFAULT_STROKE_WIDTH};
+use bevy::prelude::*;
+use gallifreyan_core::alphabet::{parse_rules_string, Alphabet};
+use gallifreyan_core::export::ExportOptions;
+use gallifreyan_core::layout::ReadingDirection;
+use gallifreyan_core::script::{create_script_system, SHERMAN_ID};
+use std::collections::{HashMap, VecDeque};
+use std::io::{Cursor, Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::sync::mpsc::{sync_channel, Receiver};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+pub const DEFAULT_PORT: u16 = 8080;
+
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ServeSettings {
+    /// The server only listens on localhost, `0` picks a free port.
+    pub port: u16,
+    /// Maximum size of the request line and headers in bytes.
+    pub max_request_size: usize,
+    /// Maximum number of characters of the text before it gets sanitized.
+    pub max_text_length: usize,
+    pub max_resolution: u32,
+    /// Number of responses which are kept until the oldest one gets replaced.
+    pub cache_size: usize,
+    /// Number of threads which handle the connections.
+    pub workers: usize,
+    /// Number of accepted connections which wait for a free worker before new ones are no longer
+    /// accepted.
+    pub max_pending_connections: usize,
+}
+
+impl Default for ServeSettings {
+    fn default() -> Self {
+        Self {
+            port: DEFAULT_PORT,
+            max_request_size: 8 * 1024,
+            max_text_length: 1000,
+            max_resolution: 2048,
+            cache_size: 256,
+            workers: 4,
+            max_pending_connections: 64,
+        }
+    }
+}
+
+/// Renders `GET /svg` and `GET /png` requests with the same conversion as the exports of the
+/// editor.
+///
+/// Both endpoints take the `text`, `script`, `nesting`, `color` and `stroke_width` query
+/// parameters and `/png` additionally the `resolution`.
+pub struct Server {
+    listener: TcpListener,
+    state: Arc<ServerState>,
+}
+
+struct ServerState {
+    settings: ServeSettings,
+    cache: Mutex<Cache>,
+}
+
+impl Server {
+    pub fn bind(settings: ServeSettings) -> std::io::Result<Self> {
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, settings.port))?;
+
+        Ok(Self {
+            listener,
+            state: Arc::new(ServerState {
+                cache: Mutex::new(Cache::new(settings.cache_size)),
+                settings,
+            }),
+        })
+    }
+
+    pub fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.listener.local_addr()
+    }
+
+    /// Hands the connections to a fixed number of workers until the listener fails.
+    pub fn run(self) -> std::io::Result<()> {
+        info!("Serving on http://{}", self.local_addr()?);
+
+        let (sender, receiver) = sync_channel(self.state.settings.max_pending_connections);
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for _ in 0..self.state.settings.workers.max(1) {
+            let state = Arc::clone(&self.state);
+            let receiver = Arc::clone(&receiver);
+            std::thread::spawn(move || state.work(&receiver));
+        }
+
+        for stream in self.listener.incoming() {
+            match stream {
+                // blocks while all workers are busy and the queue is full
+                Ok(stream) => {
+                    if sender.send(stream).is_err() {
+                        error!("All workers stopped!");
+                        break;
+                    }
+                }
+                Err(error) => error!("Failed to accept connection: {}", error),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ServerState {
+    fn work(&self, receiver: &Mutex<Receiver<TcpStream>>) {
+        loop {
+            // the lock is released before the connection gets handled
+            let stream = receiver.lock().unwrap().recv();
+
+            match stream {
+                Ok(stream) => self.handle_connection(stream),
+                Err(_) => return,
+            }
+        }
+    }
+
+    fn handle_connection(&self, mut stream: TcpStream) {
+        let response = match read_request_head(&mut stream, self.settings.max_request_size) {
+            Ok(Some(head)) => self.respond(&head),
+            Ok(None) => Response::error(413, "Request is too large!"),
+            Err(error) => {
+                debug!("Failed to read request: {}", error);
+                return;
+            }
+        };
+
+        if let Err(error) = response.write_to(&mut stream) {
+            debug!("Failed to write response: {}", error);
+        }
+    }
+
+    fn respond(&self, head: &str) -> Response {
+        let mut parts = head.lines().next().unwrap_or_default().split(' ');
+        let (method, target) = (parts.next().unwrap_or_default(), parts.next());
+
+        let Some(target) = target else {
+            return Response::error(400, "Invalid request line!");
+        };
+
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+        let format = match path {
+            "/svg" => Format::Svg,
+            "/png" => Format::Png,
+            _ => return Response::error(404, "Not found!"),
+        };
+
+        if method != "GET" {
+            return Response::error(405, "Only GET is allowed!");
+        }
+
+        debug!("Serve request: {}", target);
+
+        let parameters: HashMap<String, String> = form_urlencoded::parse(query.as_bytes())
+            .into_owned()
+            .collect();
+
+        match self.parse_request(format, &parameters) {
+            Ok(request) => self.render_cached(request),
+            Err(response) => response,
+        }
+    }
+
+    fn parse_request(
+        &self,
+        format: Format,
+        parameters: &HashMap<String, String>,
+    ) -> Result<RenderRequest, Response> {
+        let text = parameters
+            .get("text")
+            .ok_or_else(|| Response::error(400, "Missing text parameter!"))?;
+
+        if text.chars().count() > self.settings.max_text_length {
+            return Err(Response::error(413, "Text is too long!"));
+        }
+
+        let script_system =
+            create_script_system(parameters.get("script").map(String::as_str), None)
+                .map_err(|error| Response::error(400, &error))?;
+
+        // only sherman nests vocals, other script systems ignore the rules
+        let nesting = parameters
+            .get("nesting")
+            .filter(|_| script_system.id() == SHERMAN_ID)
+            .cloned();
+
+        let color = match parameters.get("color") {
+            Some(color) => Rgb::hex(color.trim_start_matches('#'))
+                .map_err(|error| Response::error(400, &error))?,
+            None => Rgb::BLACK,
+        };
+
+        let stroke_width = match parameters.get("stroke_width") {
+            Some(stroke_width) => stroke_width
+                .parse::<f32>()
+                .ok()
+                .filter(|it| it.is_finite() && *it > 0.0)
+                .ok_or_else(|| Response::error(400, "Invalid stroke width!"))?,
+            None => DEFAULT_STROKE_WIDTH,
+        };
+
+        let resolution = match (format, parameters.get("resolution")) {
+            (Format::Png, Some(resolution)) => resolution
+                .parse::<u32>()
+                .ok()
+                .filter(|it| (1..=self.settings.max_resolution).contains(it))
+                .ok_or_else(|| {
+                    Response::error(
+                        400,
+                        &format!(
+                            "Resolution has to be between 1 and {}!",
+                            self.settings.max_resolution
+                        ),
+                    )
+                })?,
+            (Format::Png, None) => RasterSettings::default().resolution,
+            (Format::Svg, _) => 0,
+        };
+
+        let nesting_key = match nesting.as_deref() {
+            None => None,
+            Some("") => Some(vec![]),
+            Some(rules) => {
+                let mut rules: Vec<String> = parse_rules_string(rules, &Alphabet::sherman())
+                    .map_err(|error| Response::error(400, &error))?
+                    .iter()
+                    .map(|rule| format!("{:?}", rule))
+                    .collect();
+                rules.sort();
+                Some(rules)
+            }
+        };
+
+        Ok(RenderRequest {
+            key: CacheKey {
+                format,
+                script: script_system.id(),
+                text: script_system.sanitize_text_input(text),
+                nesting: nesting_key,
+                color: [color.r, color.g, color.b].map(|channel| channel.to_bits()),
+                stroke_width: stroke_width.to_bits(),
+                resolution,
+            },
+            text: text.clone(),
+            script: script_system.id(),
+            nesting,
+            color,
+            stroke_width,
+        })
+    }
+
+    fn render_cached(&self, request: RenderRequest) -> Response {
+        if let Some(body) = self.cache.lock().unwrap().get(&request.key) {
+            return Response::ok(request.key.format, body, true);
+        }
+
+        match request.render() {
+            Ok(body) => {
+                let body = Arc::new(body);
+                self.cache
+                    .lock()
+                    .unwrap()
+                    .insert(request.key.clone(), Arc::clone(&body));
+
+                Response::ok(request.key.format, body, false)
+            }
+            Err(error) => Response::error(400, &error),
+        }
+    }
+}
+
+/// Reads until the end of the headers and returns `None` if they exceed the size limit.
+fn read_request_head(stream: &mut TcpStream, max_size: usize) -> std::io::Result<Option<String>> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+
+    let mut head = Vec::new();
+    let mut buffer = [0; 1024];
+
+    loop {
+        if let Some(end) = head.windows(4).position(|it| it == b"\r\n\r\n") {
+            return Ok(
+                (end <= max_size).then(|| String::from_utf8_lossy(&head[..end]).into_owned())
+            );
+        }
+
+        if head.len() > max_size {
+            return Ok(None);
+        }
+
+        let read = stream.read(&mut buffer)?;
+
+        if read == 0 {
+            return Err(std::io::ErrorKind::UnexpectedEof.into());
+        }
+
+        head.extend_from_slice(&buffer[..read]);
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+enum Format {
+    Svg,
+    Png,
+}
+
+/// Requests are cached by their sanitized text and parsed options, so that e.g. `ab, c` and
+/// `ab c` share the same response.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct CacheKey {
+    format: Format,
+    script: &'static str,
+    text: String,
+    nesting: Option<Vec<String>>,
+    color: [u32; 3],
+    stroke_width: u32,
+    resolution: u32,
+}
+
+struct RenderRequest {
+    key: CacheKey,
+    text: String,
+    script: &'static str,
+    nesting: Option<String>,
+    color: Rgb,
+    stroke_width: f32,
+}
+
+impl RenderRequest {
+    fn render(&self) -> Result<Vec<u8>, String> {
+        let script_system = create_script_system(Some(self.script), self.nesting.as_deref())?;
+
+        match self.key.format {
+            Format::Svg => {
+                let options = ExportOptions {
+                    color: self.color,
+                    stroke_width: self.stroke_width,
+                };
+
+                Ok(script_system
+                    .to_svg(&self.text, ReadingDirection::default(), &options)?
+                    .to_string()
+                    .into_bytes())
+            }
+            Format::Png => {
+                let settings = RasterSettings {
+                    resolution: self.key.resolution,
+                    color: Color::rgb(self.color.r, self.color.g, self.color.b),
+                    stroke_width: self.stroke_width,
+                };
+
+                let svg = script_system.to_svg(
+                    &self.text,
+                    ReadingDirection::default(),
+                    &ExportOptions::default(),
+                )?;
+                let image = rasterize_svg(&svg, &settings);
+                let buffer = image::RgbaImage::from_raw(
+                    settings.resolution,
+                    settings.resolution,
+                    image.data,
+                )
+                .ok_or("Invalid image size!")?;
+
+                let mut png = Vec::new();
+                buffer
+                    .write_to(&mut Cursor::new(&mut png), image::ImageOutputFormat::Png)
+                    .map_err(|error| error.to_string())?;
+
+                Ok(png)
+            }
+        }
+    }
+}
+
+struct Cache {
+    capacity: usize,
+    entries: HashMap<CacheKey, Arc<Vec<u8>>>,
+    order: VecDeque<CacheKey>,
+}
+
+impl Cache {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            entries: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    fn get(&self, key: &CacheKey) -> Option<Arc<Vec<u8>>> {
+        self.entries.get(key).cloned()
+    }
+
+    fn insert(&mut self, key: CacheKey, body: Arc<Vec<u8>>) {
+        if self.capacity == 0 || self.entries.contains_key(&key) {
+            return;
+        }
+
+        if self.order.len() >= self.capacity {
+            if let Some(oldest) = self.order.pop_front() {
+                self.entries.remove(&oldest);
+            }
+        }
+
+        self.order.push_back(key.clone());
+        self.entries.insert(key, body);
+    }
+}
+
+struct Response {
+    status: u16,
+    content_type: &'static str,
+    cached: Option<bool>,
+    body: Arc<Vec<u8>>,
+}
+
+impl Response {
+    fn ok(format: Format, body: Arc<Vec<u8>>, cached: bool) -> Self {
+        Self {
+            status: 200,
+            content_type: match format {
+                Format::Svg => "image/svg+xml",
+                Format::Png => "image/png",
+            },
+            cached: Some(cached),
+            body,
+        }
+    }
+
+    fn error(status: u16, message: &str) -> Self {
+        Self {
+            status,
+            content_type: "text/plain; charset=utf-8",
+            cached: None,
+            body: Arc::new(message.as_bytes().to_vec()),
+        }
+    }
+
+    fn write_to(&self, stream: &mut TcpStream) -> std::io::Result<()> {
+        let reason = match self.status {
+            200 => "OK",
+            400 => "Bad Request",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            413 => "Payload Too Large",
+            _ => "",
+        };
+
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+            self.status,
+            reason,
+            self.content_type,
+            self.body.len()
+        );
+
+        if let Some(cached) = self.cached {
+            head += &format!("X-Cache: {}\r\n", if cached { "hit" } else { "miss" });
+        }
+
+        head += "\r\n";
+
+        stream.write_all(head.as_bytes())?;
+        stream.write_all(&self.body)?;
+        stream.flush()
+    }
+}
@@ -1,3 +1,4 @@
 pub mod contains;
+#[cfg(feature = "native-dialogs")]
 pub mod event_set;
 pub mod update_if_changed;
@@ -0,0 +1,171 @@
+#![cfg(feature = "serve")]
+
+use gallifreyan_lib::serve::{ServeSettings, Server};
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+
+struct Response {
+    status: u16,
+    headers: String,
+    body: Vec<u8>,
+}
+
+fn start_server(settings: ServeSettings) -> SocketAddr {
+    let server = Server::bind(ServeSettings {
+        port: 0,
+        ..settings
+    })
+    .unwrap();
+    let address = server.local_addr().unwrap();
+
+    std::thread::spawn(move || server.run());
+
+    address
+}
+
+fn request(address: SocketAddr, request: &str) -> Response {
+    let mut stream = TcpStream::connect(address).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+
+    let mut response = Vec::new();
+    stream.read_to_end(&mut response).unwrap();
+
+    let split = response
+        .windows(4)
+        .position(|it| it == b"\r\n\r\n")
+        .unwrap();
+    let headers = String::from_utf8(response[..split].to_vec()).unwrap();
+
+    Response {
+        status: headers[9..12].parse().unwrap(),
+        headers,
+        body: response[split + 4..].to_vec(),
+    }
+}
+
+fn get(address: SocketAddr, target: &str) -> Response {
+    request(
+        address,
+        &format!("GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", target),
+    )
+}
+
+fn fixture(svg: &str) -> Vec<u8> {
+    svg.replace("\r\n", "\n").into_bytes()
+}
+
+#[test]
+fn should_serve_svg() {
+    let address = start_server(ServeSettings::default());
+
+    let response = get(address, "/svg?text=abajatatha");
+
+    assert_eq!(response.status, 200);
+    assert!(response.headers.contains("Content-Type: image/svg+xml"));
+    assert_eq!(response.body, fixture(include_str!("svg/abajatatha.svg")));
+
+    let response = get(address, "/svg?text=bchdhgf&nesting=");
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.body, fixture(include_str!("svg/bchdhgf.svg")));
+}
+
+#[test]
+fn should_serve_script_systems() {
+    let address = start_server(ServeSettings::default());
+
+    let sherman = get(address, "/svg?text=clock&script=sherman");
+    let clockwork = get(address, "/svg?text=clock&script=clockwork");
+
+    assert_eq!(sherman.status, 200);
+    assert_eq!(clockwork.status, 200);
+    assert!(clockwork.headers.contains("X-Cache: miss"));
+    assert_ne!(sherman.body, clockwork.body);
+    assert_eq!(get(address, "/png?text=clock&script=clockwork").status, 200);
+
+    // clockwork doesn't nest vocals and ignores the rules
+    let with_nesting = get(address, "/svg?text=clock&script=clockwork&nesting=b");
+
+    assert_eq!(with_nesting.status, 200);
+    assert!(with_nesting.headers.contains("X-Cache: hit"));
+}
+
+#[test]
+fn should_serve_png() {
+    let address = start_server(ServeSettings::default());
+
+    let response = get(
+        address,
+        "/png?text=gallifreyan&resolution=64&color=%23ff0000",
+    );
+
+    assert_eq!(response.status, 200);
+    assert!(response.headers.contains("Content-Type: image/png"));
+    assert_eq!(&response.body[..8], b"\x89PNG\r\n\x1a\n");
+    // width and height of the IHDR chunk
+    assert_eq!(&response.body[16..24], &[0, 0, 0, 64, 0, 0, 0, 64]);
+}
+
+#[test]
+fn should_cache_normalized_input() {
+    let address = start_server(ServeSettings::default());
+
+    let first = get(address, "/svg?text=ab%20cd&nesting=*a,ba");
+    let second = get(address, "/svg?text=%20ab,%20cd!&nesting=ba,*a");
+    let other_format = get(address, "/png?text=ab%20cd&nesting=*a,ba");
+
+    assert!(first.headers.contains("X-Cache: miss"));
+    assert!(second.headers.contains("X-Cache: hit"));
+    assert!(other_format.headers.contains("X-Cache: miss"));
+    assert_eq!(first.body, second.body);
+}
+
+#[test]
+fn should_reject_invalid_requests() {
+    let address = start_server(ServeSettings {
+        max_request_size: 256,
+        max_text_length: 10,
+        max_resolution: 128,
+        ..Default::default()
+    });
+
+    assert_eq!(get(address, "/svg").status, 400);
+    assert_eq!(get(address, "/svg?text=ab&nesting=b").status, 400);
+    assert_eq!(get(address, "/svg?text=ab&color=red").status, 400);
+    assert_eq!(get(address, "/svg?text=ab&script=cot").status, 400);
+    assert_eq!(get(address, "/svg?text=ab&stroke_width=-1").status, 400);
+    assert_eq!(get(address, "/png?text=ab&resolution=129").status, 400);
+    assert_eq!(get(address, "/pdf?text=ab").status, 404);
+    assert_eq!(get(address, "/svg?text=abcdefghijk").status, 413);
+    assert_eq!(
+        request(address, "POST /svg?text=ab HTTP/1.1\r\n\r\n").status,
+        405
+    );
+
+    let too_large = format!(
+        "GET /svg?text=ab HTTP/1.1\r\nX-Padding: {}\r\n\r\n",
+        "a".repeat(512)
+    );
+    assert_eq!(request(address, &too_large).status, 413);
+}
+
+#[test]
+fn should_queue_connections_for_busy_workers() {
+    let address = start_server(ServeSettings {
+        workers: 1,
+        max_pending_connections: 1,
+        ..Default::default()
+    });
+
+    // a connection which never sends its request keeps the only worker busy until it times out
+    let _idle = TcpStream::connect(address).unwrap();
+
+    let requests: Vec<_> = ["a", "b", "c"]
+        .into_iter()
+        .map(|text| std::thread::spawn(move || get(address, &format!("/svg?text={}", text))))
+        .collect();
+
+    for request in requests {
+        assert_eq!(request.join().unwrap().status, 200);
+    }
+}
@@ -1,8 +1,23 @@
-use bevy::prelude::{App, Color, Events};
+use bevy::prelude::{App, Color, Entity, Events, Vec2, With};
+use gallifreyan_core::alphabet::Alphabet;
+use gallifreyan_core::layout::ReadingDirection;
+use gallifreyan_core::model;
 use gallifreyan_lib::plugins::color_theme::{ColorTheme, DRAW_COLOR};
-use gallifreyan_lib::plugins::svg::{export::SVGExportSystemParams, SVGPlugin};
-use gallifreyan_lib::plugins::text_converter::components::NestingSettings;
-use gallifreyan_lib::plugins::text_converter::{SetText, TextConverterPlugin};
+use gallifreyan_lib::plugins::svg::metadata::{ImportSVG, ImportSVGFailed};
+use gallifreyan_lib::plugins::svg::{export::SVGExportSystemParams, SVGPlugin, SVG};
+use gallifreyan_lib::plugins::text_converter::components::{
+    CustomDecoration, ElementId, Letter, LineSlot, NestingSettings, PositionData, Radius, Text,
+    Word,
+};
+use gallifreyan_lib::plugins::text_converter::decoration::AddDecoration;
+use gallifreyan_lib::plugins::text_converter::model::{
+    SentenceModelSystemParams, SetSentenceModels,
+};
+use gallifreyan_lib::plugins::text_converter::{
+    SentenceFrame, SentenceFrameSettings, SetText, TextConverterPlugin,
+};
+use itertools::Itertools;
+use std::str::FromStr;
 use std::sync::mpsc::sync_channel;
 
 trait TestApp {
@@ -112,3 +127,390 @@ fn consonant_on_line() {
         .set_text("thghyzqquxng")
         .assert_svg(include_str!("svg/thghyzqquxng.svg"));
 }
+
+#[test]
+fn single_word_without_frame() {
+    let mut app = App::new_test(NestingSettings::All);
+    app.insert_resource(SentenceFrameSettings(SentenceFrame::None))
+        .set_text("bphva");
+
+    let (sender, receiver) = sync_channel::<String>(1);
+
+    app.add_system(move |svg_export: SVGExportSystemParams| {
+        let svg = svg_export.create_svg().unwrap();
+        sender.send(svg.to_string()).unwrap();
+    });
+
+    app.update();
+
+    let result = receiver.recv().unwrap();
+
+    // the word takes the place of the sentence circles
+    assert!(!result.contains("r=\"450\""));
+    assert!(result.contains("A 460 460"));
+    // root, word, letters, dot and position correction without the groups of the sentence
+    assert_eq!(result.matches("<g").count(), 8);
+}
+
+#[test]
+fn import_round_trip() {
+    let files = [
+        include_str!("svg/abajatatha.svg"),
+        include_str!("svg/ebejetethe.svg"),
+        include_str!("svg/ibijitithi.svg"),
+        include_str!("svg/obojototho.svg"),
+        include_str!("svg/ubujututhu.svg"),
+        include_str!("svg/bchdhgf.svg"),
+        include_str!("svg/jphklcnpm.svg"),
+        include_str!("svg/twhshrvws.svg"),
+        include_str!("svg/thghyzqquxng.svg"),
+    ];
+
+    for file in files {
+        let file = file.replace("\r\n", "\n");
+        let svg = SVG::from_str(&file).unwrap();
+        assert_eq!(svg.to_string(), file);
+    }
+}
+
+#[test]
+fn import_metadata_round_trip() {
+    fn export_with_metadata(app: &mut App) -> String {
+        let (sender, receiver) = sync_channel::<String>(1);
+
+        app.add_system(move |svg_export: SVGExportSystemParams| {
+            let svg = svg_export.create_svg_with_metadata().unwrap();
+            sender.send(svg.to_string()).unwrap();
+        });
+
+        app.update();
+
+        receiver.recv().unwrap()
+    }
+
+    let mut app = App::new_test(NestingSettings::All);
+    app.set_text("abajatatha dot gun");
+
+    for mut radius in app
+        .world
+        .query_filtered::<&mut Radius, With<Word>>()
+        .iter_mut(&mut app.world)
+    {
+        **radius *= 0.8;
+    }
+
+    for mut position_data in app
+        .world
+        .query_filtered::<&mut PositionData, With<LineSlot>>()
+        .iter_mut(&mut app.world)
+    {
+        position_data.distance += 5.0;
+    }
+
+    let exported = export_with_metadata(&mut app);
+    assert!(exported.contains(r#"data-kind="nested-vocal""#));
+    assert!(exported.contains(r#"data-kind="line-slot""#));
+
+    let mut import_app = App::new_test(NestingSettings::All);
+    import_app
+        .world
+        .resource_mut::<Events<ImportSVG>>()
+        .send(ImportSVG(SVG::from_str(&exported).unwrap()));
+    import_app.update();
+    import_app.update();
+
+    assert_eq!(export_with_metadata(&mut import_app), exported);
+}
+
+#[test]
+fn import_metadata_without_frame() {
+    fn export_with_metadata(app: &mut App) -> String {
+        let (sender, receiver) = sync_channel::<String>(1);
+
+        app.add_system(move |svg_export: SVGExportSystemParams| {
+            let svg = svg_export.create_svg_with_metadata().unwrap();
+            sender.send(svg.to_string()).unwrap();
+        });
+
+        app.update();
+
+        receiver.recv().unwrap()
+    }
+
+    let mut app = App::new_test(NestingSettings::All);
+    app.insert_resource(SentenceFrameSettings(SentenceFrame::None))
+        .set_text("bphva");
+
+    let exported = export_with_metadata(&mut app);
+    assert!(exported.contains(r#"data-frame="none""#));
+    assert!(!exported.contains("r=\"450\""));
+
+    let mut import_app = App::new_test(NestingSettings::All);
+    import_app
+        .world
+        .resource_mut::<Events<ImportSVG>>()
+        .send(ImportSVG(SVG::from_str(&exported).unwrap()));
+    import_app.update();
+    import_app.update();
+
+    assert_eq!(
+        **import_app.world.resource::<SentenceFrameSettings>(),
+        SentenceFrame::None
+    );
+    assert_eq!(export_with_metadata(&mut import_app), exported);
+}
+
+#[test]
+fn import_restores_nesting() {
+    fn export_with_metadata(app: &mut App) -> String {
+        let (sender, receiver) = sync_channel::<String>(1);
+
+        app.add_system(move |svg_export: SVGExportSystemParams| {
+            let svg = svg_export.create_svg_with_metadata().unwrap();
+            sender.send(svg.to_string()).unwrap();
+        });
+
+        app.update();
+
+        receiver.recv().unwrap()
+    }
+
+    let mut app = App::new_test(NestingSettings::None);
+    app.set_text("ba");
+
+    let exported = export_with_metadata(&mut app);
+
+    let mut import_app = App::new_test(NestingSettings::All);
+    import_app
+        .world
+        .resource_mut::<Events<ImportSVG>>()
+        .send(ImportSVG(SVG::from_str(&exported).unwrap()));
+    import_app.update();
+    import_app.update();
+
+    assert!(matches!(
+        *import_app.world.resource::<NestingSettings>(),
+        NestingSettings::None
+    ));
+    assert_eq!(export_with_metadata(&mut import_app), exported);
+}
+
+#[test]
+fn import_reports_mismatching_letters() {
+    let mut app = App::new_test(NestingSettings::All);
+    app.set_text("bd");
+
+    let (sender, receiver) = sync_channel::<String>(1);
+    app.add_system(move |svg_export: SVGExportSystemParams| {
+        let svg = svg_export.create_svg_with_metadata().unwrap();
+        sender.send(svg.to_string()).unwrap();
+    });
+    app.update();
+
+    // as if the letters were written with another alphabet
+    let exported = receiver
+        .recv()
+        .unwrap()
+        .replace(r#"data-consonant="D""#, r#"data-consonant="T""#);
+
+    let mut import_app = App::new_test(NestingSettings::All);
+    import_app
+        .world
+        .resource_mut::<Events<ImportSVG>>()
+        .send(ImportSVG(SVG::from_str(&exported).unwrap()));
+    import_app.update();
+    import_app.update();
+
+    let events = import_app.world.resource::<Events<ImportSVGFailed>>();
+    let errors: Vec<ImportSVGFailed> = events.get_reader().iter(events).cloned().collect();
+
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].0.contains("another alphabet"));
+}
+
+#[test]
+fn import_metadata_with_custom_decorations() {
+    fn export_with_metadata(app: &mut App) -> String {
+        let (sender, receiver) = sync_channel::<String>(1);
+
+        app.add_system(move |svg_export: SVGExportSystemParams| {
+            let svg = svg_export.create_svg_with_metadata().unwrap();
+            sender.send(svg.to_string()).unwrap();
+        });
+
+        app.update();
+
+        receiver.recv().unwrap()
+    }
+
+    fn custom_decorations(app: &mut App) -> Vec<(CustomDecoration, ElementId)> {
+        app.world
+            .query::<(&CustomDecoration, &ElementId)>()
+            .iter(&app.world)
+            .map(|(decoration, id)| (*decoration, *id))
+            .sorted_by_key(|(_, id)| *id)
+            .collect()
+    }
+
+    let mut app = App::new_test(NestingSettings::All);
+    app.set_text("bd");
+
+    let letter = app
+        .world
+        .query_filtered::<Entity, With<Letter>>()
+        .iter(&app.world)
+        .next()
+        .unwrap();
+
+    for (decoration, position) in [
+        (CustomDecoration::Circle, Some(Vec2::new(5.0, 0.0))),
+        (CustomDecoration::Line, None),
+    ] {
+        app.world
+            .resource_mut::<Events<AddDecoration>>()
+            .send(AddDecoration {
+                letter,
+                decoration,
+                position,
+            });
+    }
+
+    app.update();
+    app.update();
+
+    let exported = export_with_metadata(&mut app);
+    assert!(exported.contains(r#"data-custom="circle""#));
+    assert!(exported.contains(r#"data-custom="line""#));
+
+    let mut import_app = App::new_test(NestingSettings::All);
+    import_app
+        .world
+        .resource_mut::<Events<ImportSVG>>()
+        .send(ImportSVG(SVG::from_str(&exported).unwrap()));
+    import_app.update();
+    import_app.update();
+
+    assert_eq!(
+        custom_decorations(&mut import_app),
+        custom_decorations(&mut app)
+    );
+    assert_eq!(export_with_metadata(&mut import_app), exported);
+}
+
+#[test]
+fn import_keeps_element_ids() {
+    fn element_ids(app: &mut App) -> Vec<(String, ElementId)> {
+        app.world
+            .query_filtered::<(&Text, &ElementId), With<Letter>>()
+            .iter(&app.world)
+            .map(|(text, id)| (text.to_string(), *id))
+            .sorted()
+            .collect()
+    }
+
+    let mut app = App::new_test(NestingSettings::All);
+    app.set_text("ab cd");
+
+    let (sender, receiver) = sync_channel::<SVG>(1);
+    app.add_system(move |svg_export: SVGExportSystemParams| {
+        sender
+            .send(svg_export.create_svg_with_metadata().unwrap())
+            .unwrap();
+    });
+    app.update();
+    let exported = receiver.recv().unwrap();
+
+    assert!(exported.to_string().contains(r#"<g id="e"#));
+
+    // the ids of the existing sentence would collide with the imported ones
+    let mut import_app = App::new_test(NestingSettings::All);
+    import_app.set_text("xyz ab");
+    import_app
+        .world
+        .resource_mut::<Events<ImportSVG>>()
+        .send(ImportSVG(exported));
+    import_app.update();
+    import_app.update();
+
+    assert_eq!(element_ids(&mut import_app), element_ids(&mut app));
+
+    let ids: Vec<ElementId> = import_app
+        .world
+        .query::<&ElementId>()
+        .iter(&import_app.world)
+        .copied()
+        .collect();
+
+    assert_eq!(ids.iter().unique().count(), ids.len());
+}
+
+#[test]
+fn model_from_entities() {
+    let mut app = App::new_test(NestingSettings::All);
+    app.set_text("abajatatha dot");
+
+    let (sender, receiver) = sync_channel::<Vec<model::Sentence>>(1);
+
+    app.add_system(move |sentence_model: SentenceModelSystemParams| {
+        sender.send(sentence_model.create_models()).unwrap();
+    });
+
+    app.update();
+
+    assert_eq!(
+        receiver.recv().unwrap(),
+        vec![model::Sentence::new(
+            "abajatatha dot",
+            &NestingSettings::All,
+            &Alphabet::sherman(),
+            ReadingDirection::default()
+        )]
+    );
+}
+
+#[test]
+fn models_from_multiple_sentences() {
+    let mut app = App::new_test(NestingSettings::All);
+    app.set_text("abajatatha dot\ngun");
+
+    let (sender, receiver) = sync_channel::<Vec<model::Sentence>>(1);
+
+    app.add_system(move |sentence_model: SentenceModelSystemParams| {
+        sender.send(sentence_model.create_models()).unwrap();
+    });
+
+    app.update();
+
+    let texts: Vec<String> = receiver
+        .recv()
+        .unwrap()
+        .into_iter()
+        .map(|sentence| sentence.text)
+        .collect();
+
+    assert_eq!(texts, ["abajatatha dot", "gun"]);
+}
+
+#[test]
+fn entities_from_model() {
+    let mut sentence = model::Sentence::new(
+        "abajatatha dot",
+        &NestingSettings::All,
+        &Alphabet::sherman(),
+        ReadingDirection::default(),
+    );
+
+    for word in &mut sentence.words {
+        word.radius *= 0.8;
+        word.letters[0].position_data.distance += 10.0;
+    }
+
+    let mut app = App::new_test(NestingSettings::All);
+    app.world
+        .resource_mut::<Events<SetSentenceModels>>()
+        .send(SetSentenceModels(vec![sentence.clone()]));
+    app.update();
+    app.update();
+
+    app.assert_svg(&sentence.to_svg(&Alphabet::sherman()).to_string());
+}